@@ -0,0 +1,137 @@
+//! Render a stereo WAV file through Tension Field without a host.
+//!
+//! ```text
+//! cargo run --example process_wav -- in.wav out.wav [--bpm 120] [name=value ...]
+//! ```
+//!
+//! Parameter names match the host display names, ignoring case and spaces
+//! (`feedback=0.4`, `warpmotion=0.6`). Values are plain parameter values.
+//! The input may be 16-bit PCM or 32-bit float; the output is 32-bit float.
+
+use std::error::Error;
+use std::fs;
+
+use tension_field::offline::OfflineRenderer;
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let (Some(input_path), Some(output_path)) = (args.next(), args.next()) else {
+        return Err("usage: process_wav <in.wav> <out.wav> [--bpm N] [name=value ...]".into());
+    };
+
+    let mut tempo_bpm = 120.0;
+    let mut overrides = Vec::new();
+    while let Some(arg) = args.next() {
+        if arg == "--bpm" {
+            tempo_bpm = args.next().ok_or("--bpm needs a value")?.parse()?;
+        } else {
+            let (name, value) = arg
+                .split_once('=')
+                .ok_or_else(|| format!("expected name=value, got `{arg}`"))?;
+            overrides.push((name.to_string(), value.parse::<f32>()?));
+        }
+    }
+
+    let wav = read_wav(&fs::read(&input_path)?)?;
+    let mut renderer = OfflineRenderer::new(wav.sample_rate as f32, tempo_bpm);
+    for (name, value) in overrides {
+        let id =
+            OfflineRenderer::param_id(&name).ok_or_else(|| format!("unknown param `{name}`"))?;
+        renderer.set_param(id, value);
+    }
+
+    let (mut left, mut right) = (wav.left, wav.right);
+    renderer.process(&mut left, &mut right);
+    fs::write(&output_path, write_wav(wav.sample_rate, &left, &right))?;
+    println!(
+        "rendered {} frames at {} Hz ({} samples latency) to {output_path}",
+        left.len(),
+        wav.sample_rate,
+        renderer.latency_samples()
+    );
+    Ok(())
+}
+
+/// Decoded stereo audio; mono files are duplicated to both channels.
+struct Wav {
+    sample_rate: u32,
+    left: Vec<f32>,
+    right: Vec<f32>,
+}
+
+fn read_wav(bytes: &[u8]) -> Result<Wav, Box<dyn Error>> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("not a RIFF/WAVE file".into());
+    }
+
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into()?) as usize;
+        let body = &bytes[offset + 8..(offset + 8 + size).min(bytes.len())];
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let tag = u16::from_le_bytes([body[0], body[1]]);
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let sample_rate = u32::from_le_bytes(body[4..8].try_into()?);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                format = Some((tag, channels, sample_rate, bits));
+            }
+            b"data" => data = Some(body),
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        offset += 8 + size + (size & 1);
+    }
+
+    let (tag, channels, sample_rate, bits) = format.ok_or("missing fmt chunk")?;
+    let data = data.ok_or("missing data chunk")?;
+    let samples: Vec<f32> = match (tag, bits) {
+        (WAVE_FORMAT_PCM, 16) => data
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32_768.0)
+            .collect(),
+        (WAVE_FORMAT_IEEE_FLOAT, 32) => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        _ => return Err(format!("unsupported WAV format {tag} at {bits} bits").into()),
+    };
+
+    let channels = usize::from(channels.max(1));
+    let frames = samples.chunks_exact(channels);
+    let left = frames.clone().map(|frame| frame[0]).collect();
+    let right = frames.map(|frame| frame[(channels > 1) as usize]).collect();
+    Ok(Wav {
+        sample_rate,
+        left,
+        right,
+    })
+}
+
+fn write_wav(sample_rate: u32, left: &[f32], right: &[f32]) -> Vec<u8> {
+    let data_len = (left.len() * 2 * 4) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16_u32.to_le_bytes());
+    bytes.extend_from_slice(&WAVE_FORMAT_IEEE_FLOAT.to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2 * 4).to_le_bytes());
+    bytes.extend_from_slice(&(2_u16 * 4).to_le_bytes());
+    bytes.extend_from_slice(&32_u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for (left, right) in left.iter().zip(right) {
+        bytes.extend_from_slice(&left.to_le_bytes());
+        bytes.extend_from_slice(&right.to_le_bytes());
+    }
+    bytes
+}
@@ -0,0 +1,51 @@
+//! Editor-set audition aids read by the audio thread.
+//!
+//! These are listening tools rather than sound settings, so they are not
+//! parameters and are never saved with plugin state.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+const NO_SOLO: u32 = u32::MAX;
+
+/// Audition requests set by the editor and read by the audio thread.
+pub(crate) struct Auditions {
+    mod_destination: AtomicU32,
+    feedback_tail: AtomicBool,
+}
+
+impl Default for Auditions {
+    fn default() -> Self {
+        Self {
+            mod_destination: AtomicU32::new(NO_SOLO),
+            feedback_tail: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Auditions {
+    /// Solo one modulation destination index, or clear the solo with `None`.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn set_mod_solo(&self, destination: Option<usize>) {
+        let value = destination.map_or(NO_SOLO, |index| index as u32);
+        self.mod_destination.store(value, Ordering::Relaxed);
+    }
+
+    /// Return the soloed modulation destination index, if any.
+    pub(crate) fn mod_solo(&self) -> Option<usize> {
+        match self.mod_destination.load(Ordering::Relaxed) {
+            NO_SOLO => None,
+            index => Some(index as usize),
+        }
+    }
+
+    /// Hear only the regenerated feedback tail instead of the full output.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn set_feedback_solo(&self, enabled: bool) {
+        self.feedback_tail.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Return whether the feedback tail is soloed.
+    pub(crate) fn feedback_solo(&self) -> bool {
+        self.feedback_tail.load(Ordering::Relaxed)
+    }
+}
@@ -11,6 +11,8 @@ pub(crate) struct TransportState {
     pub is_playing: bool,
     /// Song position in quarter-note beats when available.
     pub song_pos_beats: Option<f64>,
+    /// Bar length in quarter-note beats, from the host time signature.
+    pub beats_per_bar: f64,
 }
 
 impl Default for TransportState {
@@ -19,6 +21,7 @@ impl Default for TransportState {
             tempo_bpm: 120.0,
             is_playing: false,
             song_pos_beats: None,
+            beats_per_bar: 4.0,
         }
     }
 }
@@ -30,6 +33,11 @@ pub(crate) struct ClockFrame {
     pub beat_position: f64,
     /// Playback state flag.
     pub is_playing: bool,
+    /// Whether `beat_position` is anchored to a host timeline this block.
+    ///
+    /// When false the position comes from the free-running fallback clock and
+    /// must not be treated as a musical grid.
+    pub host_timeline: bool,
 }
 
 impl ClockFrame {
@@ -45,22 +53,63 @@ impl ClockFrame {
 pub(crate) struct TransportClock {
     sample_rate: f32,
     fallback_beat_position: f64,
+    host_timeline: bool,
+    previous_beat_position: Option<f64>,
+    bars_elapsed: u32,
+    jumped_back: bool,
+    was_playing: bool,
+    started: bool,
 }
 
+/// Backward movement smaller than this is treated as host rounding, not a jump.
+const JUMP_TOLERANCE_BEATS: f64 = 1.0e-3;
+
 impl TransportClock {
     /// Create a clock for the given sample rate.
     pub(crate) fn new(sample_rate: f32) -> Self {
         Self {
             sample_rate: sample_rate.max(1.0),
             fallback_beat_position: 0.0,
+            host_timeline: false,
+            previous_beat_position: None,
+            bars_elapsed: 0,
+            jumped_back: false,
+            was_playing: false,
+            started: false,
         }
     }
 
+    /// Bars crossed since the clock started or the song position last jumped backward.
+    pub(crate) fn bars_elapsed(&self) -> u32 {
+        self.bars_elapsed
+    }
+
+    /// Return whether the latest tick moved the song position backward (loop or relocate).
+    pub(crate) fn jumped_back(&self) -> bool {
+        self.jumped_back
+    }
+
+    /// Return whether the latest tick is the first playing sample after a stop.
+    pub(crate) fn started(&self) -> bool {
+        self.started
+    }
+
+    /// Record whether the host supplied a song position for the upcoming block.
+    ///
+    /// Blocks only carry a position for their first sample; later samples
+    /// extrapolate from it and stay host-anchored until a block arrives without one.
+    pub(crate) fn begin_block(&mut self, transport: TransportState) {
+        self.host_timeline = transport.song_pos_beats.is_some();
+    }
+
     /// Advance one sample and return the current transport frame.
     pub(crate) fn tick(&mut self, transport: TransportState) -> ClockFrame {
         let tempo_bpm = transport.tempo_bpm.clamp(20.0, 300.0);
         let beat_increment = tempo_bpm as f64 / (self.sample_rate as f64 * 60.0);
 
+        if transport.song_pos_beats.is_some() {
+            self.host_timeline = true;
+        }
         let beat_position = transport
             .song_pos_beats
             .unwrap_or(self.fallback_beat_position);
@@ -70,14 +119,99 @@ impl TransportClock {
         } else {
             self.fallback_beat_position = beat_position;
         }
+        self.count_bars(beat_position, transport.beats_per_bar);
+        self.started = transport.is_playing && !self.was_playing;
+        self.was_playing = transport.is_playing;
 
         ClockFrame {
             beat_position,
             is_playing: transport.is_playing,
+            host_timeline: self.host_timeline,
+        }
+    }
+
+    fn count_bars(&mut self, beat_position: f64, beats_per_bar: f64) {
+        self.jumped_back = false;
+        if let Some(previous) = self.previous_beat_position {
+            if beat_position + JUMP_TOLERANCE_BEATS < previous {
+                self.jumped_back = true;
+                self.bars_elapsed = 0;
+            } else {
+                let bar_beats = beats_per_bar.max(1.0e-3);
+                let crossed = (beat_position / bar_beats).floor() - (previous / bar_beats).floor();
+                self.bars_elapsed = self.bars_elapsed.saturating_add(crossed.max(0.0) as u32);
+            }
         }
+        self.previous_beat_position = Some(beat_position);
     }
 }
 
+/// Largest phase shift, as a fraction of one cycle, at full humanize.
+pub(crate) const HUMANIZE_MAX_CYCLE_SHIFT: f32 = 0.03;
+/// Per-sample glide towards each cycle's new shift so humanized phases never jump.
+const HUMANIZE_GLIDE: f32 = 0.0005;
+
+/// Bounded, deterministic phase wander that loosens synced cycles.
+///
+/// A new random shift is drawn each time the incoming phase wraps, so the same
+/// seed and transport always produce the same deviations.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PhaseHumanizer {
+    rng_state: u32,
+    target: f32,
+    offset: f32,
+    previous_phase: f32,
+}
+
+impl Default for PhaseHumanizer {
+    fn default() -> Self {
+        Self::new(0x2545_F491)
+    }
+}
+
+impl PhaseHumanizer {
+    /// Create a humanizer with a fixed random seed.
+    pub(crate) fn new(seed: u32) -> Self {
+        Self {
+            rng_state: seed.max(1),
+            target: 0.0,
+            offset: 0.0,
+            previous_phase: 0.0,
+        }
+    }
+
+    /// Return `phase` shifted by at most `amount * HUMANIZE_MAX_CYCLE_SHIFT` of a cycle.
+    pub(crate) fn apply(&mut self, phase: f32, amount: f32) -> f32 {
+        let amount = amount.clamp(0.0, 1.0);
+        let wrapped = phase < self.previous_phase;
+        self.previous_phase = phase;
+        if amount <= 0.0 {
+            self.target = 0.0;
+            self.offset = 0.0;
+            return phase;
+        }
+
+        if wrapped {
+            self.target = next_signed(&mut self.rng_state) * amount * HUMANIZE_MAX_CYCLE_SHIFT;
+        }
+        self.target = self.target.clamp(
+            -amount * HUMANIZE_MAX_CYCLE_SHIFT,
+            amount * HUMANIZE_MAX_CYCLE_SHIFT,
+        );
+        self.offset += (self.target - self.offset) * HUMANIZE_GLIDE;
+        (phase + self.offset).rem_euclid(1.0)
+    }
+}
+
+fn next_signed(state: &mut u32) -> f32 {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    *state = x;
+    (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+}
+
 /// Warp phase with a swing amount while preserving `[0, 1]` bounds.
 pub(crate) fn apply_swing(phase: f32, swing: f32) -> f32 {
     let p = phase.fract();
@@ -109,13 +243,66 @@ mod tests {
             tempo_bpm: 120.0,
             is_playing: true,
             song_pos_beats: None,
+            ..TransportState::default()
         });
         let second = clock.tick(TransportState {
             tempo_bpm: 120.0,
             is_playing: true,
             song_pos_beats: None,
+            ..TransportState::default()
         });
 
         assert!(second.beat_position > first.beat_position);
     }
+
+    #[test]
+    fn started_flags_only_the_first_sample_after_a_stop() {
+        let mut clock = TransportClock::new(48_000.0);
+        let mut transport = TransportState {
+            is_playing: true,
+            ..TransportState::default()
+        };
+        let _ = clock.tick(transport);
+        assert!(clock.started());
+        let _ = clock.tick(transport);
+        assert!(!clock.started());
+
+        transport.is_playing = false;
+        let _ = clock.tick(transport);
+        assert!(!clock.started());
+        transport.is_playing = true;
+        let _ = clock.tick(transport);
+        assert!(clock.started());
+    }
+
+    #[test]
+    fn bar_counter_increments_per_bar_and_resets_on_backward_jump() {
+        let mut clock = TransportClock::new(48_000.0);
+        // 3/4 at 120 BPM: one bar is 3 beats, 72_000 samples.
+        let mut transport = TransportState {
+            tempo_bpm: 120.0,
+            is_playing: true,
+            song_pos_beats: Some(0.0),
+            beats_per_bar: 3.0,
+        };
+        let _ = clock.tick(transport);
+        transport.song_pos_beats = None;
+        for _ in 0..(72_000 * 2 + 100) {
+            let _ = clock.tick(transport);
+            assert!(!clock.jumped_back());
+        }
+        assert_eq!(clock.bars_elapsed(), 2);
+
+        transport.song_pos_beats = Some(1.5);
+        let _ = clock.tick(transport);
+        assert!(clock.jumped_back());
+        assert_eq!(clock.bars_elapsed(), 0);
+
+        transport.song_pos_beats = None;
+        for _ in 0..72_000 {
+            let _ = clock.tick(transport);
+        }
+        assert!(!clock.jumped_back());
+        assert_eq!(clock.bars_elapsed(), 1);
+    }
 }
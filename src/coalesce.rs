@@ -0,0 +1,94 @@
+//! Per-frame coalescing of editor automation value pushes.
+
+use toybox::clack_plugin::prelude::ClapId;
+
+/// Latest pending automation value per parameter plus a deferred flush request.
+#[derive(Default)]
+pub(crate) struct ValueCoalescer {
+    pending: Vec<(ClapId, f32)>,
+    flush_requested: bool,
+}
+
+impl ValueCoalescer {
+    /// Record a value, replacing any earlier pending value for the same parameter.
+    pub(crate) fn push(&mut self, param_id: ClapId, value: f32) {
+        match self.pending.iter_mut().find(|(id, _)| *id == param_id) {
+            Some(entry) => entry.1 = value,
+            None => self.pending.push((param_id, value)),
+        }
+        self.flush_requested = true;
+    }
+
+    /// Remove and return the pending value for one parameter.
+    ///
+    /// Used before gesture begin/end events so values stay ordered inside their gesture.
+    pub(crate) fn take(&mut self, param_id: ClapId) -> Option<f32> {
+        let index = self.pending.iter().position(|(id, _)| *id == param_id)?;
+        Some(self.pending.remove(index).1)
+    }
+
+    /// Emit all pending values in first-touched order and clear them.
+    pub(crate) fn drain(&mut self, mut emit: impl FnMut(ClapId, f32)) {
+        for (param_id, value) in self.pending.drain(..) {
+            emit(param_id, value);
+        }
+    }
+
+    /// Ask for one host flush at the next frame boundary.
+    pub(crate) fn request_flush(&mut self) {
+        self.flush_requested = true;
+    }
+
+    /// Return whether a flush was requested since the last call, clearing the flag.
+    pub(crate) fn take_flush_request(&mut self) -> bool {
+        std::mem::take(&mut self.flush_requested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueCoalescer;
+    use toybox::clack_plugin::prelude::ClapId;
+
+    #[test]
+    fn pushes_within_a_frame_coalesce_per_param() {
+        let tension = ClapId::new(1);
+        let width = ClapId::new(7);
+        let mut coalescer = ValueCoalescer::default();
+        for step in 0..100 {
+            coalescer.push(tension, step as f32 / 100.0);
+            coalescer.push(width, 1.0 - step as f32 / 100.0);
+        }
+
+        let mut emitted = Vec::new();
+        coalescer.drain(|param_id, value| emitted.push((param_id, value)));
+
+        assert_eq!(emitted, vec![(tension, 0.99), (width, 1.0 - 0.99)]);
+        assert!(coalescer.take_flush_request());
+        assert!(!coalescer.take_flush_request());
+
+        let mut second = Vec::new();
+        coalescer.drain(|param_id, value| second.push((param_id, value)));
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn take_removes_only_the_requested_param_and_flush_requests_latch() {
+        let tension = ClapId::new(1);
+        let width = ClapId::new(7);
+        let mut coalescer = ValueCoalescer::default();
+        coalescer.push(tension, 0.4);
+        coalescer.push(width, 0.6);
+
+        assert_eq!(coalescer.take(tension), Some(0.4));
+        assert_eq!(coalescer.take(tension), None);
+
+        let mut emitted = Vec::new();
+        coalescer.drain(|param_id, value| emitted.push((param_id, value)));
+        assert_eq!(emitted, vec![(width, 0.6)]);
+
+        assert!(coalescer.take_flush_request());
+        coalescer.request_flush();
+        assert!(coalescer.take_flush_request());
+    }
+}
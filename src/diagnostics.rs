@@ -0,0 +1,113 @@
+//! Instability snapshots captured when the audio thread sanitizes non-finite audio.
+
+use std::sync::Mutex;
+
+use crate::params::TensionFieldSettings;
+
+/// Number of recent input frames kept for a snapshot.
+pub(crate) const INPUT_HISTORY_FRAMES: usize = 64;
+
+/// Where the non-finite value that triggered a snapshot was found.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum SanitizeSource {
+    /// The host delivered a NaN or infinite input sample.
+    Input,
+    /// The processing chain produced a NaN or infinite output or feedback sample.
+    Output,
+}
+
+impl SanitizeSource {
+    /// Return a short name for reports.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Output => "output",
+        }
+    }
+}
+
+/// Settings and recent input at the moment the sanitizer fired.
+///
+/// Only the editor reads the fields, so builds without it see them as unused.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(not(any(target_os = "windows", test)), allow(dead_code))]
+pub(crate) struct PanicSnapshot {
+    /// What was found to be non-finite.
+    pub(crate) source: SanitizeSource,
+    /// Parameter settings in effect for the block.
+    pub(crate) settings: TensionFieldSettings,
+    /// Most recent input frames, oldest first, ending with the offending frame.
+    pub(crate) recent_input: [(f32, f32); INPUT_HISTORY_FRAMES],
+}
+
+/// Ring of the most recent raw input frames.
+pub(crate) struct InputHistory {
+    frames: [(f32, f32); INPUT_HISTORY_FRAMES],
+    next: usize,
+}
+
+impl Default for InputHistory {
+    fn default() -> Self {
+        Self {
+            frames: [(0.0, 0.0); INPUT_HISTORY_FRAMES],
+            next: 0,
+        }
+    }
+}
+
+impl InputHistory {
+    /// Record one raw input frame.
+    pub(crate) fn push(&mut self, left: f32, right: f32) {
+        self.frames[self.next] = (left, right);
+        self.next = (self.next + 1) % INPUT_HISTORY_FRAMES;
+    }
+
+    /// Return the stored frames, oldest first.
+    pub(crate) fn recent(&self) -> [(f32, f32); INPUT_HISTORY_FRAMES] {
+        std::array::from_fn(|index| self.frames[(self.next + index) % INPUT_HISTORY_FRAMES])
+    }
+}
+
+/// Latest snapshot handed from the audio thread to the editor.
+#[derive(Default)]
+pub(crate) struct PanicSlot {
+    latest: Mutex<Option<PanicSnapshot>>,
+}
+
+impl PanicSlot {
+    /// Store a snapshot without blocking; it is dropped if the editor holds the lock.
+    pub(crate) fn publish(&self, snapshot: PanicSnapshot) {
+        if let Ok(mut latest) = self.latest.try_lock() {
+            *latest = Some(snapshot);
+        }
+    }
+
+    /// Return the most recent snapshot, if the sanitizer has fired.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn latest(&self) -> Option<PanicSnapshot> {
+        self.latest.lock().ok().and_then(|latest| *latest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{INPUT_HISTORY_FRAMES, InputHistory};
+
+    #[test]
+    fn input_history_returns_frames_oldest_first() {
+        let mut history = InputHistory::default();
+        for n in 0..(INPUT_HISTORY_FRAMES + 10) {
+            history.push(n as f32, -(n as f32));
+        }
+        let recent = history.recent();
+        assert_eq!(recent[0], (10.0, -10.0));
+        assert_eq!(
+            recent[INPUT_HISTORY_FRAMES - 1],
+            (
+                (INPUT_HISTORY_FRAMES + 9) as f32,
+                -((INPUT_HISTORY_FRAMES + 9) as f32)
+            )
+        );
+    }
+}
@@ -1,11 +1,100 @@
 //! Core DSP for the Tension Field effect.
 
-use std::f32::consts::TAU;
+use std::f32::consts::{PI, TAU};
 
 use crate::clock::{TransportClock, TransportState};
-use crate::gesture::{GestureEngine, GestureInput};
+use crate::diagnostics::{InputHistory, PanicSnapshot, SanitizeSource};
+use crate::gesture::{GestureEngine, GestureFrame, GestureInput, StepSequence};
 use crate::mod_matrix::ModMatrix;
-use crate::params::{CharacterMode, TensionFieldSettings, WarpColor};
+use crate::params::{
+    CharacterMode, ClipMode, INPUT_GATE_OFF_DB, KeyLock, ModRateMode, SEQ_STEP_COUNT,
+    TensionFieldSettings, TensionMeterSource, WarpColor,
+};
+
+/// Pre-clip level above which the soft clipper is considered to be engaging hard.
+const OVERSHOOT_LEVEL: f32 = 1.0;
+/// Lowest level shown by dBFS meters; quieter peaks read as this floor.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) const METER_FLOOR_DBFS: f32 = -60.0;
+
+/// Peak level the safety limiter holds the output under before soft clipping.
+pub(crate) const LIMITER_CEILING: f32 = 0.95;
+/// Reactive limiter attack time used when lookahead is off.
+const LIMITER_ATTACK_SECONDS: f32 = 0.001;
+/// Limiter gain recovery time from full reduction back to unity.
+const LIMITER_RELEASE_SECONDS: f32 = 0.08;
+/// True-peak limiter ceiling (-0.5 dBTP), leaving headroom for what a 2x detector misses.
+const TRUE_PEAK_CEILING: f32 = 0.944;
+/// Fixed lookahead of the optional true-peak limiter.
+const TRUE_PEAK_LOOKAHEAD_SECONDS: f32 = 0.0015;
+/// Samples the true-peak detector holds back so each sample sees both neighbouring midpoints.
+const TRUE_PEAK_DETECTOR_DELAY: usize = 2;
+
+/// Samples ahead of the elastic read head scanned for transient marks.
+const TRANSIENT_READ_AHEAD: f32 = 48.0;
+/// Per-sample decay of the elastic transient hold once an attack has passed.
+const TRANSIENT_HOLD_DECAY: f32 = 0.9995;
+
+/// Crossfade time between the old and new character processing on a mode change.
+const CHARACTER_FADE_SECONDS: f32 = 0.02;
+
+/// Signal level whose peak the character drive makeup holds constant.
+const DRIVE_REFERENCE_LEVEL: f32 = 0.35;
+
+/// Longest safety limiter lookahead in milliseconds.
+const MAX_LIMIT_LOOKAHEAD_MS: f32 = 10.0;
+
+/// Cycle offset of the right-channel gesture in dual-gesture mode.
+const DUAL_GESTURE_CYCLE_OFFSET: f32 = 0.25;
+/// Random seed of the right-channel gesture in dual-gesture mode.
+const DUAL_GESTURE_SEED: u32 = 0x6C07_8965;
+
+/// Per-sample smoothing of the energy ceiling's mean-square estimate (about 40 ms at 48 kHz).
+const ENERGY_RMS_COEFF: f32 = 0.0005;
+
+/// Host tempo at which tempo-followed free rates equal their stored values.
+const TEMPO_FOLLOW_REFERENCE_BPM: f32 = 120.0;
+
+/// Peak level below which input and output count as silent for the idle bypass.
+const IDLE_SILENCE_LEVEL: f32 = 1.0e-5;
+/// Continuous silence in and out required before the idle bypass arms, on top of
+/// the time the usable elastic window and the space ring take to drain.
+const IDLE_BYPASS_SECONDS: f32 = 0.5;
+
+/// Level relative to the input at which a reported tail counts as decayed (-60 dB).
+const TAIL_DECAY_LEVEL: f32 = 0.001;
+/// Longest delay the elastic buffer can read back, in seconds.
+///
+/// The buffer is allocated at this size up front; `Elastic Size` only narrows
+/// the usable window, so changing it never allocates on the audio thread.
+const ELASTIC_MAX_DELAY_SECONDS: f32 = 6.0;
+
+/// Mid-channel gain the input gate settles to when closed; the side is removed entirely.
+const INPUT_GATE_FLOOR: f32 = 0.1;
+/// Input gate opening time in seconds.
+const INPUT_GATE_ATTACK_SECONDS: f32 = 0.001;
+/// Input gate closing time in seconds.
+const INPUT_GATE_RELEASE_SECONDS: f32 = 0.08;
+
+/// Performance input that a [`NoteTrigger`] changes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum TriggerSource {
+    /// Held notes on the note input gate the pull.
+    Notes,
+    /// MIDI sustain (CC64) holds a pull after its trigger is released.
+    SustainPedal,
+}
+
+/// Note gate change at a sample offset within the current block.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct NoteTrigger {
+    /// Frame offset from the start of the block.
+    pub offset: usize,
+    /// Which gate this event changes.
+    pub source: TriggerSource,
+    /// Whether the gate is down from this frame on.
+    pub pressed: bool,
+}
 
 /// Per-block metering information exported to the GUI thread.
 #[derive(Debug, Copy, Clone, Default)]
@@ -18,37 +107,121 @@ pub(crate) struct RenderReport {
     pub elastic_activity: f32,
     /// Warp stage activity (0..1).
     pub warp_activity: f32,
-    /// Space stage activity (0..1).
+    /// Space stage peak activity (0..1).
     pub space_activity: f32,
+    /// Space stage RMS activity reflecting sustained diffusion (0..1).
+    pub space_rms: f32,
     /// Feedback path activity (0..1).
     pub feedback_activity: f32,
     /// Output left activity (0..1).
     pub output_left: f32,
     /// Output right activity (0..1).
     pub output_right: f32,
-    /// Tension drive activity (0..1).
+    /// Tension meter activity (0..1), from the source chosen by the Tension Meter setting.
     pub tension_activity: f32,
+    /// Ducking detector level (0..1), from the sidechain when one is connected.
+    pub duck_level: f32,
+    /// Absolute peak output level of the elastic stage (linear).
+    pub elastic_level: f32,
+    /// Absolute peak output level of the warp stage (linear).
+    pub warp_level: f32,
+    /// Absolute peak output level of the space stage (linear).
+    pub space_level: f32,
+    /// Absolute peak level of the feedback path (linear).
+    pub feedback_level: f32,
+    /// Whether the output drove the soft clipper hard during the block.
+    pub output_overshoot: bool,
+    /// Gesture pull-cycle phase (0..1) at the end of the block.
+    pub gesture_phase: f32,
+    /// Bars elapsed since playback started or last looped back.
+    pub bar_count: u32,
+    /// Mod matrix source A and B values (-1..1) at the end of the block.
+    pub mod_sources: [f32; 2],
+    /// Smoothed elastic-buffer delay in milliseconds at the end of the block.
+    pub elastic_delay_ms: f32,
+    /// Modulation applied to the elastic, warp, space, and feedback stages'
+    /// key parameters (tension, warp motion, width, feedback) at the end of the block.
+    pub stage_modulation: [f32; 4],
+    /// Deepest true-peak limiter gain reduction during the block, in dB (0 = none).
+    pub true_peak_reduction_db: f32,
+}
+
+impl RenderReport {
+    /// Fold a later block into this report, keeping the peak of each field.
+    ///
+    /// The gesture phase, bar count, mod source and stage modulation values, and
+    /// elastic delay are positions rather than levels, so the later block wins.
+    pub(crate) fn merge(&mut self, other: RenderReport) {
+        self.input_left = self.input_left.max(other.input_left);
+        self.input_right = self.input_right.max(other.input_right);
+        self.elastic_activity = self.elastic_activity.max(other.elastic_activity);
+        self.warp_activity = self.warp_activity.max(other.warp_activity);
+        self.space_activity = self.space_activity.max(other.space_activity);
+        self.space_rms = self.space_rms.max(other.space_rms);
+        self.feedback_activity = self.feedback_activity.max(other.feedback_activity);
+        self.output_left = self.output_left.max(other.output_left);
+        self.output_right = self.output_right.max(other.output_right);
+        self.tension_activity = self.tension_activity.max(other.tension_activity);
+        self.duck_level = self.duck_level.max(other.duck_level);
+        self.elastic_level = self.elastic_level.max(other.elastic_level);
+        self.warp_level = self.warp_level.max(other.warp_level);
+        self.space_level = self.space_level.max(other.space_level);
+        self.feedback_level = self.feedback_level.max(other.feedback_level);
+        self.true_peak_reduction_db = self
+            .true_peak_reduction_db
+            .max(other.true_peak_reduction_db);
+        self.output_overshoot |= other.output_overshoot;
+        self.gesture_phase = other.gesture_phase;
+        self.bar_count = other.bar_count;
+        self.mod_sources = other.mod_sources;
+        self.elastic_delay_ms = other.elastic_delay_ms;
+        self.stage_modulation = other.stage_modulation;
+    }
 }
 
 /// Audio engine implementing transport-aware gestures, modulation, and signal stages.
 pub(crate) struct TensionFieldEngine {
     sample_rate: f32,
     clock: TransportClock,
-    pre_left: PreEmphasis,
-    pre_right: PreEmphasis,
+    stages: SignalStages,
+    feedback_tail: SignalStages,
     gesture: GestureEngine,
+    gesture_right: GestureEngine,
     modulation: ModMatrix,
-    elastic: ElasticBuffer,
-    warp_left: SpectralWarp,
-    warp_right: SpectralWarp,
-    space: SpaceStage,
+    output_chain: OutputChain,
+    dry_align: DryAlign,
+    input_gate: InputGate,
+    character_fade: CharacterFade,
+    transient_detector: TransientDetector,
+    feedback_hpf: FeedbackHighPass,
+    feedback_tone: FeedbackTone,
     feedback_left: f32,
     feedback_right: f32,
     input_env: f32,
+    duck_env: f32,
+    sidechain: Vec<f32>,
+    sidechain_read: usize,
     high_env: f32,
+    energy_mean_square: f32,
     safety_gain: f32,
     previous_input_abs: f32,
+    note_gate: bool,
+    sustain_pedal: bool,
     output_gain: f32,
+    mix: f32,
+    char_drive: f32,
+    space_scale: f32,
+    haas_samples: f32,
+    image_rotation: f32,
+    idle_samples: usize,
+    sleep_samples: usize,
+    tail_pending: bool,
+    idle_bypass_armed: bool,
+    feedback_solo: bool,
+    input_history: InputHistory,
+    panic_snapshot: Option<PanicSnapshot>,
+    controls: Option<SmoothedControls>,
+    step_pattern: [f32; SEQ_STEP_COUNT],
 }
 
 impl TensionFieldEngine {
@@ -57,61 +230,331 @@ impl TensionFieldEngine {
         Self {
             sample_rate,
             clock: TransportClock::new(sample_rate),
-            pre_left: PreEmphasis::default(),
-            pre_right: PreEmphasis::default(),
+            stages: SignalStages::new(sample_rate),
+            feedback_tail: SignalStages::new(sample_rate),
             gesture: GestureEngine::default(),
+            gesture_right: GestureEngine::with_offset(DUAL_GESTURE_CYCLE_OFFSET, DUAL_GESTURE_SEED),
             modulation: ModMatrix::default(),
-            elastic: ElasticBuffer::new(sample_rate),
-            warp_left: SpectralWarp::new(37, 73),
-            warp_right: SpectralWarp::new(43, 79),
-            space: SpaceStage::default(),
+            output_chain: OutputChain::new(sample_rate),
+            dry_align: DryAlign::new(0),
+            input_gate: InputGate::new(sample_rate),
+            character_fade: CharacterFade::new(sample_rate),
+            transient_detector: TransientDetector::default(),
+            feedback_hpf: FeedbackHighPass::new(sample_rate),
+            feedback_tone: FeedbackTone::new(sample_rate),
             feedback_left: 0.0,
             feedback_right: 0.0,
             input_env: 0.0,
+            duck_env: 0.0,
+            sidechain: Vec::new(),
+            sidechain_read: 0,
             high_env: 0.0,
+            energy_mean_square: 0.0,
             safety_gain: 1.0,
             previous_input_abs: 0.0,
+            note_gate: false,
+            sustain_pedal: false,
             output_gain: 1.0,
+            mix: 1.0,
+            char_drive: 1.0,
+            space_scale: 1.0,
+            haas_samples: 0.0,
+            image_rotation: 0.0,
+            idle_samples: 0,
+            sleep_samples: 0,
+            tail_pending: false,
+            idle_bypass_armed: false,
+            feedback_solo: false,
+            input_history: InputHistory::default(),
+            panic_snapshot: None,
+            controls: None,
+            step_pattern: [0.0; SEQ_STEP_COUNT],
+        }
+    }
+
+    /// Take the snapshot recorded the last time non-finite audio was sanitized.
+    pub(crate) fn take_panic_snapshot(&mut self) -> Option<PanicSnapshot> {
+        self.panic_snapshot.take()
+    }
+
+    /// Set the safety limiter lookahead, fixed for the lifetime of one activation.
+    ///
+    /// The ducking detector keeps reading the undelayed input, so ducking leads
+    /// the delayed output by the same amount and total latency stays equal to
+    /// the limiter lookahead.
+    pub(crate) fn set_limit_lookahead_ms(&mut self, lookahead_ms: f32) {
+        let lookahead = lookahead_samples(self.sample_rate, lookahead_ms);
+        self.output_chain.limiter = PeakLimiter::new(self.sample_rate, lookahead);
+        self.dry_align = DryAlign::new(self.output_chain.latency());
+    }
+
+    /// Enable or bypass the true-peak limiter, fixed for the lifetime of one activation.
+    ///
+    /// Its lookahead and detector delay add to the reported latency, so the
+    /// dry path is realigned to the new total.
+    pub(crate) fn set_true_peak_limit(&mut self, enabled: bool) {
+        self.output_chain.true_peak = enabled.then(|| TruePeakLimiter::new(self.sample_rate));
+        self.dry_align = DryAlign::new(self.output_chain.latency());
+    }
+
+    /// Reserve sidechain storage for blocks of up to `max_frames`; call before processing.
+    pub(crate) fn reserve_sidechain(&mut self, max_frames: usize) {
+        self.sidechain.reserve_exact(max_frames);
+    }
+
+    /// Drive the ducking detector from per-frame sidechain levels for the next block.
+    ///
+    /// `None` means no sidechain is connected and ducking follows the main input.
+    /// Levels beyond the capacity from [`Self::reserve_sidechain`] are dropped so the
+    /// audio thread never allocates.
+    pub(crate) fn set_sidechain(&mut self, levels: Option<&[f32]>) {
+        self.sidechain.clear();
+        if let Some(levels) = levels {
+            let frames = levels.len().min(self.sidechain.capacity());
+            self.sidechain.extend_from_slice(&levels[..frames]);
+        }
+        self.sidechain_read = 0;
+    }
+
+    /// Use these step intensities for the gesture sequencer from the next block on.
+    pub(crate) fn set_step_pattern(&mut self, steps: [f32; SEQ_STEP_COUNT]) {
+        self.step_pattern = steps;
+    }
+
+    /// Audition a single modulation destination, or restore all with `None`.
+    pub(crate) fn set_mod_solo(&mut self, destination: Option<usize>) {
+        self.modulation.set_solo(destination);
+    }
+
+    /// Output only the regenerated feedback tail while `enabled`.
+    pub(crate) fn set_feedback_solo(&mut self, enabled: bool) {
+        if enabled && !self.feedback_solo {
+            self.feedback_tail.elastic.align_to(&self.stages.elastic);
         }
+        self.feedback_solo = enabled;
+    }
+
+    /// Return whether `lookahead_ms` maps to the lookahead currently in use.
+    pub(crate) fn limit_lookahead_matches(&self, lookahead_ms: f32) -> bool {
+        lookahead_samples(self.sample_rate, lookahead_ms) == self.output_chain.limiter.lookahead
     }
 
-    /// Process one stereo block in place.
+    /// Return whether the true-peak limiter is in the state `enabled` asks for.
+    pub(crate) fn true_peak_limit_matches(&self, enabled: bool) -> bool {
+        self.output_chain.true_peak.is_some() == enabled
+    }
+
+    /// Latency in samples introduced by the limiter lookaheads.
+    ///
+    /// These are the engine's only fixed delays, and the dry path is held back by
+    /// exactly this much. The elastic delay is the effect itself and keeps
+    /// moving with tension and gestures, so it is not reported or compensated.
+    pub(crate) fn latency_samples(&self) -> u32 {
+        self.output_chain.latency() as u32
+    }
+
+    /// Samples the output can keep ringing after the input stops.
+    ///
+    /// The space diffusers ring until their allpass gain has decayed to
+    /// [`TAIL_DECAY_LEVEL`], and each feedback pass adds up to the longest
+    /// usable elastic delay plus that ring, repeated until the loop gain has decayed too.
+    pub(crate) fn tail_samples(&self, settings: &TensionFieldSettings) -> u32 {
+        let pass = settings.elastic_size_seconds * self.sample_rate + space_ring_samples(settings);
+        let feedback = settings.feedback.clamp(0.0, 0.99);
+        let passes = if feedback > 0.0 {
+            TAIL_DECAY_LEVEL.ln() / feedback.ln()
+        } else {
+            0.0
+        };
+        (pass * (1.0 + passes)).ceil() as u32
+    }
+
+    /// Whether input and internal state have been silent for a whole reported tail,
+    /// so the host can stop processing without cutting anything off.
+    pub(crate) fn sleeping(&self) -> bool {
+        self.idle_bypass_armed && self.idle_samples >= self.sleep_samples
+    }
+
+    /// Whether the last block had silent input while the output was still ringing.
+    pub(crate) fn tail_pending(&self) -> bool {
+        self.tail_pending
+    }
+
+    /// Process one stereo block in place without note input.
+    #[cfg(test)]
     pub(crate) fn render(
         &mut self,
         settings: &TensionFieldSettings,
         left: &mut [f32],
         right: &mut [f32],
         transport: TransportState,
+    ) -> RenderReport {
+        self.clock.begin_block(transport);
+        self.render_frames(settings, left, right, transport)
+    }
+
+    /// Process one stereo block, applying note gate changes at their sample offsets.
+    ///
+    /// `triggers` must be sorted by offset, as host event lists are. The block is
+    /// split at each offset so quantize timing and one-shot starts land on the
+    /// exact frame of the note event.
+    pub(crate) fn render_with_triggers(
+        &mut self,
+        settings: &TensionFieldSettings,
+        left: &mut [f32],
+        right: &mut [f32],
+        transport: TransportState,
+        triggers: &[NoteTrigger],
+    ) -> RenderReport {
+        let frames = left.len().min(right.len());
+        self.clock.begin_block(transport);
+
+        let mut report = RenderReport::default();
+        let mut segment_transport = transport;
+        let mut start = 0;
+        for trigger in triggers {
+            let offset = trigger.offset.min(frames);
+            if offset > start {
+                report.merge(self.render_frames(
+                    settings,
+                    &mut left[start..offset],
+                    &mut right[start..offset],
+                    segment_transport,
+                ));
+                // Only the first segment starts at the host-reported song position.
+                segment_transport.song_pos_beats = None;
+                start = offset;
+            }
+            match trigger.source {
+                TriggerSource::Notes => self.note_gate = trigger.pressed,
+                TriggerSource::SustainPedal => self.sustain_pedal = trigger.pressed,
+            }
+        }
+        if frames > start {
+            report.merge(self.render_frames(
+                settings,
+                &mut left[start..frames],
+                &mut right[start..frames],
+                segment_transport,
+            ));
+        }
+        report
+    }
+
+    /// Process a single-channel block as dual mono and fold the result back to mono.
+    ///
+    /// `scratch` carries the duplicated right channel; frames beyond its length are
+    /// left untouched.
+    pub(crate) fn render_mono_with_triggers(
+        &mut self,
+        settings: &TensionFieldSettings,
+        mono: &mut [f32],
+        scratch: &mut [f32],
+        transport: TransportState,
+        triggers: &[NoteTrigger],
+    ) -> RenderReport {
+        let frames = mono.len().min(scratch.len());
+        let mono = &mut mono[..frames];
+        let scratch = &mut scratch[..frames];
+        scratch.copy_from_slice(mono);
+        let report = self.render_with_triggers(settings, mono, scratch, transport, triggers);
+        for (left, right) in mono.iter_mut().zip(scratch.iter()) {
+            *left = (*left + *right) * 0.5;
+        }
+        report
+    }
+
+    fn render_frames(
+        &mut self,
+        settings: &TensionFieldSettings,
+        left: &mut [f32],
+        right: &mut [f32],
+        transport: TransportState,
     ) -> RenderReport {
         let frames = left.len().min(right.len());
         if frames == 0 {
             return RenderReport::default();
         }
+        let followed;
+        let settings = if settings.tempo_follow {
+            followed = tempo_followed(settings, transport.tempo_bpm);
+            &followed
+        } else {
+            settings
+        };
+        // A frozen loop or scrub can replay old audio from silent input, so it never idles.
+        let holding = settings.freeze || (settings.scrub && settings.hold);
+        self.sleep_samples = self.tail_samples(settings) as usize;
+        if self.idle_bypass_armed && !holding && block_is_silent(&left[..frames], &right[..frames])
+        {
+            return self.bypass_idle_frames(&mut left[..frames], &mut right[..frames], transport);
+        }
+        self.idle_bypass_armed = false;
 
         let mut input_left_peak = 0.0_f32;
         let mut input_right_peak = 0.0_f32;
         let mut elastic_peak = 0.0_f32;
         let mut warp_peak = 0.0_f32;
-        let mut space_peak = 0.0_f32;
+        let mut space_meter = ActivityMeter::default();
         let mut feedback_peak = 0.0_f32;
+        let mut elastic_level = 0.0_f32;
+        let mut warp_level = 0.0_f32;
+        let mut space_level = 0.0_f32;
         let mut output_left_peak = 0.0_f32;
         let mut output_right_peak = 0.0_f32;
         let mut tension_peak = 0.0_f32;
+        let mut duck_peak = 0.0_f32;
+        let mut pre_clip_peak = 0.0_f32;
+        let mut gesture_phase = 0.0_f32;
+        let mut stage_modulation = [0.0_f32; 4];
+        let mut true_peak_gain = 1.0_f32;
 
+        let gate_threshold = (settings.input_gate_db > INPUT_GATE_OFF_DB)
+            .then(|| db_to_gain(settings.input_gate_db));
+        let control_targets = SmoothedControls::from_settings(settings);
+        let mut controls = self.controls.unwrap_or(control_targets);
         let mut transport_for_sample = transport;
         for (l, r) in left.iter_mut().zip(right.iter_mut()).take(frames) {
-            let in_l = *l;
-            let in_r = *r;
+            controls.advance(&control_targets);
+            self.input_history.push(*l, *r);
+            let (in_l, in_r) = if l.is_finite() && r.is_finite() {
+                (*l, *r)
+            } else {
+                self.capture_panic(settings, SanitizeSource::Input);
+                (0.0, 0.0)
+            };
             input_left_peak = input_left_peak.max(in_l.abs());
             input_right_peak = input_right_peak.max(in_r.abs());
+            let (dry_l, dry_r) = self.dry_align.process(in_l, in_r);
+            let (in_l, in_r) = self.input_gate.process(in_l, in_r, gate_threshold);
 
             let input_abs = in_l.abs().max(in_r.abs());
-            self.input_env += (input_abs - self.input_env) * (0.01 + settings.ducking * 0.08);
+            let attack_mark = self.transient_detector.process(input_abs);
+            let duck_coeff = 0.01 + controls.ducking * 0.08;
+            self.input_env += (input_abs - self.input_env) * duck_coeff;
+            let duck_source = match self.sidechain.get(self.sidechain_read) {
+                Some(level) => {
+                    self.sidechain_read += 1;
+                    *level
+                }
+                None => input_abs,
+            };
+            self.duck_env += (duck_source - self.duck_env) * duck_coeff;
+            duck_peak = duck_peak.max(self.duck_env.clamp(0.0, 1.0));
             let transient = (input_abs - self.previous_input_abs).max(0.0);
             self.previous_input_abs = input_abs;
 
             let clock = self.clock.tick(transport_for_sample);
             transport_for_sample.song_pos_beats = None;
+            if self.clock.jumped_back() {
+                // Loop points and relocations restart free-running cycles on the new downbeat.
+                self.gesture.reset_phase();
+                self.gesture_right.reset_phase();
+                self.modulation.reset_phases();
+            }
+            if self.clock.started() {
+                self.modulation.transport_started(&settings.modulation);
+            }
 
             let mod_values = self.modulation.next(
                 &settings.modulation,
@@ -119,103 +562,202 @@ impl TensionFieldEngine {
                 self.input_env,
                 self.sample_rate,
             );
+            stage_modulation = stage_modulation_values(mod_values);
 
-            let tension = (settings.tension + mod_values[0]).clamp(0.0, 1.0);
-            let pull_direction = (settings.pull_direction + mod_values[1]).clamp(-1.0, 1.0);
-            let grain = (settings.grain_continuity + mod_values[2]).clamp(0.0, 1.0);
-            let width = (settings.width + mod_values[3]).clamp(0.0, 1.0);
-            let warp_motion = (settings.warp_motion + mod_values[4]).clamp(0.0, 1.0);
+            let tension = (controls.tension + mod_values[0])
+                .clamp(0.0, 1.0)
+                .max(settings.tension_floor);
+            // While scrubbing, the map X axis picks the frozen read point instead of a direction.
+            let scrub =
+                (settings.scrub && settings.hold).then_some((settings.pull_direction + 1.0) * 0.5);
+            let pull_direction = if scrub.is_some() {
+                0.0
+            } else {
+                (controls.pull_direction + mod_values[1]).clamp(-1.0, 1.0)
+            };
+            let grain = (controls.grain_continuity + mod_values[2]).clamp(0.0, 1.0);
+            let width = (controls.width
+                + mod_values[3]
+                + elasticity_width_offset(controls.elasticity, settings.elasticity_to_width))
+            .clamp(0.0, 1.0);
+            let warp_motion = warp_motion_curve(
+                (controls.warp_motion + mod_values[4]).clamp(0.0, 1.0),
+                settings.warp_motion_curve,
+            );
             let tension_excite = (transient * (4.0 + tension * 7.0)).clamp(0.0, 1.0);
             let warp_motion = (warp_motion + tension_excite * 0.22).clamp(0.0, 1.0);
-            let feedback =
-                (settings.feedback + mod_values[5] + tension_excite * 0.05).clamp(0.0, 0.7);
-
-            let gesture = self.gesture.next(
-                GestureInput {
-                    tension,
-                    tension_bias: settings.tension_bias,
-                    time_mode: settings.time_mode,
-                    pull_rate_hz: settings.pull_rate_hz,
-                    pull_division: settings.pull_division,
-                    swing: settings.swing,
-                    pull_shape: settings.pull_shape,
-                    pull_trigger: settings.pull_trigger,
-                    pull_latch: settings.pull_latch,
-                    pull_quantize: settings.pull_quantize,
-                    rebound: settings.rebound,
-                    release_snap: settings.release_snap,
-                    pull_direction,
-                    elasticity: settings.elasticity,
-                },
-                self.sample_rate,
-                clock,
-            );
-            tension_peak = tension_peak.max(gesture.tension_drive);
-
-            let duck_gain = 1.0 - settings.ducking * self.input_env.clamp(0.0, 1.0) * 0.85;
-            let feedback_l = self.feedback_left * feedback * duck_gain * self.safety_gain;
-            let feedback_r = self.feedback_right * feedback * duck_gain * self.safety_gain;
-            feedback_peak = feedback_peak.max(feedback_l.abs().max(feedback_r.abs()));
+            // Transients only push feedback that is already engaged, so zero stays silent.
+            let feedback_base = controls.feedback + mod_values[5];
+            let feedback = if feedback_base > 0.0 {
+                (feedback_base + tension_excite * 0.05).clamp(0.0, 0.7)
+            } else {
+                0.0
+            };
+            let width = feedback_width_safety(width, feedback, settings.width_safety);
 
-            let pre_l = self
-                .pre_left
-                .process(in_l + feedback_l, gesture.tension_drive, grain);
-            let pre_r = self
-                .pre_right
-                .process(in_r + feedback_r, gesture.tension_drive, grain);
+            let gesture_input = GestureInput {
+                tension,
+                tension_bias: settings.tension_bias,
+                time_mode: settings.time_mode,
+                pull_rate_hz: settings.pull_rate_hz,
+                pull_division: settings.pull_division,
+                rate_multiplier: settings.rate_multiplier,
+                swing: settings.swing,
+                humanize: settings.humanize,
+                pull_shape: settings.pull_shape,
+                shape_smooth: settings.shape_smooth,
+                pull_phase: settings.pull_phase,
+                pull_trigger: settings.pull_trigger || self.note_gate,
+                pull_latch: settings.pull_latch,
+                sustain: settings.sustain_pedal && self.sustain_pedal,
+                pull_quantize: settings.pull_quantize,
+                quantize_multiple: settings.quantize_multiple,
+                quantize_strength: settings.quantize_strength,
+                rebound: settings.rebound,
+                release_snap: settings.release_snap,
+                release_shape: settings.release_shape,
+                pull_direction,
+                elasticity: controls.elasticity,
+                elastic_size: settings.elastic_size_seconds,
+                idle_motion: settings.idle_motion,
+                warp_rate: settings.warp_rate_hz,
+                warp_rate_mode: settings.warp_rate_mode,
+                direction_return: settings.dir_return,
+                sequencer: settings.sequencer.then_some(StepSequence {
+                    steps: self.step_pattern,
+                    length: settings.seq_steps,
+                    division: settings.seq_division,
+                }),
+            };
+            let gesture = self.gesture.next(gesture_input, self.sample_rate, clock);
+            // Dual mono: the right channel strains on its own offset cycle.
+            let gesture_right = settings.dual_gesture.then(|| {
+                self.gesture_right
+                    .next(gesture_input, self.sample_rate, clock)
+            });
+            tension_peak = tension_peak.max(tension_meter_value(
+                settings.tension_meter,
+                &gesture,
+                tension,
+            ));
+            gesture_phase = gesture.phase;
 
-            let character_dirty = settings.character != CharacterMode::Clean;
-            let (elastic_l, elastic_r) = self.elastic.process(
-                pre_l,
-                pre_r,
-                ElasticControl {
-                    delay_samples: gesture.delay_samples,
-                    velocity: gesture.velocity,
-                    pitch_coupling: settings.pitch_coupling,
-                    grain_amount: grain,
-                    elasticity: settings.elasticity,
-                    dirty: character_dirty,
-                },
+            let duck_gain = 1.0 - controls.ducking * self.duck_env.clamp(0.0, 1.0) * 0.85;
+            let polarity = if settings.feedback_invert { -1.0 } else { 1.0 };
+            let feedback_gain = feedback * duck_gain * self.safety_gain * polarity;
+            let (balance_l, balance_r) = feedback_balance_scales(settings.feedback_balance);
+            let (feedback_l, feedback_r) = self.feedback_hpf.process(
+                self.feedback_left,
+                self.feedback_right,
+                settings.feedback_hpf_hz,
             );
-            elastic_peak =
-                elastic_peak.max((elastic_l - pre_l).abs().max((elastic_r - pre_r).abs()));
+            let (feedback_l, feedback_r) =
+                self.feedback_tone
+                    .process(feedback_l, feedback_r, settings.feedback_tone);
+            let feedback_l = feedback_l * feedback_gain * balance_l;
+            let feedback_r = feedback_r * feedback_gain * balance_r;
+            feedback_peak = feedback_peak.max(feedback_l.abs().max(feedback_r.abs()));
 
+            let character_dirty = settings.character != CharacterMode::Clean;
+            self.character_fade.advance(settings.character);
+            let dirty_mix = self
+                .character_fade
+                .mix(|character| (character != CharacterMode::Clean) as u8 as f32);
+            let elastic_control = ElasticControl {
+                delay_samples: gesture.delay_samples,
+                velocity: gesture.velocity,
+                pitch_coupling: settings.pitch_coupling,
+                key_lock: settings.key_lock,
+                grain_amount: grain,
+                grain_bias: settings.grain_bias,
+                spring: (controls.elasticity + settings.spring).clamp(0.0, 1.0),
+                dirty: character_dirty,
+                transient_mark: attack_mark * settings.transient_preserve.clamp(0.0, 1.0),
+                scrub,
+                window_samples: settings.elastic_size_seconds * self.sample_rate,
+                freeze: settings.freeze,
+                reverse: settings.elastic_reverse,
+            };
+            let right_control = gesture_right.map(|frame| ElasticControl {
+                delay_samples: frame.delay_samples,
+                velocity: frame.velocity,
+                ..elastic_control
+            });
             let warp_control = WarpControl {
                 tension: gesture.tension_drive,
-                diffusion: settings.diffusion,
-                elasticity: settings.elasticity,
+                diffusion: controls.diffusion,
+                elasticity: controls.elasticity,
                 air_damping: settings.air_damping,
                 air_compensation: settings.air_compensation,
                 drift_phase_inc: gesture.drift_phase_inc,
                 warp_motion,
                 color: settings.warp_color,
+                high_color: settings.warp_high_color,
                 character: settings.character,
+                low_keep_hz: settings.warp_low_keep_hz,
+                air_freq_hz: settings.air_freq_hz,
+            };
+            self.space_scale += (space_size_scale(settings.space_size) - self.space_scale) * 0.001;
+            let haas_target = settings.haas_ms.clamp(0.0, MAX_HAAS_MS) * 0.001 * self.sample_rate;
+            // Snap to zero so an off Haas delay bypasses the line exactly.
+            self.haas_samples = if haas_target > 0.0 {
+                self.haas_samples + (haas_target - self.haas_samples) * 0.001
+            } else {
+                0.0
+            };
+            let rotation_target = settings.image_rotate.clamp(-45.0, 45.0).to_radians();
+            self.image_rotation += (rotation_target - self.image_rotation) * 0.001;
+            let stage_control = StageControl {
+                tension_drive: gesture.tension_drive,
+                grain,
+                elastic: elastic_control,
+                right_elastic: right_control,
+                warp: warp_control,
+                space: SpaceControl {
+                    width,
+                    diffusion: controls.diffusion,
+                    scale: self.space_scale,
+                    tone: controls.space_tone,
+                    haas_samples: self.haas_samples,
+                    rotation: self.image_rotation,
+                    dirty: dirty_mix,
+                },
             };
-            let warped_l = self.warp_left.process(elastic_l, warp_control);
-            let warped_r = self.warp_right.process(elastic_r, warp_control);
+            let taps = self
+                .stages
+                .process(in_l + feedback_l, in_r + feedback_r, stage_control);
+            let (pre_l, pre_r) = taps.pre;
+            let (elastic_l, elastic_r) = taps.elastic;
+            let (warped_l, warped_r) = taps.warped;
+            let (space_l, space_r) = taps.space;
+            elastic_peak =
+                elastic_peak.max((elastic_l - pre_l).abs().max((elastic_r - pre_r).abs()));
             warp_peak = warp_peak.max(
                 (warped_l - elastic_l)
                     .abs()
                     .max((warped_r - elastic_r).abs()),
             );
 
-            let (space_l, space_r) = self.space.process(
-                warped_l,
-                warped_r,
-                width,
-                settings.diffusion,
-                character_dirty,
-            );
-            space_peak = space_peak.max((space_l - warped_l).abs().max((space_r - warped_r).abs()));
+            space_meter.push(space_l - warped_l, space_r - warped_r);
+            elastic_level = elastic_level.max(elastic_l.abs().max(elastic_r.abs()));
+            warp_level = warp_level.max(warped_l.abs().max(warped_r.abs()));
+            space_level = space_level.max(space_l.abs().max(space_r.abs()));
 
             let high_proxy = ((warped_l - elastic_l).abs() + (warped_r - elastic_r).abs()) * 0.5
                 + tension_excite * 0.2;
             self.high_env += (high_proxy - self.high_env) * 0.02;
-            let energy = ((space_l * space_l + space_r * space_r) * 0.5).sqrt();
-            let energy_mix = energy * 0.65 + self.high_env * 0.35;
-            let threshold = lerp(0.2, 1.0, settings.energy_ceiling.clamp(0.0, 1.0));
-            let over = (energy_mix - threshold).max(0.0);
-            let target_safety = 1.0 / (1.0 + over * 2.6);
+            // A slow RMS of the post-space signal rides program level rather
+            // than single peaks; a ceiling at 100% leaves the output untouched.
+            let mean_square = (space_l * space_l + space_r * space_r) * 0.5;
+            self.energy_mean_square += (mean_square - self.energy_mean_square) * ENERGY_RMS_COEFF;
+            let energy_mix = self.energy_mean_square.sqrt() * 0.65 + self.high_env * 0.35;
+            let target_safety = if settings.energy_ceiling >= 1.0 {
+                1.0
+            } else {
+                let threshold = lerp(0.2, 1.0, settings.energy_ceiling.clamp(0.0, 1.0));
+                let over = (energy_mix - threshold).max(0.0);
+                1.0 / (1.0 + over * 2.6)
+            };
             let safety_coeff = if target_safety < self.safety_gain {
                 0.07
             } else {
@@ -224,34 +766,295 @@ impl TensionFieldEngine {
             self.safety_gain += (target_safety - self.safety_gain) * safety_coeff;
 
             self.output_gain += (db_to_gain(settings.output_trim_db) - self.output_gain) * 0.002;
-            let mut out_l = space_l * self.output_gain * self.safety_gain;
-            let mut out_r = space_r * self.output_gain * self.safety_gain;
-            if settings.character == CharacterMode::Crush {
-                out_l = crush(out_l);
-                out_r = crush(out_r);
-            }
+            let makeup = if settings.character_makeup {
+                self.character_fade.mix(character_level_trim)
+            } else {
+                1.0
+            };
+            // Drive only feeds the character nonlinearities, so Clean ignores it.
+            let drive_target = if character_dirty {
+                db_to_gain(settings.char_drive_db)
+            } else {
+                1.0
+            };
+            self.char_drive += (drive_target - self.char_drive) * 0.002;
+            let pull_duck = 1.0 - settings.pull_duck * gesture.tension_drive * 0.6;
+            let out_gain = self.output_gain * self.safety_gain * makeup * pull_duck;
+            let pre_l = space_l * out_gain;
+            let pre_r = space_r * out_gain;
+            pre_clip_peak = pre_clip_peak.max(pre_l.abs().max(pre_r.abs()));
+            let (out_l, out_r) = self.output_chain.process(
+                pre_l,
+                pre_r,
+                OutputChainControl {
+                    drive: self.char_drive,
+                    crush: self
+                        .character_fade
+                        .mix(|character| (character == CharacterMode::Crush) as u8 as f32),
+                    crush_steps: settings.crush_depth_bits.exp2(),
+                    downsample: settings.downsample_factor as u32,
+                    dc_block: dirty_mix,
+                    limit: settings.safety_limit,
+                    clip: settings.clip_mode,
+                },
+            );
+            true_peak_gain = true_peak_gain.min(self.output_chain.true_peak_gain());
+
+            self.feedback_left = out_l;
+            self.feedback_right = out_r;
 
-            out_l = soft_clip(out_l);
-            out_r = soft_clip(out_r);
+            self.mix += (settings.mix - self.mix) * 0.002;
+            let (out_l, out_r) = if self.mix < 1.0 {
+                (lerp(dry_l, out_l, self.mix), lerp(dry_r, out_r, self.mix))
+            } else {
+                (out_l, out_r)
+            };
+
+            // Wet solo: the stages are close to linear, so running the feedback
+            // signal alone through a shadow copy isolates the part of the output
+            // that the feedback adds on top of the direct pass.
+            let (out_l, out_r) = if self.feedback_solo {
+                let (tail_l, tail_r) = self
+                    .feedback_tail
+                    .process(feedback_l, feedback_r, stage_control)
+                    .space;
+                (
+                    output_clip(tail_l * out_gain, settings.clip_mode),
+                    output_clip(tail_r * out_gain, settings.clip_mode),
+                )
+            } else {
+                (out_l, out_r)
+            };
 
+            // Mono monitoring only affects what is heard; feedback keeps the stereo signal.
+            let (out_l, out_r) = if settings.mono_monitor {
+                let mono = (out_l + out_r) * 0.5;
+                (mono, mono)
+            } else {
+                (out_l, out_r)
+            };
+            let (out_l, out_r) = if out_l.is_finite()
+                && out_r.is_finite()
+                && self.feedback_left.is_finite()
+                && self.feedback_right.is_finite()
+            {
+                (out_l, out_r)
+            } else {
+                self.capture_panic(settings, SanitizeSource::Output);
+                self.feedback_hpf.reset();
+                self.feedback_tone.reset();
+                self.feedback_left = 0.0;
+                self.feedback_right = 0.0;
+                (0.0, 0.0)
+            };
             *l = out_l;
             *r = out_r;
             output_left_peak = output_left_peak.max(out_l.abs());
             output_right_peak = output_right_peak.max(out_r.abs());
-            self.feedback_left = out_l;
-            self.feedback_right = out_r;
         }
+        self.controls = Some(controls);
+
+        // Internal energy counts too, so a dry mix can't hide a ringing feedback loop.
+        let idle = input_left_peak.max(input_right_peak) <= IDLE_SILENCE_LEVEL
+            && output_left_peak.max(output_right_peak) <= IDLE_SILENCE_LEVEL
+            && feedback_peak.max(space_level) <= IDLE_SILENCE_LEVEL;
+        self.idle_samples = if idle {
+            self.idle_samples.saturating_add(frames)
+        } else {
+            0
+        };
+        // Audio still inside the usable window or the space ring shows no level yet,
+        // so the bypass waits until both could have played out before clearing them.
+        let window = (settings.elastic_size_seconds * self.sample_rate)
+            .min(self.stages.elastic.left.len() as f32);
+        let drain = window + space_ring_samples(settings);
+        self.idle_bypass_armed =
+            !holding && self.idle_samples as f32 >= IDLE_BYPASS_SECONDS * self.sample_rate + drain;
+        if self.idle_bypass_armed {
+            self.clear_residual();
+        }
+        self.tail_pending = input_left_peak.max(input_right_peak) <= IDLE_SILENCE_LEVEL
+            && output_left_peak.max(output_right_peak) > IDLE_SILENCE_LEVEL;
 
         RenderReport {
             input_left: meter_norm(input_left_peak),
             input_right: meter_norm(input_right_peak),
             elastic_activity: meter_norm(elastic_peak),
             warp_activity: meter_norm(warp_peak),
-            space_activity: meter_norm(space_peak),
+            space_activity: meter_norm(space_meter.peak),
+            space_rms: meter_norm(space_meter.rms()),
             feedback_activity: meter_norm(feedback_peak),
             output_left: meter_norm(output_left_peak),
             output_right: meter_norm(output_right_peak),
             tension_activity: tension_peak.clamp(0.0, 1.0),
+            duck_level: duck_peak,
+            elastic_level,
+            warp_level,
+            space_level,
+            feedback_level: feedback_peak,
+            output_overshoot: pre_clip_peak > OVERSHOOT_LEVEL,
+            gesture_phase,
+            bar_count: self.clock.bars_elapsed(),
+            mod_sources: self.modulation.source_values(),
+            elastic_delay_ms: self.stages.elastic.left_head.smooth_delay * 1_000.0
+                / self.sample_rate.max(1.0),
+            stage_modulation,
+            true_peak_reduction_db: 20.0 * true_peak_gain.recip().log10(),
+        }
+    }
+
+    /// Record the settings and recent input behind a sanitized non-finite sample.
+    fn capture_panic(&mut self, settings: &TensionFieldSettings, source: SanitizeSource) {
+        self.panic_snapshot = Some(PanicSnapshot {
+            source,
+            settings: *settings,
+            recent_input: self.input_history.recent(),
+        });
+    }
+
+    /// Zero the elastic buffers and feedback state so waking up never replays stale audio.
+    fn clear_residual(&mut self) {
+        self.stages.elastic.clear();
+        self.feedback_tail.elastic.clear();
+        self.feedback_hpf.reset();
+        self.feedback_tone.reset();
+        self.feedback_left = 0.0;
+        self.feedback_right = 0.0;
+    }
+
+    /// Clear a silent block while idle, skipping the per-sample signal stages.
+    ///
+    /// Only the transport clock keeps running so bar counts and synced phases
+    /// stay aligned when input returns.
+    fn bypass_idle_frames(
+        &mut self,
+        left: &mut [f32],
+        right: &mut [f32],
+        transport: TransportState,
+    ) -> RenderReport {
+        let mut transport_for_sample = transport;
+        for _ in 0..left.len() {
+            let _ = self.clock.tick(transport_for_sample);
+            transport_for_sample.song_pos_beats = None;
+            if self.clock.jumped_back() {
+                self.gesture.reset_phase();
+                self.gesture_right.reset_phase();
+                self.modulation.reset_phases();
+            }
+        }
+        left.fill(0.0);
+        right.fill(0.0);
+        self.idle_samples = self.idle_samples.saturating_add(left.len());
+        self.tail_pending = false;
+        RenderReport {
+            bar_count: self.clock.bars_elapsed(),
+            mod_sources: self.modulation.source_values(),
+            elastic_delay_ms: self.stages.elastic.left_head.smooth_delay * 1_000.0
+                / self.sample_rate.max(1.0),
+            ..RenderReport::default()
+        }
+    }
+}
+
+/// Pick each stage's key destination out of the mod matrix outputs.
+///
+/// The order follows the stage meters: elastic (tension), warp (warp motion),
+/// space (width), and feedback.
+fn stage_modulation_values(mod_values: [f32; 6]) -> [f32; 4] {
+    [mod_values[0], mod_values[4], mod_values[3], mod_values[5]]
+}
+
+/// Scale the free-running rates in `settings` by host tempo relative to 120 BPM.
+///
+/// Synced rates already follow the host, so only Hz rates are touched.
+fn tempo_followed(settings: &TensionFieldSettings, tempo_bpm: f32) -> TensionFieldSettings {
+    let scale = tempo_bpm.clamp(20.0, 300.0) / TEMPO_FOLLOW_REFERENCE_BPM;
+    let mut followed = *settings;
+    followed.pull_rate_hz *= scale;
+    if followed.warp_rate_mode == ModRateMode::FreeHz {
+        followed.warp_rate_hz *= scale;
+    }
+    followed.modulation.source_a.rate_hz *= scale;
+    followed.modulation.source_b.rate_hz *= scale;
+    followed
+}
+
+/// Return whether every sample in both channels is below the idle silence level.
+/// Samples the space diffusers keep ringing until they decay to [`TAIL_DECAY_LEVEL`].
+fn space_ring_samples(settings: &TensionFieldSettings) -> f32 {
+    let diffuser_samples = (SPACE_DIFFUSER_SAMPLES + SPACE_SIDE_DELAY_SAMPLES) * SPACE_SCALE_MAX;
+    let diffusion_gain = space_diffusion_gain(settings.diffusion);
+    diffuser_samples * (TAIL_DECAY_LEVEL.ln() / diffusion_gain.ln()).max(1.0)
+}
+
+fn block_is_silent(left: &[f32], right: &[f32]) -> bool {
+    left.iter()
+        .chain(right.iter())
+        .all(|sample| sample.abs() <= IDLE_SILENCE_LEVEL)
+}
+
+/// Per-sample controls shared by the main stages and the feedback-tail shadow.
+#[derive(Copy, Clone)]
+struct StageControl {
+    tension_drive: f32,
+    grain: f32,
+    elastic: ElasticControl,
+    right_elastic: Option<ElasticControl>,
+    warp: WarpControl,
+    space: SpaceControl,
+}
+
+/// Stereo frame after each signal stage, kept for metering.
+#[derive(Copy, Clone)]
+struct StageTaps {
+    pre: (f32, f32),
+    elastic: (f32, f32),
+    warped: (f32, f32),
+    space: (f32, f32),
+}
+
+/// Pre-emphasis, elastic buffer, spectral warp, and space stages in series.
+struct SignalStages {
+    pre_left: PreEmphasis,
+    pre_right: PreEmphasis,
+    elastic: ElasticBuffer,
+    warp_left: SpectralWarp,
+    warp_right: SpectralWarp,
+    space: SpaceStage,
+}
+
+impl SignalStages {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            pre_left: PreEmphasis::default(),
+            pre_right: PreEmphasis::default(),
+            elastic: ElasticBuffer::new(sample_rate),
+            warp_left: SpectralWarp::new(37, 73, sample_rate),
+            warp_right: SpectralWarp::new(43, 79, sample_rate),
+            space: SpaceStage::new(sample_rate),
+        }
+    }
+
+    fn process(&mut self, left: f32, right: f32, control: StageControl) -> StageTaps {
+        let pre = (
+            self.pre_left
+                .process(left, control.tension_drive, control.grain),
+            self.pre_right
+                .process(right, control.tension_drive, control.grain),
+        );
+        let elastic = self
+            .elastic
+            .process(pre.0, pre.1, control.elastic, control.right_elastic);
+        let warped = (
+            self.warp_left.process(elastic.0, control.warp),
+            self.warp_right.process(elastic.1, control.warp),
+        );
+        let space = self.space.process(warped.0, warped.1, control.space);
+        StageTaps {
+            pre,
+            elastic,
+            warped,
+            space,
         }
     }
 }
@@ -261,70 +1064,311 @@ struct ElasticControl {
     delay_samples: f32,
     velocity: f32,
     pitch_coupling: f32,
+    key_lock: KeyLock,
     grain_amount: f32,
-    elasticity: f32,
+    grain_bias: f32,
+    spring: f32,
     dirty: bool,
+    transient_mark: f32,
+    /// Freeze the buffer and scrub it at this 0..1 position (0 = oldest, 1 = newest).
+    scrub: Option<f32>,
+    /// Usable part of the buffer in samples; delays and scrubbing stay inside it.
+    window_samples: f32,
+    /// Hold the stored audio while the read heads keep looping through it.
+    freeze: bool,
+    /// Run the read heads backward through the buffer.
+    reverse: bool,
+}
+
+/// Length of each scrub grain.
+const SCRUB_GRAIN_SECONDS: f32 = 0.06;
+/// Per-sample glide of the scrub point toward its target.
+const SCRUB_GLIDE: f32 = 0.0015;
+/// Per-sample step of the read-head direction ramp, so reversing glides through a stop.
+const REVERSE_RAMP_STEP: f32 = 0.001;
+/// Fade at each end of a reverse pass through the usable window.
+const REVERSE_FADE_SAMPLES: f32 = 256.0;
+/// Crossfade between writing and holding the elastic buffer when freeze toggles.
+const FREEZE_FADE_SECONDS: f32 = 0.005;
+
+/// Two overlapping Hann grains looping around a scrub point in a frozen buffer.
+#[derive(Debug, Copy, Clone, Default)]
+struct Scrubber {
+    active: bool,
+    /// Smoothed scrub point in samples behind the frozen write index.
+    delay: f32,
+    phase: f32,
 }
 
 struct ElasticBuffer {
     left: Vec<f32>,
     right: Vec<f32>,
+    transient_marks: Vec<f32>,
     write_index: usize,
-    read_position: f32,
-    smooth_delay: f32,
+    left_head: ReadHead,
+    right_head: ReadHead,
     jitter: f32,
     rng_state: u32,
+    scrubber: Scrubber,
+    grain_samples: f32,
+    /// Write crossfade: 0 records input, 1 keeps the stored audio.
+    freeze_mix: f32,
+    freeze_step: f32,
+}
+
+/// Spring-driven read position chasing one channel's target delay.
+#[derive(Debug, Copy, Clone)]
+struct ReadHead {
+    read_position: f32,
+    smooth_delay: f32,
+    transient_hold: f32,
+    /// Playback direction, ramping between 1 (forward) and -1 (reverse).
+    direction: f32,
 }
 
 impl ElasticBuffer {
     fn new(sample_rate: f32) -> Self {
-        let length = (sample_rate * 2.75).ceil() as usize + 4;
+        let length = (sample_rate * ELASTIC_MAX_DELAY_SECONDS).ceil() as usize + 4;
         let initial_delay = sample_rate * 0.18;
+        let head = ReadHead {
+            read_position: length as f32 - initial_delay,
+            smooth_delay: initial_delay,
+            transient_hold: 0.0,
+            direction: 1.0,
+        };
         Self {
             left: vec![0.0; length],
             right: vec![0.0; length],
+            transient_marks: vec![0.0; length],
             write_index: 0,
-            read_position: length as f32 - initial_delay,
-            smooth_delay: initial_delay,
+            left_head: head,
+            right_head: head,
             jitter: 0.0,
             rng_state: 0xA341_316C,
+            scrubber: Scrubber::default(),
+            grain_samples: (sample_rate * SCRUB_GRAIN_SECONDS).max(8.0),
+            freeze_mix: 0.0,
+            freeze_step: 1.0 / (sample_rate * FREEZE_FADE_SECONDS).max(1.0),
         }
     }
 
-    fn process(&mut self, left_in: f32, right_in: f32, control: ElasticControl) -> (f32, f32) {
-        let len = self.left.len() as f32;
+    /// Zero the stored audio and transient marks, keeping the read heads in place.
+    fn clear(&mut self) {
+        self.left.fill(0.0);
+        self.right.fill(0.0);
+        self.transient_marks.fill(0.0);
+    }
 
-        self.left[self.write_index] = left_in;
-        self.right[self.write_index] = right_in;
+    /// Empty the buffer and copy `other`'s heads and jitter so both read in step.
+    fn align_to(&mut self, other: &ElasticBuffer) {
+        self.clear();
+        self.write_index = other.write_index;
+        self.left_head = other.left_head;
+        self.right_head = other.right_head;
+        self.jitter = other.jitter;
+        self.rng_state = other.rng_state;
+        self.scrubber = other.scrubber;
+        self.freeze_mix = other.freeze_mix;
+    }
+
+    /// Write one frame and read both channels back through the elastic heads.
+    ///
+    /// With `right_control` set the right channel follows its own delay target;
+    /// otherwise both channels share the left head.
+    fn process(
+        &mut self,
+        left_in: f32,
+        right_in: f32,
+        control: ElasticControl,
+        right_control: Option<ElasticControl>,
+    ) -> (f32, f32) {
+        if let Some(position) = control.scrub {
+            return self.scrub(position, control.window_samples);
+        }
+        self.scrubber.active = false;
+
+        // Frozen, the write index keeps moving but each slot takes the audio one
+        // usable window behind it, so the heads loop the window captured at the
+        // freeze point and never reach older audio; the fade keeps the seams smooth.
+        let freeze_target = control.freeze as u8 as f32;
+        self.freeze_mix +=
+            (freeze_target - self.freeze_mix).clamp(-self.freeze_step, self.freeze_step);
+        let buffer_len = self.left.len();
+        let index = self.write_index;
+        let period = (control.window_samples.min(buffer_len as f32 - 4.0) as usize).max(1);
+        let looped = (index + buffer_len - period) % buffer_len;
+        self.left[index] = lerp(left_in, self.left[looped], self.freeze_mix);
+        self.right[index] = lerp(right_in, self.right[looped], self.freeze_mix);
+        self.transient_marks[index] = lerp(
+            control.transient_mark,
+            self.transient_marks[looped],
+            self.freeze_mix,
+        );
 
-        let jitter_depth = 4.0 + control.grain_amount.powi(2) * 110.0;
         self.jitter = (self.jitter + next_signed(&mut self.rng_state) * 0.02).clamp(-1.0, 1.0);
         let jitter = if control.dirty {
             self.jitter + next_signed(&mut self.rng_state) * 0.25
         } else {
             self.jitter
         };
+        let jitter = skew_jitter(jitter, control.grain_bias);
+
+        self.left_head.advance(
+            control,
+            jitter,
+            self.write_index,
+            &self.transient_marks,
+            &mut self.rng_state,
+        );
+        match right_control {
+            Some(right_control) => self.right_head.advance(
+                right_control,
+                jitter,
+                self.write_index,
+                &self.transient_marks,
+                &mut self.rng_state,
+            ),
+            None => self.right_head = self.left_head,
+        }
+
+        let len = self.left.len() as f32;
+        let window = control.window_samples.min(len - 4.0);
+        let out_l = read_cubic(&self.left, self.left_head.read_position)
+            * self.left_head.reverse_gain(self.write_index, len, window);
+        let out_r = read_cubic(&self.right, self.right_head.read_position)
+            * self.right_head.reverse_gain(self.write_index, len, window);
+
+        self.write_index = (self.write_index + 1) % self.left.len();
+        (out_l, out_r)
+    }
+
+    /// Read the frozen buffer around `position` without writing new input.
+    ///
+    /// Position 0 sits near the oldest audio in the usable window and 1 just
+    /// behind the freeze point; the scrub point glides there so map moves sound
+    /// like a tape scrub.
+    fn scrub(&mut self, position: f32, window_samples: f32) -> (f32, f32) {
+        let len = self.left.len() as f32;
+        let window = window_samples.min(len);
+        let nearest = self.grain_samples + 4.0;
+        let span = (window - nearest - self.grain_samples - 4.0).max(0.0);
+        let target = nearest + (1.0 - position.clamp(0.0, 1.0)) * span;
+        if !self.scrubber.active {
+            self.scrubber = Scrubber {
+                active: true,
+                delay: self.left_head.smooth_delay.clamp(nearest, nearest + span),
+                phase: 0.0,
+            };
+        }
+        self.scrubber.delay += (target - self.scrubber.delay) * SCRUB_GLIDE;
+        self.scrubber.phase = (self.scrubber.phase + 1.0 / self.grain_samples).fract();
+
+        let center = self.scrub_position();
+        let mut out = (0.0, 0.0);
+        for offset in [0.0, 0.5] {
+            let phase = (self.scrubber.phase + offset).fract();
+            let window = (PI * phase).sin().powi(2);
+            let read = wrap_position(center + (phase - 0.5) * self.grain_samples, len);
+            out.0 += read_cubic(&self.left, read) * window;
+            out.1 += read_cubic(&self.right, read) * window;
+        }
+        out
+    }
 
-        let target_delay = (control.delay_samples + jitter * jitter_depth).max(8.0);
-        let delay_smooth = 0.0018 + control.elasticity * 0.01;
+    /// Buffer position the scrub grains are centred on.
+    fn scrub_position(&self) -> f32 {
+        wrap_position(
+            self.write_index as f32 - self.scrubber.delay,
+            self.left.len() as f32,
+        )
+    }
+}
+
+impl ReadHead {
+    fn advance(
+        &mut self,
+        control: ElasticControl,
+        jitter: f32,
+        write_index: usize,
+        transient_marks: &[f32],
+        rng_state: &mut u32,
+    ) {
+        let len = transient_marks.len() as f32;
+        let reverse_target = if control.reverse { -1.0 } else { 1.0 };
+        self.direction +=
+            (reverse_target - self.direction).clamp(-REVERSE_RAMP_STEP, REVERSE_RAMP_STEP);
+        let jitter_depth = 4.0 + control.grain_amount.powi(2) * 110.0;
+        let target_delay = (control.delay_samples + jitter * jitter_depth)
+            .min(control.window_samples.min(len - 4.0))
+            .max(8.0);
+        let delay_smooth = 0.0018 + control.spring * 0.01;
         self.smooth_delay += (target_delay - self.smooth_delay) * delay_smooth;
 
-        let desired_read = wrap_position(self.write_index as f32 - self.smooth_delay, len);
+        let desired_read = wrap_position(write_index as f32 - self.smooth_delay, len);
         let error = wrap_delta(desired_read - self.read_position, len);
 
-        let mut speed = 1.0 + error * 0.003 + control.velocity * control.pitch_coupling * 0.48;
+        // Only forward motion chases the delay target; reverse lets the delay grow.
+        let mut speed = 1.0
+            + error * 0.003 * self.direction.max(0.0)
+            + coupled_pitch_offset(control.velocity, control.pitch_coupling, control.key_lock);
         if control.dirty {
-            speed += next_signed(&mut self.rng_state) * 0.03 * control.grain_amount;
+            speed += next_signed(rng_state) * 0.03 * control.grain_amount;
         }
         speed = speed.clamp(0.35, 1.65);
 
-        self.read_position = wrap_position(self.read_position + speed, len);
+        // Transient marks travel with the audio, so the read speed snaps to 1.0
+        // just before a stored attack is read rather than when it was written.
+        let ahead = TRANSIENT_READ_AHEAD.min(self.smooth_delay - 1.0).max(0.0);
+        let mark_index = wrap_position(self.read_position + ahead, len) as usize;
+        let mark = transient_marks[mark_index.min(transient_marks.len() - 1)];
+        self.transient_hold = (self.transient_hold * TRANSIENT_HOLD_DECAY).max(mark);
+        speed += (1.0 - speed) * self.transient_hold;
 
-        let out_l = read_cubic(&self.left, self.read_position);
-        let out_r = read_cubic(&self.right, self.read_position);
+        self.read_position = wrap_position(self.read_position + speed * self.direction, len);
+        // A reverse pass that reaches the end of the usable window restarts at
+        // the delay target; `reverse_gain` has faded it out by then.
+        if self.direction < 0.0
+            && self.delay_behind(write_index, len) >= control.window_samples.min(len - 4.0)
+        {
+            self.read_position = desired_read;
+        }
+    }
 
-        self.write_index = (self.write_index + 1) % self.left.len();
-        (out_l, out_r)
+    /// Samples the head trails the write index by.
+    fn delay_behind(&self, write_index: usize, len: f32) -> f32 {
+        wrap_position(write_index as f32 - self.read_position, len)
+    }
+
+    /// Output gain fading reverse passes in after a restart and out before the window end.
+    fn reverse_gain(&self, write_index: usize, len: f32, window: f32) -> f32 {
+        let reverse = (-self.direction).clamp(0.0, 1.0);
+        if reverse == 0.0 {
+            return 1.0;
+        }
+        let delay = self.delay_behind(write_index, len);
+        let fade_in = (delay - self.smooth_delay) / REVERSE_FADE_SAMPLES;
+        let fade_out = (window - delay) / REVERSE_FADE_SAMPLES;
+        lerp(1.0, fade_in.min(fade_out).clamp(0.0, 1.0), reverse)
+    }
+}
+
+/// Envelope-rise detector flagging input attacks for transient preservation.
+#[derive(Default)]
+struct TransientDetector {
+    fast: f32,
+    slow: f32,
+}
+
+impl TransientDetector {
+    /// Return 1.0 while the fast envelope rises well above the slow one, else 0.0.
+    fn process(&mut self, input_abs: f32) -> f32 {
+        self.fast += (input_abs - self.fast) * 0.2;
+        self.slow += (input_abs - self.slow) * 0.002;
+        if self.fast > self.slow * 2.0 + 0.02 {
+            1.0
+        } else {
+            0.0
+        }
     }
 }
 
@@ -334,23 +1378,72 @@ struct WarpControl {
     diffusion: f32,
     elasticity: f32,
     air_damping: f32,
-    air_compensation: bool,
+    air_compensation: f32,
     drift_phase_inc: f32,
     warp_motion: f32,
     color: WarpColor,
+    high_color: Option<WarpColor>,
     character: CharacterMode,
+    low_keep_hz: f32,
+    air_freq_hz: f32,
 }
 
+/// Crossover between the low and high warp bands when they use different colors.
+const WARP_BAND_SPLIT_HZ: f32 = 800.0;
+
 struct SpectralWarp {
+    band: WarpBand,
+    low_band: WarpBand,
+    crossover: BandSplit,
+}
+
+impl SpectralWarp {
+    fn new(a_size: usize, b_size: usize, sample_rate: f32) -> Self {
+        Self {
+            band: WarpBand::new(a_size, b_size, sample_rate),
+            low_band: WarpBand::new(a_size, b_size, sample_rate),
+            crossover: BandSplit::new(sample_rate),
+        }
+    }
+
+    /// Warp the input, leaving content below `low_keep_hz` clean when it is set.
+    ///
+    /// With a separate high color, the input is split at the low-keep frequency
+    /// (or [`WARP_BAND_SPLIT_HZ`]) and each band is warped with its own color.
+    fn process(&mut self, input: f32, control: WarpControl) -> f32 {
+        let low_keep = control.low_keep_hz > 0.0;
+        let split_hz = match (low_keep, control.high_color) {
+            (true, _) => control.low_keep_hz,
+            (false, Some(_)) => WARP_BAND_SPLIT_HZ,
+            (false, None) => return self.band.process(input, control),
+        };
+        let high_control = WarpControl {
+            color: control.high_color.unwrap_or(control.color),
+            ..control
+        };
+        let (low, high) = self.crossover.split(input, split_hz);
+        let low = if low_keep {
+            low
+        } else {
+            self.low_band.process(low, control)
+        };
+        low + self.band.process(high, high_control)
+    }
+}
+
+/// Tone, allpass smear, and drift state for one warped band.
+struct WarpBand {
+    sample_rate: f32,
     low_state: f32,
     allpass_a: AllpassDelay,
     allpass_b: AllpassDelay,
     drift_phase: f32,
 }
 
-impl SpectralWarp {
-    fn new(a_size: usize, b_size: usize) -> Self {
+impl WarpBand {
+    fn new(a_size: usize, b_size: usize, sample_rate: f32) -> Self {
         Self {
+            sample_rate,
             low_state: 0.0,
             allpass_a: AllpassDelay::new(a_size),
             allpass_b: AllpassDelay::new(b_size),
@@ -366,20 +1459,18 @@ impl SpectralWarp {
         };
         let damping = (control.air_damping * (0.3 + control.tension * 0.7) + color_damping_bias)
             .clamp(0.0, 0.98);
-        let low_coeff = 0.012 + (1.0 - damping) * 0.12;
+        // Damping pulls the air-band corner down to a tenth of the reference frequency.
+        let corner_hz = control.air_freq_hz * (0.1 + (1.0 - damping) * 0.9);
+        let low_coeff = 1.0 - (-TAU * corner_hz / self.sample_rate).exp();
         self.low_state += (input - self.low_state) * low_coeff;
 
         let high = input - self.low_state;
-        let compensation = if control.air_compensation {
-            let color_boost = match control.color {
-                WarpColor::Neutral => 1.0,
-                WarpColor::DarkDrag => 0.75,
-                WarpColor::BrightShear => 1.2,
-            };
-            damping * 0.72 * color_boost
-        } else {
-            0.0
+        let color_boost = match control.color {
+            WarpColor::Neutral => 1.0,
+            WarpColor::DarkDrag => 0.75,
+            WarpColor::BrightShear => 1.2,
         };
+        let compensation = damping * 0.72 * color_boost * control.air_compensation.clamp(0.0, 1.0);
         let tone = self.low_state + high * (1.0 - damping * 0.9 + compensation);
 
         let g1 = (0.12
@@ -406,51 +1497,333 @@ impl SpectralWarp {
     }
 }
 
-#[derive(Default)]
+/// Fourth-order Linkwitz-Riley band split whose bands sum back to an allpass.
+struct BandSplit {
+    sample_rate: f32,
+    cutoff_hz: f32,
+    low: [Biquad; 2],
+    high: [Biquad; 2],
+}
+
+impl BandSplit {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1.0),
+            cutoff_hz: 0.0,
+            low: [Biquad::default(); 2],
+            high: [Biquad::default(); 2],
+        }
+    }
+
+    /// Return the (low, high) bands of `input` split at `cutoff_hz`.
+    fn split(&mut self, input: f32, cutoff_hz: f32) -> (f32, f32) {
+        if cutoff_hz != self.cutoff_hz {
+            self.set_cutoff(cutoff_hz);
+        }
+        let low = self
+            .low
+            .iter_mut()
+            .fold(input, |signal, section| section.process(signal));
+        let high = self
+            .high
+            .iter_mut()
+            .fold(input, |signal, section| section.process(signal));
+        (low, high)
+    }
+
+    fn set_cutoff(&mut self, cutoff_hz: f32) {
+        // Filter state is kept when the cutoff moves so automation does not click.
+        self.cutoff_hz = cutoff_hz;
+        let cutoff = cutoff_hz.clamp(10.0, self.sample_rate * 0.45);
+        let omega = TAU * cutoff / self.sample_rate;
+        let (sin, cos) = omega.sin_cos();
+        let alpha = sin / (2.0 * std::f32::consts::FRAC_1_SQRT_2);
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos / a0;
+        let a2 = (1.0 - alpha) / a0;
+        let low_b0 = (1.0 - cos) * 0.5 / a0;
+        let high_b0 = (1.0 + cos) * 0.5 / a0;
+        for section in &mut self.low {
+            section.set(low_b0, 2.0 * low_b0, low_b0, a1, a2);
+        }
+        for section in &mut self.high {
+            section.set(high_b0, -2.0 * high_b0, high_b0, a1, a2);
+        }
+    }
+}
+
+/// Transposed direct-form II biquad section.
+#[derive(Copy, Clone, Default)]
+struct Biquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    z1: f32,
+    z2: f32,
+}
+
+impl Biquad {
+    fn set(&mut self, b0: f32, b1: f32, b2: f32, a1: f32, a2: f32) {
+        self.b0 = b0;
+        self.b1 = b1;
+        self.b2 = b2;
+        self.a1 = a1;
+        self.a2 = a2;
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let output = self.b0 * input + self.z1;
+        self.z1 = self.b1 * input - self.a1 * output + self.z2;
+        self.z2 = self.b2 * input - self.a2 * output;
+        output
+    }
+}
+
+/// Side-delay length in samples at the original (1x) space size.
+const SPACE_SIDE_DELAY_SAMPLES: f32 = 23.0;
+/// Diffuser allpass length in samples at the original (1x) space size.
+const SPACE_DIFFUSER_SAMPLES: f32 = 31.0;
+/// Delay-length scale at space size 0.
+const SPACE_SCALE_MIN: f32 = 0.5;
+/// Delay-length scale at space size 1; buffers are allocated for this up front.
+const SPACE_SCALE_MAX: f32 = 3.0;
+/// Longest Haas delay applied to the right channel, in milliseconds.
+const MAX_HAAS_MS: f32 = 20.0;
+/// Pivot of the tone tilt ahead of the diffusers.
+const SPACE_TONE_PIVOT_HZ: f32 = 1_500.0;
+/// Share of the width the width safety removes per unit of feedback.
+const FEEDBACK_WIDTH_SAFETY: f32 = 0.9;
+/// Largest width offset the elasticity coupling applies at either end of elasticity.
+const ELASTICITY_WIDTH_RANGE: f32 = 0.3;
+/// Feedback low-pass corner at the darkest feedback tone.
+const FEEDBACK_TONE_MIN_HZ: f32 = 600.0;
+/// Feedback low-pass corner just below full tone, where the filter is bypassed.
+const FEEDBACK_TONE_MAX_HZ: f32 = 18_000.0;
+
+/// Allpass gain of the space diffusers for a 0..1 diffusion amount.
+fn space_diffusion_gain(diffusion: f32) -> f32 {
+    (0.14 + diffusion * 0.56).clamp(0.08, 0.8)
+}
+
+/// Map the 0..1 space size to a delay-length scale (0.2 gives the original 1x).
+fn space_size_scale(size: f32) -> f32 {
+    lerp(SPACE_SCALE_MIN, SPACE_SCALE_MAX, size.clamp(0.0, 1.0))
+}
+
+#[derive(Copy, Clone)]
+struct SpaceControl {
+    width: f32,
+    diffusion: f32,
+    /// Delay-length scale from the space size.
+    scale: f32,
+    /// Diffusion input tilt (-1 = dark, 0 = neutral, 1 = bright).
+    tone: f32,
+    /// Right-channel Haas delay in samples (0 = off).
+    haas_samples: f32,
+    /// Stereo field rotation in radians (positive turns the image toward the right).
+    rotation: f32,
+    /// Character dirt amount (0 = clean, 1 = dirty; fractional during a character crossfade).
+    dirty: f32,
+}
+
 struct SpaceStage {
     side_delay_a: ShortDelay,
     side_delay_b: ShortDelay,
+    tone_left: ToneTilt,
+    tone_right: ToneTilt,
     diff_left: AllpassDelay,
     diff_right: AllpassDelay,
+    haas: ShortDelay,
 }
 
 impl SpaceStage {
-    fn process(
-        &mut self,
-        left: f32,
-        right: f32,
-        width: f32,
-        diffusion: f32,
-        dirty: bool,
-    ) -> (f32, f32) {
+    fn new(sample_rate: f32) -> Self {
+        let side_capacity = (SPACE_SIDE_DELAY_SAMPLES * SPACE_SCALE_MAX).ceil() as usize + 4;
+        let diffuser_capacity = (SPACE_DIFFUSER_SAMPLES * SPACE_SCALE_MAX).ceil() as usize + 4;
+        Self {
+            side_delay_a: ShortDelay::new(side_capacity),
+            side_delay_b: ShortDelay::new(side_capacity),
+            tone_left: ToneTilt::new(sample_rate),
+            tone_right: ToneTilt::new(sample_rate),
+            diff_left: AllpassDelay::new(diffuser_capacity),
+            diff_right: AllpassDelay::new(diffuser_capacity),
+            haas: ShortDelay::new((MAX_HAAS_MS * 0.001 * sample_rate).ceil() as usize + 4),
+        }
+    }
+
+    fn process(&mut self, left: f32, right: f32, control: SpaceControl) -> (f32, f32) {
+        let SpaceControl {
+            width,
+            diffusion,
+            scale,
+            tone,
+            haas_samples,
+            rotation,
+            dirty,
+        } = control;
         let mid = (left + right) * 0.5;
         let side = (left - right) * 0.5;
 
-        let delayed_a = self.side_delay_a.process(side);
-        let delayed_b = self.side_delay_b.process(-side);
+        let side_length = SPACE_SIDE_DELAY_SAMPLES * scale;
+        let diffuser_length = SPACE_DIFFUSER_SAMPLES * scale;
+        let delayed_a = self.side_delay_a.process(side, side_length);
+        let delayed_b = self.side_delay_b.process(-side, side_length);
         let decorrelated = lerp(side, (delayed_a - delayed_b) * 0.5, width * 0.82);
 
         let spread = 1.0 + width * 0.78;
         let mut out_l = mid + decorrelated * spread;
         let mut out_r = mid - decorrelated * spread;
 
-        let diffusion_gain = (0.14 + diffusion * 0.56).clamp(0.08, 0.8);
-        let diffused_l = self.diff_left.process(out_l, diffusion_gain);
-        let diffused_r = self.diff_right.process(out_r, diffusion_gain * 0.95);
+        let diffusion_gain = space_diffusion_gain(diffusion);
+        let tilted_l = self.tone_left.process(out_l, tone);
+        let tilted_r = self.tone_right.process(out_r, tone);
+        let diffused_l =
+            self.diff_left
+                .process_with_length(tilted_l, diffusion_gain, diffuser_length);
+        let diffused_r =
+            self.diff_right
+                .process_with_length(tilted_r, diffusion_gain * 0.95, diffuser_length);
 
-        let blend = 0.1 + diffusion * 0.5;
+        // Fade the base blend in over the first 10% so diffusion 0 bypasses the diffusers.
+        let blend = (diffusion * 10.0).clamp(0.0, 1.0) * 0.1 + diffusion * 0.5;
         out_l = lerp(out_l, diffused_l, blend);
         out_r = lerp(out_r, diffused_r, blend);
 
-        if dirty {
-            out_l *= 1.015;
-            out_r *= 1.015;
+        let dirty_gain = 1.0 + dirty * 0.015;
+        out_l *= dirty_gain;
+        out_r *= dirty_gain;
+
+        if rotation != 0.0 {
+            let (sin, cos) = rotation.sin_cos();
+            let rotated_l = out_l * cos - out_r * sin;
+            out_r = out_l * sin + out_r * cos;
+            out_l = rotated_l;
+        }
+
+        // The delay line keeps running at zero so engaging Haas never replays stale audio.
+        let delayed_r = self.haas.process(out_r, haas_samples);
+        if haas_samples > 0.0 {
+            out_r = delayed_r;
         }
 
         (out_l, out_r)
     }
 }
 
+/// One-pole tilt that only cuts: negative tone removes highs, positive removes lows.
+struct ToneTilt {
+    coeff: f32,
+    low_state: f32,
+}
+
+impl ToneTilt {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            coeff: 1.0 - (-TAU * SPACE_TONE_PIVOT_HZ / sample_rate.max(1.0)).exp(),
+            low_state: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f32, tone: f32) -> f32 {
+        self.low_state += (input - self.low_state) * self.coeff;
+        let high = input - self.low_state;
+        let tone = tone.clamp(-1.0, 1.0);
+        self.low_state * (1.0 - tone.max(0.0) * 0.85) + high * (1.0 + tone.min(0.0) * 0.85)
+    }
+}
+
+/// Stereo one-pole low cut on the feedback return so repeats do not pile up bass.
+struct FeedbackHighPass {
+    sample_rate: f32,
+    cutoff_hz: f32,
+    coeff: f32,
+    low_left: f32,
+    low_right: f32,
+}
+
+impl FeedbackHighPass {
+    fn new(sample_rate: f32) -> Self {
+        let mut filter = Self {
+            sample_rate: sample_rate.max(1.0),
+            cutoff_hz: 0.0,
+            coeff: 0.0,
+            low_left: 0.0,
+            low_right: 0.0,
+        };
+        filter.set_cutoff(20.0);
+        filter
+    }
+
+    fn set_cutoff(&mut self, cutoff_hz: f32) {
+        self.cutoff_hz = cutoff_hz;
+        self.coeff = 1.0 - (-TAU * cutoff_hz / self.sample_rate).exp();
+    }
+
+    fn process(&mut self, left: f32, right: f32, cutoff_hz: f32) -> (f32, f32) {
+        if cutoff_hz != self.cutoff_hz {
+            self.set_cutoff(cutoff_hz);
+        }
+        self.low_left += (left - self.low_left) * self.coeff;
+        self.low_right += (right - self.low_right) * self.coeff;
+        (left - self.low_left, right - self.low_right)
+    }
+
+    fn reset(&mut self) {
+        self.low_left = 0.0;
+        self.low_right = 0.0;
+    }
+}
+
+/// Stereo one-pole low-pass on the feedback return that darkens each repeat.
+///
+/// Independent of the warp stage air damping; full tone bypasses the filter.
+struct FeedbackTone {
+    sample_rate: f32,
+    tone: f32,
+    coeff: f32,
+    low_left: f32,
+    low_right: f32,
+}
+
+impl FeedbackTone {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1.0),
+            tone: 1.0,
+            coeff: 1.0,
+            low_left: 0.0,
+            low_right: 0.0,
+        }
+    }
+
+    fn process(&mut self, left: f32, right: f32, tone: f32) -> (f32, f32) {
+        let tone = tone.clamp(0.0, 1.0);
+        if tone != self.tone {
+            self.tone = tone;
+            // Exponential sweep from the darkest corner to just under Nyquist.
+            let cutoff_hz = (FEEDBACK_TONE_MIN_HZ
+                * (FEEDBACK_TONE_MAX_HZ / FEEDBACK_TONE_MIN_HZ).powf(tone))
+            .min(self.sample_rate * 0.45);
+            self.coeff = 1.0 - (-TAU * cutoff_hz / self.sample_rate).exp();
+        }
+        if tone >= 1.0 {
+            self.low_left = left;
+            self.low_right = right;
+            return (left, right);
+        }
+        self.low_left += (left - self.low_left) * self.coeff;
+        self.low_right += (right - self.low_right) * self.coeff;
+        (self.low_left, self.low_right)
+    }
+
+    fn reset(&mut self) {
+        self.low_left = 0.0;
+        self.low_right = 0.0;
+    }
+}
+
 #[derive(Default)]
 struct PreEmphasis {
     low_state: f32,
@@ -466,138 +1839,2703 @@ impl PreEmphasis {
     }
 }
 
-struct AllpassDelay {
-    buffer: Vec<f32>,
-    index: usize,
+/// Peak and RMS accumulator for one stereo stage difference signal.
+#[derive(Default)]
+struct ActivityMeter {
+    peak: f32,
+    sum_squares: f32,
+    count: usize,
 }
 
-impl Default for AllpassDelay {
-    fn default() -> Self {
-        Self::new(31)
+impl ActivityMeter {
+    fn push(&mut self, left: f32, right: f32) {
+        self.peak = self.peak.max(left.abs().max(right.abs()));
+        self.sum_squares += (left * left + right * right) * 0.5;
+        self.count += 1;
+    }
+
+    fn rms(&self) -> f32 {
+        if self.count == 0 {
+            0.0
+        } else {
+            (self.sum_squares / self.count as f32).sqrt()
+        }
     }
 }
 
-impl AllpassDelay {
-    fn new(length: usize) -> Self {
+/// Output-stage processors applied by `OutputChain`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum OutputStage {
+    Drive,
+    Crush,
+    DcBlock,
+    Limit,
+    TruePeak,
+    SoftClip,
+}
+
+/// Fixed output-stage order.
+///
+/// Character drive saturates first so crush quantizes the driven signal, crush
+/// runs before the DC blocker so it can remove any offset it leaves (the
+/// blocker only acts on the Dirty and Crush paths, so Clean stays untouched), the peak
+/// limiter then holds the DC-free signal under its ceiling, the optional
+/// true-peak limiter catches what still overshoots between samples, and the
+/// soft clipper always runs last as the final safety.
+const OUTPUT_CHAIN: [OutputStage; 6] = [
+    OutputStage::Drive,
+    OutputStage::Crush,
+    OutputStage::DcBlock,
+    OutputStage::Limit,
+    OutputStage::TruePeak,
+    OutputStage::SoftClip,
+];
+
+#[derive(Debug, Copy, Clone)]
+struct OutputChainControl {
+    /// Linear pre-gain into the character saturation (1.0 bypasses it).
+    drive: f32,
+    /// Blend from the uncrushed (0.0) to the fully crushed (1.0) signal.
+    crush: f32,
+    /// Crush quantization levels per unit of full scale (`2^bits`).
+    crush_steps: f32,
+    /// Crush sample-and-hold factor; 1 passes every sample through.
+    downsample: u32,
+    /// Blend from the untouched (0.0) to the DC-blocked (1.0) signal; only the
+    /// Dirty and Crush characters add offset.
+    dc_block: f32,
+    /// Hold peaks under the safety limiter ceiling; bypassed, the limiter only
+    /// delays by its lookahead so latency stays fixed.
+    limit: bool,
+    /// Final safety shaper.
+    clip: ClipMode,
+}
+
+struct OutputChain {
+    decimator: Decimator,
+    dc_left: DcBlocker,
+    dc_right: DcBlocker,
+    limiter: PeakLimiter,
+    true_peak: Option<TruePeakLimiter>,
+}
+
+impl OutputChain {
+    fn new(sample_rate: f32) -> Self {
         Self {
-            buffer: vec![0.0; length.max(2)],
-            index: 0,
+            decimator: Decimator::default(),
+            dc_left: DcBlocker::default(),
+            dc_right: DcBlocker::default(),
+            limiter: PeakLimiter::new(sample_rate, 0),
+            true_peak: None,
         }
     }
 
-    fn process(&mut self, input: f32, gain: f32) -> f32 {
-        let delayed = self.buffer[self.index];
-        let output = -gain * input + delayed;
-        self.buffer[self.index] = input + gain * output;
-        self.index = (self.index + 1) % self.buffer.len();
-        output
+    /// Current true-peak limiter gain, 1.0 while it is bypassed or idle.
+    fn true_peak_gain(&self) -> f32 {
+        self.true_peak.as_ref().map_or(1.0, TruePeakLimiter::gain)
     }
-}
 
-struct ShortDelay {
-    buffer: Vec<f32>,
-    index: usize,
-}
+    /// Output delay from the sample-peak and true-peak limiter lookaheads.
+    fn latency(&self) -> usize {
+        self.limiter.lookahead + self.true_peak.as_ref().map_or(0, TruePeakLimiter::latency)
+    }
 
-impl Default for ShortDelay {
-    fn default() -> Self {
-        Self::new(23)
+    fn process(&mut self, left: f32, right: f32, control: OutputChainControl) -> (f32, f32) {
+        let mut out_l = left;
+        let mut out_r = right;
+        for stage in OUTPUT_CHAIN {
+            match stage {
+                OutputStage::Drive => {
+                    if control.drive > 1.0 {
+                        out_l = drive_saturate(out_l, control.drive);
+                        out_r = drive_saturate(out_r, control.drive);
+                    }
+                }
+                OutputStage::Crush => {
+                    if control.crush > 0.0 {
+                        let (held_l, held_r) =
+                            self.decimator.process(out_l, out_r, control.downsample);
+                        out_l = lerp(out_l, crush(held_l, control.crush_steps), control.crush);
+                        out_r = lerp(out_r, crush(held_r, control.crush_steps), control.crush);
+                    }
+                }
+                OutputStage::DcBlock => {
+                    // The blockers keep running so a character change fades in settled filters.
+                    let blocked_l = self.dc_left.process(out_l);
+                    let blocked_r = self.dc_right.process(out_r);
+                    out_l = lerp(out_l, blocked_l, control.dc_block);
+                    out_r = lerp(out_r, blocked_r, control.dc_block);
+                }
+                OutputStage::Limit => {
+                    (out_l, out_r) = if control.limit {
+                        self.limiter.process(out_l, out_r)
+                    } else {
+                        self.limiter.process_detected(out_l, out_r, 0.0)
+                    };
+                }
+                OutputStage::TruePeak => {
+                    if let Some(true_peak) = &mut self.true_peak {
+                        (out_l, out_r) = true_peak.process(out_l, out_r);
+                    }
+                }
+                OutputStage::SoftClip => {
+                    out_l = output_clip(out_l, control.clip);
+                    out_r = output_clip(out_r, control.clip);
+                }
+            }
+        }
+        (out_l, out_r)
     }
 }
 
-impl ShortDelay {
-    fn new(length: usize) -> Self {
+/// Whole-sample stereo delay that lines the dry signal up with the limiter lookahead.
+struct DryAlign {
+    left: Vec<f32>,
+    right: Vec<f32>,
+    index: usize,
+}
+
+impl DryAlign {
+    fn new(delay: usize) -> Self {
         Self {
-            buffer: vec![0.0; length.max(2)],
+            left: vec![0.0; delay],
+            right: vec![0.0; delay],
             index: 0,
         }
     }
 
-    fn process(&mut self, input: f32) -> f32 {
-        let delayed = self.buffer[self.index];
-        self.buffer[self.index] = input;
-        self.index = (self.index + 1) % self.buffer.len();
+    fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        if self.left.is_empty() {
+            return (left, right);
+        }
+        let delayed = (self.left[self.index], self.right[self.index]);
+        self.left[self.index] = left;
+        self.right[self.index] = right;
+        self.index = (self.index + 1) % self.left.len();
         delayed
     }
 }
 
-fn next_signed(state: &mut u32) -> f32 {
-    let mut x = *state;
-    x ^= x << 13;
-    x ^= x >> 17;
-    x ^= x << 5;
-    *state = x;
-    ((x as f32 / u32::MAX as f32) * 2.0) - 1.0
+/// Stereo noise gate that mono-izes and attenuates input below a threshold.
+///
+/// While closed the side channel fades out and the mid drops to
+/// [`INPUT_GATE_FLOOR`], so quiet stereo hiss is neither widened nor fed back.
+struct InputGate {
+    envelope: f32,
+    open: f32,
+    envelope_decay: f32,
+    attack: f32,
+    release: f32,
 }
 
-fn wrap_position(position: f32, length: f32) -> f32 {
-    let mut wrapped = position;
-    while wrapped < 0.0 {
-        wrapped += length;
-    }
-    while wrapped >= length {
-        wrapped -= length;
+impl InputGate {
+    fn new(sample_rate: f32) -> Self {
+        let coeff = |seconds: f32| 1.0 - (-1.0 / (seconds * sample_rate.max(1.0))).exp();
+        Self {
+            envelope: 0.0,
+            open: 1.0,
+            envelope_decay: 1.0 - coeff(INPUT_GATE_RELEASE_SECONDS),
+            attack: coeff(INPUT_GATE_ATTACK_SECONDS),
+            release: coeff(INPUT_GATE_RELEASE_SECONDS),
+        }
     }
-    wrapped
-}
 
-fn wrap_delta(delta: f32, length: f32) -> f32 {
-    let mut wrapped = delta;
-    while wrapped > length * 0.5 {
-        wrapped -= length;
-    }
-    while wrapped < -length * 0.5 {
-        wrapped += length;
+    /// Gate one frame against a linear `threshold`; `None` bypasses the gate.
+    fn process(&mut self, left: f32, right: f32, threshold: Option<f32>) -> (f32, f32) {
+        let Some(threshold) = threshold else {
+            self.open = 1.0;
+            return (left, right);
+        };
+        self.envelope = left
+            .abs()
+            .max(right.abs())
+            .max(self.envelope * self.envelope_decay);
+        let target = if self.envelope >= threshold { 1.0 } else { 0.0 };
+        let coeff = if target > self.open {
+            self.attack
+        } else {
+            self.release
+        };
+        self.open += (target - self.open) * coeff;
+        if (target - self.open).abs() < 1.0e-4 {
+            self.open = target;
+        }
+        if self.open >= 1.0 {
+            return (left, right);
+        }
+
+        let mid = (left + right) * 0.5 * (INPUT_GATE_FLOOR + (1.0 - INPUT_GATE_FLOOR) * self.open);
+        let side = (left - right) * 0.5 * self.open;
+        (mid + side, mid - side)
     }
-    wrapped
 }
 
-fn read_cubic(buffer: &[f32], position: f32) -> f32 {
-    let len = buffer.len() as isize;
-    let base = position.floor() as isize;
-    let frac = position - base as f32;
+/// Per-sample one-pole coefficient for continuous controls (about 10 ms at 48 kHz).
+const CONTROL_SMOOTHING: f32 = 0.002;
 
-    let x0 = buffer[((base - 1).rem_euclid(len)) as usize];
-    let x1 = buffer[(base.rem_euclid(len)) as usize];
-    let x2 = buffer[((base + 1).rem_euclid(len)) as usize];
-    let x3 = buffer[((base + 2).rem_euclid(len)) as usize];
+/// Continuous controls read every sample, smoothed so automation and drags don't zipper.
+///
+/// Targets come from the settings once per block and each value glides toward
+/// its target per sample; stepped and enum controls are read from the settings
+/// directly and switch at once.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SmoothedControls {
+    tension: f32,
+    pull_direction: f32,
+    grain_continuity: f32,
+    width: f32,
+    warp_motion: f32,
+    feedback: f32,
+    diffusion: f32,
+    ducking: f32,
+    space_tone: f32,
+    elasticity: f32,
+}
 
-    let a = (-0.5 * x0) + (1.5 * x1) - (1.5 * x2) + (0.5 * x3);
-    let b = x0 - (2.5 * x1) + (2.0 * x2) - (0.5 * x3);
-    let c = (-0.5 * x0) + (0.5 * x2);
-    let d = x1;
+impl SmoothedControls {
+    fn from_settings(settings: &TensionFieldSettings) -> Self {
+        Self {
+            tension: settings.tension,
+            pull_direction: settings.pull_direction,
+            grain_continuity: settings.grain_continuity,
+            width: settings.width,
+            warp_motion: settings.warp_motion,
+            feedback: settings.feedback,
+            diffusion: settings.diffusion,
+            ducking: settings.ducking,
+            space_tone: settings.space_tone,
+            elasticity: settings.elasticity,
+        }
+    }
 
-    ((a * frac + b) * frac + c) * frac + d
+    /// Move every control one sample closer to `target`, landing exactly once close.
+    fn advance(&mut self, target: &Self) {
+        let glide = |value: &mut f32, target: f32| {
+            let delta = target - *value;
+            *value = if delta.abs() < 1.0e-4 {
+                target
+            } else {
+                *value + delta * CONTROL_SMOOTHING
+            };
+        };
+        glide(&mut self.tension, target.tension);
+        glide(&mut self.pull_direction, target.pull_direction);
+        glide(&mut self.grain_continuity, target.grain_continuity);
+        glide(&mut self.width, target.width);
+        glide(&mut self.warp_motion, target.warp_motion);
+        glide(&mut self.feedback, target.feedback);
+        glide(&mut self.diffusion, target.diffusion);
+        glide(&mut self.ducking, target.ducking);
+        glide(&mut self.space_tone, target.space_tone);
+        glide(&mut self.elasticity, target.elasticity);
+    }
 }
 
-fn lerp(a: f32, b: f32, t: f32) -> f32 {
-    a + (b - a) * t
+/// Linear crossfade from the previous to the current character mode.
+///
+/// Both modes' per-sample settings are blended for [`CHARACTER_FADE_SECONDS`]
+/// after a switch, so enabling crush or the dirty gain never lands as a step.
+struct CharacterFade {
+    from: CharacterMode,
+    to: CharacterMode,
+    position: f32,
+    step: f32,
 }
 
-fn db_to_gain(db: f32) -> f32 {
-    10.0_f32.powf(db * 0.05)
-}
+impl CharacterFade {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            from: CharacterMode::Clean,
+            to: CharacterMode::Clean,
+            position: 1.0,
+            step: 1.0 / (CHARACTER_FADE_SECONDS * sample_rate.max(1.0)),
+        }
+    }
 
-fn crush(sample: f32) -> f32 {
-    (sample * 128.0).round() / 128.0
-}
+    /// Move one sample towards `target`, starting a new fade when it changes.
+    fn advance(&mut self, target: CharacterMode) {
+        if target != self.to {
+            if target == self.from {
+                // Reverse a fade in progress from where it stands.
+                self.position = 1.0 - self.position;
+            } else {
+                self.position = 0.0;
+            }
+            self.from = self.to;
+            self.to = target;
+        }
+        self.position = (self.position + self.step).min(1.0);
+    }
 
-fn soft_clip(input: f32) -> f32 {
-    input / (1.0 + input.abs() * 0.6)
+    /// Blend a per-mode value between the faded-from and faded-to modes.
+    fn mix(&self, value: impl Fn(CharacterMode) -> f32) -> f32 {
+        lerp(value(self.from), value(self.to), self.position)
+    }
 }
 
-fn meter_norm(value: f32) -> f32 {
-    (value / (1.0 + value)).clamp(0.0, 1.0)
+/// Stereo-linked peak limiter with optional lookahead.
+///
+/// Without lookahead the gain follows peaks with a short attack and can briefly
+/// overshoot the ceiling. With lookahead the audio is delayed while a running
+/// minimum of the required gain over the lookahead window is box-averaged
+/// across the same window. Every average taken while a peak is in the window
+/// is at or below its required gain, so the gain ramps down to it by the time
+/// the peak is output and the ceiling is never exceeded.
+struct PeakLimiter {
+    delay_left: Vec<f32>,
+    delay_right: Vec<f32>,
+    required: Vec<f32>,
+    /// Monotonic deque of sample positions whose required gain rises front to back.
+    window: Vec<usize>,
+    window_head: usize,
+    window_len: usize,
+    /// Window minimum for each of the last `lookahead + 1` samples.
+    ramp: Vec<f32>,
+    ramp_sum: f64,
+    position: usize,
+    lookahead: usize,
+    ceiling: f32,
+    gain: f32,
+    attack_coeff: f32,
+    release_step: f32,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{TensionFieldEngine, wrap_delta};
-    use crate::clock::TransportState;
-    use crate::params::TensionFieldParams;
+impl PeakLimiter {
+    fn new(sample_rate: f32, lookahead: usize) -> Self {
+        Self::with_ceiling(sample_rate, lookahead, LIMITER_CEILING)
+    }
 
-    #[test]
-    fn wrap_delta_picks_short_path() {
+    fn with_ceiling(sample_rate: f32, lookahead: usize, ceiling: f32) -> Self {
+        let sample_rate = sample_rate.max(1.0);
+        Self {
+            delay_left: vec![0.0; lookahead + 1],
+            delay_right: vec![0.0; lookahead + 1],
+            required: vec![1.0; lookahead + 1],
+            window: vec![0; lookahead + 1],
+            window_head: 0,
+            window_len: 0,
+            ramp: vec![1.0; lookahead + 1],
+            ramp_sum: (lookahead + 1) as f64,
+            position: 0,
+            lookahead,
+            ceiling,
+            gain: 1.0,
+            attack_coeff: 1.0 - (-1.0 / (LIMITER_ATTACK_SECONDS * sample_rate)).exp(),
+            release_step: 1.0 / (LIMITER_RELEASE_SECONDS * sample_rate),
+        }
+    }
+
+    fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        self.process_detected(left, right, left.abs().max(right.abs()))
+    }
+
+    /// Limit one frame against a `peak` found by an external detector.
+    fn process_detected(&mut self, left: f32, right: f32, peak: f32) -> (f32, f32) {
+        let required = if peak > self.ceiling {
+            self.ceiling / peak
+        } else {
+            1.0
+        };
+
+        if self.lookahead == 0 {
+            if required < self.gain {
+                self.gain += (required - self.gain) * self.attack_coeff;
+            } else {
+                self.gain = (self.gain + self.release_step).min(required);
+            }
+            return (left * self.gain, right * self.gain);
+        }
+
+        let len = self.required.len();
+        let slot = self.position % len;
+        self.delay_left[slot] = left;
+        self.delay_right[slot] = right;
+        self.required[slot] = required;
+
+        if self.window_len > 0 && self.window[self.window_head] + len <= self.position {
+            self.window_head = (self.window_head + 1) % len;
+            self.window_len -= 1;
+        }
+        while self.window_len > 0 {
+            let back = self.window[(self.window_head + self.window_len - 1) % len];
+            if self.required[back % len] < required {
+                break;
+            }
+            self.window_len -= 1;
+        }
+        self.window[(self.window_head + self.window_len) % len] = self.position;
+        self.window_len += 1;
+        let window_min = self.required[self.window[self.window_head] % len];
+
+        self.ramp_sum += f64::from(window_min) - f64::from(self.ramp[slot]);
+        self.ramp[slot] = window_min;
+        let ceiling = (self.ramp_sum / len as f64) as f32;
+        self.gain = (self.gain + self.release_step).min(ceiling);
+
+        self.position += 1;
+        let read_index = self.position % len;
+        (
+            self.delay_left[read_index] * self.gain,
+            self.delay_right[read_index] * self.gain,
+        )
+    }
+}
+
+/// Optional limiter that holds inter-sample peaks under [`TRUE_PEAK_CEILING`].
+///
+/// Peaks are detected at 2x: each sample is compared together with the
+/// half-band interpolated midpoints on either side of it, so a peak that
+/// falls between two samples still pulls the lookahead gain ramp down.
+struct TruePeakLimiter {
+    taps_left: [f32; 5],
+    taps_right: [f32; 5],
+    limiter: PeakLimiter,
+}
+
+impl TruePeakLimiter {
+    fn new(sample_rate: f32) -> Self {
+        let lookahead = (TRUE_PEAK_LOOKAHEAD_SECONDS * sample_rate.max(1.0)).round() as usize;
+        Self {
+            taps_left: [0.0; 5],
+            taps_right: [0.0; 5],
+            limiter: PeakLimiter::with_ceiling(sample_rate, lookahead.max(1), TRUE_PEAK_CEILING),
+        }
+    }
+
+    /// Samples of delay the detector and lookahead add to the output.
+    fn latency(&self) -> usize {
+        TRUE_PEAK_DETECTOR_DELAY + self.limiter.lookahead
+    }
+
+    /// Current gain applied to the output (1.0 = no reduction).
+    fn gain(&self) -> f32 {
+        self.limiter.gain
+    }
+
+    fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        self.taps_left.rotate_left(1);
+        self.taps_right.rotate_left(1);
+        self.taps_left[4] = left;
+        self.taps_right[4] = right;
+        let peak =
+            true_peak_around_center(&self.taps_left).max(true_peak_around_center(&self.taps_right));
+        self.limiter
+            .process_detected(self.taps_left[2], self.taps_right[2], peak)
+    }
+}
+
+/// Largest of the center tap and the 2x midpoints on either side of it.
+fn true_peak_around_center(taps: &[f32; 5]) -> f32 {
+    let midpoint = |a: f32, b: f32, c: f32, d: f32| (9.0 * (b + c) - (a + d)) / 16.0;
+    let before = midpoint(taps[0], taps[1], taps[2], taps[3]);
+    let after = midpoint(taps[1], taps[2], taps[3], taps[4]);
+    taps[2].abs().max(before.abs()).max(after.abs())
+}
+
+/// Stereo sample-and-hold decimator; one shared counter keeps both channels in phase.
+#[derive(Default)]
+struct Decimator {
+    held_left: f32,
+    held_right: f32,
+    counter: u32,
+}
+
+impl Decimator {
+    /// Capture a new frame every `factor` samples and repeat it in between.
+    fn process(&mut self, left: f32, right: f32, factor: u32) -> (f32, f32) {
+        if self.counter == 0 {
+            self.held_left = left;
+            self.held_right = right;
+        }
+        self.counter += 1;
+        if self.counter >= factor {
+            self.counter = 0;
+        }
+        (self.held_left, self.held_right)
+    }
+}
+
+#[derive(Default)]
+struct DcBlocker {
+    previous_input: f32,
+    previous_output: f32,
+}
+
+impl DcBlocker {
+    fn process(&mut self, input: f32) -> f32 {
+        let output = input - self.previous_input + self.previous_output * 0.9995;
+        self.previous_input = input;
+        self.previous_output = output;
+        output
+    }
+}
+
+struct AllpassDelay {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl AllpassDelay {
+    fn new(length: usize) -> Self {
+        Self {
+            buffer: vec![0.0; length.max(2)],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32, gain: f32) -> f32 {
+        let delayed = self.buffer[self.index];
+        self.feed(input, gain, delayed)
+    }
+
+    /// Process with a fractional delay of `length` samples, up to the buffer length minus 3.
+    fn process_with_length(&mut self, input: f32, gain: f32, length: f32) -> f32 {
+        let delayed = read_delayed(&self.buffer, self.index, length);
+        self.feed(input, gain, delayed)
+    }
+
+    fn feed(&mut self, input: f32, gain: f32, delayed: f32) -> f32 {
+        let output = -gain * input + delayed;
+        self.buffer[self.index] = input + gain * output;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+/// Plain delay with a fractional, per-sample delay length.
+struct ShortDelay {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl ShortDelay {
+    /// Allocate for delay lengths up to `capacity - 3` samples.
+    fn new(capacity: usize) -> Self {
+        Self {
+            buffer: vec![0.0; capacity.max(4)],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32, length: f32) -> f32 {
+        let delayed = read_delayed(&self.buffer, self.index, length);
+        self.buffer[self.index] = input;
+        self.index = (self.index + 1) % self.buffer.len();
+        delayed
+    }
+}
+
+fn next_signed(state: &mut u32) -> f32 {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    *state = x;
+    ((x as f32 / u32::MAX as f32) * 2.0) - 1.0
+}
+
+fn wrap_position(position: f32, length: f32) -> f32 {
+    let mut wrapped = position;
+    while wrapped < 0.0 {
+        wrapped += length;
+    }
+    while wrapped >= length {
+        wrapped -= length;
+    }
+    wrapped
+}
+
+fn wrap_delta(delta: f32, length: f32) -> f32 {
+    let mut wrapped = delta;
+    while wrapped > length * 0.5 {
+        wrapped -= length;
+    }
+    while wrapped < -length * 0.5 {
+        wrapped += length;
+    }
+    wrapped
+}
+
+/// Read `length` samples behind `write_index`, clamped to what the buffer holds.
+///
+/// Integer lengths read the stored sample exactly, so a fixed size matches a
+/// plain ring buffer of that length.
+fn read_delayed(buffer: &[f32], write_index: usize, length: f32) -> f32 {
+    let len = buffer.len() as f32;
+    let length = length.clamp(2.0, len - 3.0);
+    read_cubic(buffer, wrap_position(write_index as f32 - length, len))
+}
+
+/// Cubic (Catmull-Rom) read at a fractional `position`, wrapping around the buffer.
+///
+/// Only the position matters, so heads moving backward interpolate the same way.
+fn read_cubic(buffer: &[f32], position: f32) -> f32 {
+    let len = buffer.len() as isize;
+    let base = position.floor() as isize;
+    let frac = position - base as f32;
+
+    let x0 = buffer[((base - 1).rem_euclid(len)) as usize];
+    let x1 = buffer[(base.rem_euclid(len)) as usize];
+    let x2 = buffer[((base + 1).rem_euclid(len)) as usize];
+    let x3 = buffer[((base + 2).rem_euclid(len)) as usize];
+
+    let a = (-0.5 * x0) + (1.5 * x1) - (1.5 * x2) + (0.5 * x3);
+    let b = x0 - (2.5 * x1) + (2.0 * x2) - (0.5 * x3);
+    let c = (-0.5 * x0) + (0.5 * x2);
+    let d = x1;
+
+    ((a * frac + b) * frac + c) * frac + d
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn db_to_gain(db: f32) -> f32 {
+    10.0_f32.powf(db * 0.05)
+}
+
+/// Quantize `sample` to `steps` levels per unit of full scale.
+fn crush(sample: f32, steps: f32) -> f32 {
+    (sample * steps).round() / steps
+}
+
+/// Return the level trim applied ahead of the character nonlinearities.
+///
+/// Measured with a 220 Hz sine across low to high tension, Dirty and Crush come
+/// out 1.3-2% hotter than Clean (the space-stage dirt gain and stronger drift),
+/// so matching their level to Clean means a small cut rather than a boost.
+fn character_level_trim(character: CharacterMode) -> f32 {
+    match character {
+        CharacterMode::Clean => 1.0,
+        CharacterMode::Dirty => 0.984,
+        CharacterMode::Crush => 0.984,
+    }
+}
+
+/// Saturate `sample` driven by `drive`, scaled back so a `DRIVE_REFERENCE_LEVEL`
+/// peak leaves at the same level it came in.
+fn drive_saturate(sample: f32, drive: f32) -> f32 {
+    let makeup = DRIVE_REFERENCE_LEVEL / soft_clip(DRIVE_REFERENCE_LEVEL * drive);
+    soft_clip(sample * drive) * makeup
+}
+
+fn soft_clip(input: f32) -> f32 {
+    input / (1.0 + input.abs() * 0.6)
+}
+
+/// Shape one output sample with the selected clip mode.
+fn output_clip(input: f32, mode: ClipMode) -> f32 {
+    match mode {
+        ClipMode::Soft => soft_clip(input),
+        ClipMode::Tanh => input.tanh(),
+        ClipMode::Hard => input.clamp(-1.0, 1.0),
+        ClipMode::Off => input,
+    }
+}
+
+/// Skew a symmetric jitter value so grains favour longer (+) or shorter (-) delays.
+fn skew_jitter(jitter: f32, bias: f32) -> f32 {
+    let bias = bias.clamp(-1.0, 1.0);
+    if jitter >= 0.0 {
+        jitter * (1.0 + bias)
+    } else {
+        jitter * (1.0 - bias)
+    }
+}
+
+/// Convert a limiter lookahead time to whole samples, capped at the parameter maximum.
+fn lookahead_samples(sample_rate: f32, lookahead_ms: f32) -> usize {
+    (lookahead_ms.clamp(0.0, MAX_LIMIT_LOOKAHEAD_MS) * 0.001 * sample_rate.max(1.0)).round()
+        as usize
+}
+
+/// Per-channel feedback scales for a -1..1 balance; the favoured side keeps full amount.
+fn feedback_balance_scales(balance: f32) -> (f32, f32) {
+    let balance = balance.clamp(-1.0, 1.0);
+    ((1.0 - balance).min(1.0), (1.0 + balance).min(1.0))
+}
+
+/// Read-speed offset from gesture velocity, snapped to the key-lock scale when set.
+fn coupled_pitch_offset(velocity: f32, pitch_coupling: f32, key_lock: KeyLock) -> f32 {
+    let offset = velocity * pitch_coupling * 0.48;
+    if key_lock == KeyLock::Off {
+        return offset;
+    }
+    // The read speed is clamped to 0.35 downstream; this floor just keeps log2 finite.
+    let ratio = (1.0 + offset).max(0.35);
+    let semitones = key_lock.snap_semitones(12.0 * ratio.log2());
+    2.0_f32.powf(semitones / 12.0) - 1.0
+}
+
+fn meter_norm(value: f32) -> f32 {
+    (value / (1.0 + value)).clamp(0.0, 1.0)
+}
+
+/// Bar fill (0..1) at which a linear peak level sits on the activity meters.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn level_meter_fill(level: f32) -> f32 {
+    meter_norm(level)
+}
+
+/// Bar fill at which a `dbfs` level sits, on the dBFS scale or the level scale.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn dbfs_meter_fill(dbfs: f32, dbfs_scale: bool) -> f32 {
+    if dbfs_scale {
+        (dbfs / -METER_FLOOR_DBFS + 1.0).clamp(0.0, 1.0)
+    } else {
+        level_meter_fill(db_to_gain(dbfs))
+    }
+}
+
+/// Color band a meter bar is drawn in.
+#[cfg(any(target_os = "windows", test))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum MeterZone {
+    /// Below the warning level.
+    Normal,
+    /// Above the warning level.
+    Warn,
+}
+
+/// Pick the color band for a bar fill against the warning fill.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn meter_zone(fill: f32, warn_fill: f32) -> MeterZone {
+    if fill > warn_fill {
+        MeterZone::Warn
+    } else {
+        MeterZone::Normal
+    }
+}
+
+/// Shape a 0..1 warp motion amount by a response-curve exponent (1 = linear).
+fn warp_motion_curve(warp_motion: f32, exponent: f32) -> f32 {
+    warp_motion.clamp(0.0, 1.0).powf(exponent.max(0.01))
+}
+
+/// Width offset from elasticity: springy settings widen, viscous settings narrow.
+///
+/// Neutral at mid elasticity; full coupling moves width by `ELASTICITY_WIDTH_RANGE`
+/// either way.
+fn elasticity_width_offset(elasticity: f32, coupling: f32) -> f32 {
+    (elasticity.clamp(0.0, 1.0) - 0.5) * 2.0 * ELASTICITY_WIDTH_RANGE * coupling.clamp(0.0, 1.0)
+}
+
+/// Width left after the feedback safety narrows it; unchanged when the safety is off.
+///
+/// Each unit of feedback removes `FEEDBACK_WIDTH_SAFETY` of the width, so heavy
+/// regeneration collapses toward mono instead of turning phasey.
+fn feedback_width_safety(width: f32, feedback: f32, enabled: bool) -> f32 {
+    if !enabled {
+        return width;
+    }
+    width * (1.0 - feedback.clamp(0.0, 1.0) * FEEDBACK_WIDTH_SAFETY).max(0.0)
+}
+
+/// Pick the gesture value the Tension meter reports for one frame.
+fn tension_meter_value(source: TensionMeterSource, frame: &GestureFrame, tension: f32) -> f32 {
+    match source {
+        TensionMeterSource::Drive => frame.tension_drive,
+        TensionMeterSource::Envelope => frame.envelope,
+        TensionMeterSource::Effective => tension,
+    }
+}
+
+/// Convert a linear peak to dBFS, clamped to [`METER_FLOOR_DBFS`] for silence.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn peak_to_dbfs(peak: f32) -> f32 {
+    if peak.is_nan() || peak <= 0.0 {
+        return METER_FLOOR_DBFS;
+    }
+    (20.0 * peak.log10()).max(METER_FLOOR_DBFS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ActivityMeter, CONTROL_SMOOTHING, ELASTIC_MAX_DELAY_SECONDS, ElasticBuffer, ElasticControl,
+        IDLE_BYPASS_SECONDS, InputGate, LIMITER_CEILING, METER_FLOOR_DBFS, MeterZone, NoteTrigger,
+        OutputChain, OutputChainControl, PeakLimiter, RenderReport, SmoothedControls, SpaceControl,
+        SpaceStage, SpectralWarp, TensionFieldEngine, TransientDetector, TriggerSource,
+        TruePeakLimiter, WarpControl, coupled_pitch_offset, db_to_gain, dbfs_meter_fill,
+        level_meter_fill, lookahead_samples, meter_norm, meter_zone, output_clip, peak_to_dbfs,
+        space_size_scale, tempo_followed, tension_meter_value, warp_motion_curve, wrap_delta,
+    };
+    use crate::clock::TransportState;
+    use crate::diagnostics::SanitizeSource;
+    use crate::gesture::GestureFrame;
+    use crate::params::{
+        CharacterMode, ClipMode, KeyLock, ModRateMode, TensionFieldParams, TensionFieldSettings,
+        TensionMeterSource, WarpColor,
+    };
+
+    fn sine_rms(settings: &TensionFieldSettings) -> f32 {
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut sum = 0.0_f64;
+        let mut count = 0;
+        for block in 0..64 {
+            let mut left: Vec<f32> = (0..512)
+                .map(|i| {
+                    let n = (block * 512 + i) as f32;
+                    (n * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.3
+                })
+                .collect();
+            let mut right = left.clone();
+            let _ = engine.render(settings, &mut left, &mut right, TransportState::default());
+            if block >= 16 {
+                for (l, r) in left.iter().zip(right.iter()) {
+                    sum += ((l * l + r * r) * 0.5) as f64;
+                    count += 1;
+                }
+            }
+        }
+        (sum / count as f64).sqrt() as f32
+    }
+
+    #[test]
+    fn wet_path_delay_is_reported_latency_plus_moving_elastic_delay() {
+        // Low-passed noise so the cross-correlation peak tracks the low-frequency group delay.
+        let mut seed = 0x1357_9bdf_u32;
+        let mut smoothed = 0.0_f32;
+        let input: Vec<f32> = (0..72_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let noise = (seed >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0;
+                smoothed += (noise - smoothed) * 0.05;
+                smoothed
+            })
+            .collect();
+        let measure = |tension: f32| {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_TENSION_ID, tension);
+            params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.0);
+            params.set_param(crate::params::PARAM_DIFFUSION_ID, 0.0);
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            engine.set_limit_lookahead_ms(1.0);
+            let mut left = input.clone();
+            let mut right = input.clone();
+            let mut report = RenderReport::default();
+            for (block_l, block_r) in left.chunks_mut(512).zip(right.chunks_mut(512)) {
+                report = engine.render(&settings, block_l, block_r, TransportState::default());
+            }
+            let window = 64_000..72_000;
+            let (lag, _) = (0..6_000)
+                .map(|lag| {
+                    let score: f64 = window
+                        .clone()
+                        .map(|n| f64::from(left[n] * input[n - lag]))
+                        .sum();
+                    (lag, score)
+                })
+                .fold(
+                    (0, f64::MIN),
+                    |best, next| if next.1 > best.1 { next } else { best },
+                );
+            let elastic = report.elastic_delay_ms * 48.0;
+            (engine.latency_samples() as f32, lag as f32, elastic)
+        };
+
+        let mut lags = Vec::new();
+        for tension in [0.0, 0.15, 0.3] {
+            let (latency, lag, elastic) = measure(tension);
+            assert_eq!(latency, 48.0);
+            // The wet path is the fixed output latency plus the elastic delay the
+            // gesture is currently holding, plus a short settle through the warp.
+            let expected = latency + elastic;
+            assert!(
+                (expected..expected + 150.0).contains(&lag),
+                "tension {tension}: wet lag {lag}, latency {latency}, elastic {elastic}"
+            );
+            lags.push(lag);
+        }
+        // The elastic delay is the effect itself and moves with tension, so it
+        // cannot be folded into one fixed reported latency.
+        assert!(lags[2] - lags[0] > 1_000.0, "wet lags {lags:?}");
+    }
+
+    #[test]
+    fn dry_mix_returns_input_aligned_with_limiter_lookahead() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_MIX_ID, 0.0);
+        let settings = params.settings();
+        let input: Vec<f32> = (0..48_000)
+            .map(|n| (n as f32 * 330.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4)
+            .collect();
+
+        // The true-peak limiter adds its 1.5 ms lookahead and 2-sample detector delay.
+        for (true_peak, expected_latency) in [(false, 48), (true, 48 + 72 + 2)] {
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            engine.set_limit_lookahead_ms(1.0);
+            engine.set_true_peak_limit(true_peak);
+            let lookahead = engine.latency_samples() as usize;
+            assert_eq!(lookahead, expected_latency);
+
+            let mut left = input.clone();
+            let mut right = input.clone();
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+
+            // Once the mix has settled at dry, the output is the input delayed by the lookahead.
+            for n in 40_000..48_000 {
+                assert!((left[n] - input[n - lookahead]).abs() < 1.0e-4);
+                assert!((right[n] - input[n - lookahead]).abs() < 1.0e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn reported_latency_stays_fixed_while_tension_moves_the_elastic_delay() {
+        let input: Vec<f32> = (0..48_000)
+            .map(|n| (n as f32 * 110.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4)
+            .collect();
+        let mut elastic_delays = Vec::new();
+        for tension in [0.1, 0.5, 0.9] {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_TENSION_ID, tension);
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            engine.set_limit_lookahead_ms(2.0);
+
+            let mut left = input.clone();
+            let mut right = input.clone();
+            let report = engine.render(
+                &params.settings(),
+                &mut left,
+                &mut right,
+                TransportState::default(),
+            );
+            elastic_delays.push(report.elastic_delay_ms);
+            assert_eq!(engine.latency_samples(), 96);
+
+            // The dry path at the same tension lands exactly on the reported latency.
+            params.set_param(crate::params::PARAM_MIX_ID, 0.0);
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            engine.set_limit_lookahead_ms(2.0);
+            let latency = engine.latency_samples() as usize;
+            let mut left = input.clone();
+            let mut right = input.clone();
+            let _ = engine.render(
+                &params.settings(),
+                &mut left,
+                &mut right,
+                TransportState::default(),
+            );
+            for n in 40_000..48_000 {
+                assert!((left[n] - input[n - latency]).abs() < 1.0e-4);
+                assert!((right[n] - input[n - latency]).abs() < 1.0e-4);
+            }
+        }
+        // Tension moves the wet delay, which the fixed latency deliberately ignores.
+        assert!(
+            elastic_delays[2] > elastic_delays[0] + 20.0,
+            "{elastic_delays:?}"
+        );
+    }
+
+    #[test]
+    fn width_step_glides_across_samples_instead_of_jumping() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_WIDTH_ID, 0.0);
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let render_block = |engine: &mut TensionFieldEngine, settings: &TensionFieldSettings| {
+            let mut left = vec![0.2; 480];
+            let mut right = vec![-0.1; 480];
+            let _ = engine.render(settings, &mut left, &mut right, TransportState::default());
+        };
+        render_block(&mut engine, &params.settings());
+        assert_eq!(engine.controls.map(|controls| controls.width), Some(0.0));
+
+        // One block after a full-scale step the width is still on its way.
+        params.set_param(crate::params::PARAM_WIDTH_ID, 1.0);
+        let settings = params.settings();
+        render_block(&mut engine, &settings);
+        let width = engine.controls.map_or(0.0, |controls| controls.width);
+        assert!(width > 0.3 && width < 0.9, "width after one block {width}");
+
+        // Sample by sample, no step is larger than the smoothing coefficient.
+        let target = SmoothedControls::from_settings(&settings);
+        let mut controls = SmoothedControls {
+            width: 0.0,
+            ..target
+        };
+        let mut previous = controls.width;
+        for _ in 0..480 {
+            controls.advance(&target);
+            assert!(controls.width - previous <= CONTROL_SMOOTHING + 1.0e-6);
+            assert!(controls.width >= previous);
+            previous = controls.width;
+        }
+        for _ in 0..20_000 {
+            controls.advance(&target);
+        }
+        assert_eq!(controls, target);
+    }
+
+    #[test]
+    fn stage_modulation_reports_the_routed_warp_motion_offset() {
+        let render_with_depth = |depth: f32| {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_MOD_RUN_ID, 1.0);
+            params.set_param(crate::params::PARAM_MOD_A_DEPTH_ID, 1.0);
+            params.set_param(crate::params::PARAM_MOD_A_RATE_MODE_ID, 0.0);
+            params.set_param(crate::params::PARAM_MOD_A_RATE_HZ_ID, 1.0);
+            params.set_param(crate::params::PARAM_MOD_A_TO_WARP_MOTION_ID, depth);
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let mut report = RenderReport::default();
+            for _ in 0..25 {
+                let mut left = vec![0.2; 480];
+                let mut right = vec![0.2; 480];
+                report = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            }
+            report.stage_modulation
+        };
+
+        let routed = render_with_depth(0.8);
+        assert!(routed[1].abs() > 0.01, "warp modulation {routed:?}");
+        let unrouted = render_with_depth(0.0);
+        assert_eq!(unrouted[1], 0.0);
+    }
+
+    #[test]
+    fn warp_motion_curve_is_monotonic_and_linear_at_unity() {
+        for exponent in [0.5, 1.0, 2.0, 4.0] {
+            let mut previous = warp_motion_curve(0.0, exponent);
+            for step in 1..=100 {
+                let value = warp_motion_curve(step as f32 / 100.0, exponent);
+                assert!(value >= previous, "exponent {exponent} dips at step {step}");
+                previous = value;
+            }
+            assert_eq!(warp_motion_curve(1.0, exponent), 1.0);
+        }
+        for step in 0..=100 {
+            let amount = step as f32 / 100.0;
+            assert_eq!(warp_motion_curve(amount, 1.0), amount);
+        }
+        // Higher exponents keep the low end of the knob subtler.
+        assert!(warp_motion_curve(0.3, 2.0) < 0.3);
+    }
+
+    #[test]
+    fn width_safety_narrows_width_as_feedback_rises() {
+        let mut previous = super::feedback_width_safety(0.8, 0.0, true);
+        assert_eq!(previous, 0.8);
+        for step in 1..=7 {
+            let feedback = step as f32 * 0.1;
+            let width = super::feedback_width_safety(0.8, feedback, true);
+            assert!(
+                width < previous,
+                "feedback {feedback}: {width} >= {previous}"
+            );
+            assert!(width >= 0.0);
+            assert_eq!(super::feedback_width_safety(0.8, feedback, false), 0.8);
+            previous = width;
+        }
+    }
+
+    #[test]
+    fn elasticity_to_width_widens_springy_settings() {
+        // Side-to-mid energy ratio of the output for a mono input.
+        let side_ratio = |elasticity: f32, coupling: f32| {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_WIDTH_ID, 0.5);
+            params.set_param(crate::params::PARAM_ELASTICITY_ID, elasticity);
+            params.set_param(crate::params::PARAM_ELASTICITY_TO_WIDTH_ID, coupling);
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let (mut mid, mut side) = (0.0, 0.0);
+            for block in 0..200 {
+                let mut left: Vec<f32> = (0..480)
+                    .map(|n| {
+                        let n = (block * 480 + n) as f32;
+                        (n * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.3
+                    })
+                    .collect();
+                let mut right = left.clone();
+                let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+                if block >= 100 {
+                    for (l, r) in left.iter().zip(&right) {
+                        mid += (l + r).powi(2);
+                        side += (l - r).powi(2);
+                    }
+                }
+            }
+            side / mid
+        };
+
+        assert_eq!(super::elasticity_width_offset(0.5, 1.0), 0.0);
+        assert_eq!(super::elasticity_width_offset(0.9, 0.0), 0.0);
+        let viscous = side_ratio(0.1, 1.0);
+        let springy = side_ratio(0.9, 1.0);
+        assert!(
+            springy > viscous * 1.2,
+            "springy {springy} vs viscous {viscous}"
+        );
+    }
+
+    #[test]
+    fn longer_elastic_size_reaches_longer_delays_without_reallocating() {
+        let reach_ms = |size_seconds: f32| {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_TENSION_ID, 1.0);
+            params.set_param(crate::params::PARAM_ELASTICITY_ID, 1.0);
+            params.set_param(crate::params::PARAM_PULL_DIRECTION_ID, 1.0);
+            params.set_param(crate::params::PARAM_ELASTIC_SIZE_ID, size_seconds);
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let buffer = engine.stages.elastic.left.as_ptr();
+            let mut reach = 0.0_f32;
+            for _ in 0..400 {
+                let mut left = vec![0.1; 480];
+                let mut right = vec![0.1; 480];
+                let report =
+                    engine.render(&settings, &mut left, &mut right, TransportState::default());
+                reach = reach.max(report.elastic_delay_ms);
+            }
+            assert_eq!(engine.stages.elastic.left.as_ptr(), buffer);
+            assert_eq!(
+                engine.stages.elastic.left.len(),
+                (48_000.0 * ELASTIC_MAX_DELAY_SECONDS).ceil() as usize + 4
+            );
+            assert!(reach <= size_seconds * 1_000.0);
+            reach
+        };
+
+        let short = reach_ms(0.5);
+        let default = reach_ms(2.75);
+        let long = reach_ms(6.0);
+        assert!(
+            short < default && default < long,
+            "{short} {default} {long}"
+        );
+        assert!(long > default * 2.0);
+    }
+
+    #[test]
+    fn tail_grows_with_feedback_and_is_flagged_after_input_stops() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_DIFFUSION_ID, 0.8);
+        let engine = TensionFieldEngine::new(48_000.0);
+        let tail_at = |feedback: f32| {
+            params.set_param(crate::params::PARAM_FEEDBACK_ID, feedback);
+            engine.tail_samples(&params.settings())
+        };
+        let dry_tail = tail_at(0.0);
+        assert!(dry_tail >= (params.settings().elastic_size_seconds * 48_000.0) as u32);
+        assert!(tail_at(0.2) > dry_tail);
+        assert!(tail_at(0.6) > tail_at(0.2));
+
+        params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.5);
+        let settings = params.settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut left: Vec<f32> = (0..48_000)
+            .map(|n| (n as f32 * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4)
+            .collect();
+        let mut right = left.clone();
+        let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+        assert!(!engine.tail_pending());
+
+        let mut silent_left = vec![0.0; 512];
+        let mut silent_right = vec![0.0; 512];
+        let _ = engine.render(
+            &settings,
+            &mut silent_left,
+            &mut silent_right,
+            TransportState::default(),
+        );
+        assert!(engine.tail_pending());
+    }
+
+    #[test]
+    fn input_gate_attenuates_quiet_input_and_passes_loud_input_unchanged() {
+        let mut gate = InputGate::new(48_000.0);
+        let threshold = Some(db_to_gain(-40.0));
+
+        let mut quiet = (0.0, 0.0);
+        for _ in 0..48_000 {
+            quiet = gate.process(0.002, 0.001, threshold);
+        }
+        // Sub-threshold input is folded to mono and pulled down to the floor.
+        assert!((quiet.0 - quiet.1).abs() < 1.0e-6);
+        assert!(quiet.0.abs() < 0.002 * 0.2, "gated level {}", quiet.0);
+
+        let mut loud = (0.0, 0.0);
+        for _ in 0..4_800 {
+            loud = gate.process(0.5, -0.3, threshold);
+        }
+        assert_eq!(loud, (0.5, -0.3));
+
+        let mut bypassed = InputGate::new(48_000.0);
+        assert_eq!(bypassed.process(0.002, 0.001, None), (0.002, 0.001));
+    }
+
+    #[test]
+    fn connected_sidechain_drives_ducking_instead_of_main_input() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_DUCKING_ID, 1.0);
+        let settings = params.settings();
+        let render_duck_level = |sidechain: Option<&[f32]>| {
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            engine.reserve_sidechain(512);
+            let mut report = RenderReport::default();
+            for block in 0..20 {
+                engine.set_sidechain(sidechain);
+                let mut left: Vec<f32> = (0..512)
+                    .map(|n| ((block * 512 + n) as f32 * 0.05).sin() * 0.05)
+                    .collect();
+                let mut right = left.clone();
+                report = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            }
+            report.duck_level
+        };
+
+        let loud = [0.9; 512];
+        let keyed = render_duck_level(Some(&loud));
+        let unkeyed = render_duck_level(None);
+        assert!(keyed > 0.8, "sidechain duck level {keyed}");
+        assert!(unkeyed < 0.1, "main-input duck level {unkeyed}");
+    }
+
+    #[test]
+    fn single_channel_buffer_runs_through_full_engine_as_dual_mono() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_PULL_LATCH_ID, 1.0);
+        params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.4);
+        params.set_param(crate::params::PARAM_HAAS_MS_ID, 8.0);
+        let settings = params.settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut mono: Vec<f32> = (0..48_000)
+            .map(|n| (n as f32 * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4)
+            .collect();
+        let mut scratch = vec![0.0; 512];
+
+        for block in mono.chunks_mut(512) {
+            let _ = engine.render_mono_with_triggers(
+                &settings,
+                block,
+                &mut scratch,
+                TransportState::default(),
+                &[],
+            );
+        }
+
+        assert!(mono.iter().all(|sample| sample.is_finite()));
+        let tail_peak = mono[24_000..].iter().fold(0.0_f32, |m, s| m.max(s.abs()));
+        assert!(tail_peak > 0.05);
+    }
+
+    #[test]
+    fn single_sample_blocks_match_one_large_block() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.4);
+        params.set_param(crate::params::PARAM_PULL_LATCH_ID, 1.0);
+        let settings = params.settings();
+        let transport = TransportState {
+            tempo_bpm: 120.0,
+            is_playing: true,
+            song_pos_beats: None,
+            ..TransportState::default()
+        };
+        let input: Vec<f32> = (0..2048)
+            .map(|i| (i as f32 * 330.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4)
+            .collect();
+
+        let mut block_engine = TensionFieldEngine::new(48_000.0);
+        let mut block_left = input.clone();
+        let mut block_right = input.clone();
+        let block_report =
+            block_engine.render(&settings, &mut block_left, &mut block_right, transport);
+
+        let mut tiny_engine = TensionFieldEngine::new(48_000.0);
+        let mut tiny_report = RenderReport::default();
+        for (index, sample) in input.iter().enumerate() {
+            let mut left = [*sample];
+            let mut right = [*sample];
+            tiny_report.merge(tiny_engine.render(&settings, &mut left, &mut right, transport));
+            assert_eq!(left[0].to_bits(), block_left[index].to_bits());
+            assert_eq!(right[0].to_bits(), block_right[index].to_bits());
+        }
+
+        assert!((tiny_report.output_left - block_report.output_left).abs() < 1.0e-6);
+        assert!((tiny_report.tension_activity - block_report.tension_activity).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn nan_input_records_panic_snapshot_with_settings() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_TENSION_ID, 0.37);
+        params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.5);
+        let settings = params.settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut left: Vec<f32> = (0..256).map(|n| (n as f32 * 0.05).sin() * 0.3).collect();
+        let mut right = left.clone();
+        left[100] = f32::NAN;
+        assert!(engine.take_panic_snapshot().is_none());
+
+        let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+
+        let snapshot = engine
+            .take_panic_snapshot()
+            .expect("sanitizer should record a snapshot");
+        assert_eq!(snapshot.source, SanitizeSource::Input);
+        assert_eq!(snapshot.settings.tension, settings.tension);
+        assert_eq!(snapshot.settings.feedback, settings.feedback);
+        assert!(snapshot.recent_input.last().expect("history").0.is_nan());
+        assert!(left.iter().chain(&right).all(|sample| sample.is_finite()));
+        assert!(engine.take_panic_snapshot().is_none());
+    }
+
+    #[test]
+    fn synced_pull_phase_follows_block_tempo_and_stops_with_transport() {
+        let settings = TensionFieldParams::new().settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut render_block = |tempo_bpm: f32, is_playing: bool, song_pos_beats| {
+            let mut left: Vec<f32> = (0..6_000).map(|n| (n as f32 * 0.03).sin() * 0.3).collect();
+            let mut right = left.clone();
+            let transport = TransportState {
+                tempo_bpm,
+                is_playing,
+                song_pos_beats,
+                ..TransportState::default()
+            };
+            engine
+                .render(&settings, &mut left, &mut right, transport)
+                .gesture_phase
+        };
+        // The reported phase is the last frame's, one beat increment short of the block end.
+        let last_frame_beats =
+            |start: f64, tempo_bpm: f64| start + 5_999.0 * tempo_bpm / 2_880_000.0;
+
+        // Default settings pull once per quarter note, so the phase is the beat fraction.
+        let first = render_block(120.0, true, Some(0.0));
+        assert!((first as f64 - last_frame_beats(0.0, 120.0)).abs() < 1.0e-4);
+
+        // Halving the tempo halves the beat advance of the next block.
+        let second = render_block(60.0, true, None);
+        assert!((second as f64 - last_frame_beats(0.25, 60.0)).abs() < 1.0e-4);
+
+        // A stopped transport holds the beat position.
+        let stopped = render_block(60.0, false, None);
+        assert!((stopped as f64 - 0.375).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn scrub_moves_read_position_across_frozen_buffer() {
+        const WINDOW: f32 = 2.75 * 48_000.0;
+        let control = |scrub: Option<f32>| ElasticControl {
+            delay_samples: 4_000.0,
+            velocity: 0.0,
+            pitch_coupling: 0.0,
+            key_lock: KeyLock::Off,
+            grain_amount: 0.0,
+            grain_bias: 0.0,
+            spring: 0.5,
+            dirty: false,
+            transient_mark: 0.0,
+            scrub,
+            window_samples: WINDOW,
+            freeze: false,
+            reverse: false,
+        };
+        let mut buffer = ElasticBuffer::new(48_000.0);
+        for n in 0..144_000 {
+            let input = (n as f32 * 0.031).sin() * 0.5;
+            let _ = buffer.process(input, input, control(None), None);
+        }
+        let frozen_at = buffer.write_index;
+        let len = buffer.left.len() as f32;
+        let scrub_delay = |buffer: &mut ElasticBuffer, position: f32| {
+            let mut peak = 0.0_f32;
+            for _ in 0..24_000 {
+                // Fresh input must not reach the frozen buffer.
+                let (left, _) = buffer.process(0.0, 0.0, control(Some(position)), None);
+                peak = peak.max(left.abs());
+            }
+            assert!(peak > 0.1);
+            (frozen_at as f32 - buffer.scrub_position()).rem_euclid(len)
+        };
+
+        let oldest = scrub_delay(&mut buffer, 0.0);
+        let middle = scrub_delay(&mut buffer, 0.5);
+        let newest = scrub_delay(&mut buffer, 1.0);
+        assert_eq!(buffer.write_index, frozen_at);
+        assert!(oldest > middle && middle > newest);
+        assert!(oldest - newest > WINDOW * 0.9);
+        assert!(newest < 48_000.0 * 0.1);
+    }
+
+    #[test]
+    fn frozen_buffer_keeps_looping_captured_audio_through_silence() {
+        let control = |freeze: bool| ElasticControl {
+            delay_samples: 4_000.0,
+            velocity: 0.0,
+            pitch_coupling: 0.0,
+            key_lock: KeyLock::Off,
+            grain_amount: 0.0,
+            grain_bias: 0.0,
+            spring: 0.5,
+            dirty: false,
+            transient_mark: 0.0,
+            scrub: None,
+            window_samples: 48_000.0,
+            freeze,
+            reverse: false,
+        };
+        let mut buffer = ElasticBuffer::new(48_000.0);
+        let input = |n: usize| (n as f32 * 0.031).sin() * 0.5;
+        let captured = buffer.left.len() + 4_800;
+        for n in 0..captured {
+            let _ = buffer.process(input(n), input(n), control(false), None);
+        }
+
+        // Writes fade out, so the seam between live and held audio never steps.
+        let len = buffer.left.len();
+        let mut previous = buffer.left[(buffer.write_index + len - 1) % len];
+        for n in captured..captured + 480 {
+            let slot = buffer.write_index;
+            let _ = buffer.process(input(n), input(n), control(true), None);
+            assert!((buffer.left[slot] - previous).abs() < 0.05);
+            previous = buffer.left[slot];
+        }
+
+        // A full buffer length of silence later the loop still plays.
+        let mut rms = 0.0;
+        for n in 0..buffer.left.len() {
+            let (left, right) = buffer.process(0.0, 0.0, control(true), None);
+            assert_eq!(left, right);
+            if n >= buffer.left.len() - 48_000 {
+                rms += left * left;
+            }
+        }
+        let rms = (rms / 48_000.0).sqrt();
+        assert!(rms > 0.3, "frozen loop rms {rms}");
+
+        // Unfrozen, silence replaces the loop.
+        for _ in 0..buffer.left.len() {
+            let _ = buffer.process(0.0, 0.0, control(false), None);
+        }
+        let (left, _) = buffer.process(0.0, 0.0, control(false), None);
+        assert_eq!(left, 0.0);
+    }
+
+    #[test]
+    fn frozen_loop_stays_inside_the_usable_window() {
+        const WINDOW: f32 = 12_000.0;
+        let control = |freeze: bool| ElasticControl {
+            delay_samples: 4_000.0,
+            velocity: 0.0,
+            pitch_coupling: 0.0,
+            key_lock: KeyLock::Off,
+            grain_amount: 0.0,
+            grain_bias: 0.0,
+            spring: 0.5,
+            dirty: false,
+            transient_mark: 0.0,
+            scrub: None,
+            window_samples: WINDOW,
+            freeze,
+            reverse: false,
+        };
+        let mut buffer = ElasticBuffer::new(48_000.0);
+        // Loud audio fills the allocation, then a window of silence is captured.
+        for n in 0..buffer.left.len() {
+            let loud = (n as f32 * 0.031).sin() * 0.8;
+            let _ = buffer.process(loud, loud, control(false), None);
+        }
+        for _ in 0..WINDOW as usize * 2 {
+            let _ = buffer.process(0.0, 0.0, control(false), None);
+        }
+
+        // The loop replays only the silent window, never the older audio behind it.
+        for _ in 0..buffer.left.len() * 2 {
+            let (left, right) = buffer.process(0.0, 0.0, control(true), None);
+            assert!(left.abs() < 1.0e-6 && right.abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn freeze_keeps_the_engine_awake_through_silence() {
+        let mut settings = TensionFieldParams::new().settings();
+        settings.freeze = true;
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        for _ in 0..200 {
+            let mut left = vec![0.0; 512];
+            let mut right = vec![0.0; 512];
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+        }
+        assert!(!engine.idle_bypass_armed);
+        assert!(!engine.sleeping());
+    }
+
+    #[test]
+    fn reverse_plays_an_impulse_train_back_in_reverse_order() {
+        let control = |reverse: bool| ElasticControl {
+            delay_samples: 500.0,
+            velocity: 0.0,
+            pitch_coupling: 0.0,
+            key_lock: KeyLock::Off,
+            grain_amount: 0.0,
+            grain_bias: 0.0,
+            spring: 1.0,
+            dirty: false,
+            transient_mark: 0.0,
+            scrub: None,
+            window_samples: 48_000.0,
+            freeze: false,
+            reverse,
+        };
+        // Peaks of each impulse the output passes, in the order they are heard.
+        let heard = |output: &[f32]| {
+            let mut peaks: Vec<f32> = Vec::new();
+            let mut in_pulse = false;
+            for sample in output.iter().map(|sample| sample.abs()) {
+                if sample > 0.1 {
+                    if !in_pulse {
+                        peaks.push(0.0);
+                    }
+                    let peak = peaks.last_mut().expect("pulse started");
+                    *peak = peak.max(sample);
+                }
+                in_pulse = sample > 0.1;
+            }
+            peaks
+        };
+
+        let mut buffer = ElasticBuffer::new(48_000.0);
+        // Settle on the delay target, then record four rising impulses.
+        for _ in 0..24_000 {
+            let _ = buffer.process(0.0, 0.0, control(false), None);
+        }
+        let mut forward = Vec::new();
+        for n in 0..4_600 {
+            let input = if n % 1_000 == 0 && n > 0 {
+                n as f32 / 4_000.0
+            } else {
+                0.0
+            };
+            forward.push(buffer.process(input, input, control(false), None).0);
+        }
+        let reverse: Vec<f32> = (0..12_000)
+            .map(|_| buffer.process(0.0, 0.0, control(true), None).0)
+            .collect();
+
+        let forward = heard(&forward);
+        let reverse = heard(&reverse);
+        assert_eq!(forward.len(), 4, "{forward:?}");
+        assert!(
+            forward.windows(2).all(|pair| pair[0] < pair[1]),
+            "{forward:?}"
+        );
+        assert_eq!(reverse.len(), 4, "{reverse:?}");
+        assert!(
+            reverse.windows(2).all(|pair| pair[0] > pair[1]),
+            "{reverse:?}"
+        );
+        assert!(reverse.iter().all(|peak| peak.is_finite()));
+    }
+
+    #[test]
+    fn stiffer_spring_tracks_delay_target_faster() {
+        let settle_error = |spring: f32| {
+            let mut buffer = ElasticBuffer::new(48_000.0);
+            for _ in 0..400 {
+                let _ = buffer.process(
+                    0.0,
+                    0.0,
+                    ElasticControl {
+                        delay_samples: 2_000.0,
+                        velocity: 0.0,
+                        pitch_coupling: 0.0,
+                        key_lock: KeyLock::Off,
+                        grain_amount: 0.0,
+                        grain_bias: 0.0,
+                        spring,
+                        dirty: false,
+                        transient_mark: 0.0,
+                        scrub: None,
+                        window_samples: 48_000.0,
+                        freeze: false,
+                        reverse: false,
+                    },
+                    None,
+                );
+            }
+            (buffer.left_head.smooth_delay - 2_000.0).abs()
+        };
+
+        assert!(settle_error(1.0) < settle_error(0.0));
+    }
+
+    #[test]
+    fn character_switch_crossfades_instead_of_stepping() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_CHAR_MAKEUP_ID, 1.0);
+        let clean = params.settings();
+        let mut crush = clean;
+        crush.character = CharacterMode::Crush;
+        let input = |n: usize| (n as f32 * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.5;
+        let render = |engine: &mut TensionFieldEngine, settings, start: usize, frames: usize| {
+            let mut left: Vec<f32> = (start..start + frames).map(input).collect();
+            let mut right = left.clone();
+            let _ = engine.render(settings, &mut left, &mut right, TransportState::default());
+            left
+        };
+
+        let mut reference = TensionFieldEngine::new(48_000.0);
+        let mut switched = TensionFieldEngine::new(48_000.0);
+        let _ = render(&mut reference, &clean, 0, 48_000);
+        let _ = render(&mut switched, &clean, 0, 48_000);
+        let stay = render(&mut reference, &clean, 48_000, 4_800);
+        let change = render(&mut switched, &crush, 48_000, 4_800);
+        let difference: Vec<f32> = stay.iter().zip(&change).map(|(a, b)| b - a).collect();
+
+        // A hard switch moves the very first samples by the crush step and the
+        // makeup change (a few thousandths); the crossfade starts from nothing.
+        let onset = difference[..8].iter().fold(0.0_f32, |m, d| m.max(d.abs()));
+        let settled = difference[1_200..]
+            .iter()
+            .fold(0.0_f32, |m, d| m.max(d.abs()));
+        assert!(onset < 2.0e-4);
+        assert!(settled > 1.0e-2);
+    }
+
+    #[test]
+    fn every_clip_mode_stays_finite_and_monotonic() {
+        for mode in [
+            ClipMode::Soft,
+            ClipMode::Tanh,
+            ClipMode::Hard,
+            ClipMode::Off,
+        ] {
+            let mut previous = output_clip(-4.0, mode);
+            for step in 1..=800 {
+                let input = -4.0 + step as f32 * 0.01;
+                let output = output_clip(input, mode);
+                assert!(output.is_finite(), "{mode:?} at {input}");
+                assert!(output >= previous, "{mode:?} falls at {input}");
+                previous = output;
+            }
+        }
+        // Soft stays the original curve so existing projects sound the same.
+        assert_eq!(output_clip(0.9, ClipMode::Soft), 0.9 / (1.0 + 0.9 * 0.6));
+        assert_eq!(output_clip(3.0, ClipMode::Hard), 1.0);
+        assert_eq!(output_clip(3.0, ClipMode::Off), 3.0);
+    }
+
+    #[test]
+    fn output_chain_applies_stages_in_fixed_order() {
+        let mut chain = OutputChain::new(48_000.0);
+        let control = OutputChainControl {
+            drive: 1.0,
+            crush: 1.0,
+            crush_steps: 128.0,
+            downsample: 1,
+            dc_block: 1.0,
+            limit: false,
+            clip: ClipMode::Soft,
+        };
+
+        // First sample: crush 0.9 -> 115/128, DC blocker passes it, then soft clip.
+        let (left, right) = chain.process(0.9, -0.9, control);
+        let crushed = 115.0 / 128.0;
+        let expected = crushed / (1.0 + crushed * 0.6);
+        assert!((left - expected).abs() < 1.0e-6);
+        assert!((right + expected).abs() < 1.0e-6);
+
+        let mut settled = (0.0, 0.0);
+        for _ in 0..48_000 {
+            settled = chain.process(0.9, -0.9, control);
+        }
+        assert!(settled.0.abs() < 1.0e-3);
+        assert!(settled.1.abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn clean_output_chain_leaves_low_frequencies_untouched() {
+        let mut chain = OutputChain::new(48_000.0);
+        let control = OutputChainControl {
+            drive: 1.0,
+            crush: 0.0,
+            crush_steps: 128.0,
+            downsample: 1,
+            dc_block: 0.0,
+            limit: false,
+            clip: ClipMode::Off,
+        };
+        for n in 0..48_000 {
+            // An offset 5 Hz sine that the DC blocker would visibly reshape.
+            let input = 0.3 + (n as f32 * 5.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.2;
+            assert_eq!(chain.process(input, -input, control), (input, -input));
+        }
+    }
+
+    #[test]
+    fn lower_crush_depth_increases_quantization_error() {
+        let mean_error = |bits: f32| {
+            let steps = bits.exp2();
+            let frames = 1_000;
+            (0..frames)
+                .map(|index| {
+                    let sample = (index as f32 * 0.0137).sin() * 0.8;
+                    (super::crush(sample, steps) - sample).abs()
+                })
+                .sum::<f32>()
+                / frames as f32
+        };
+
+        let errors = [2.0, 4.0, 7.0, 12.0, 16.0].map(mean_error);
+        assert!(
+            errors.windows(2).all(|pair| pair[0] > pair[1]),
+            "{errors:?}"
+        );
+        assert!(errors[4] < 1.0e-4);
+    }
+
+    #[test]
+    fn decimator_holds_each_captured_frame_for_the_factor() {
+        let input = |index: usize| (index as f32 * 0.1, -(index as f32) * 0.1);
+
+        let mut transparent = super::Decimator::default();
+        for index in 0..16 {
+            let (left, right) = input(index);
+            assert_eq!(transparent.process(left, right, 1), (left, right));
+        }
+
+        let factor = 4;
+        let mut decimator = super::Decimator::default();
+        let held: Vec<(f32, f32)> = (0..16)
+            .map(|index| {
+                let (left, right) = input(index);
+                decimator.process(left, right, factor)
+            })
+            .collect();
+        for (block, frames) in held.chunks(factor as usize).enumerate() {
+            let expected = input(block * factor as usize);
+            assert!(frames.iter().all(|frame| *frame == expected), "{frames:?}");
+        }
+    }
+
+    #[test]
+    fn feedback_hpf_removes_dc_bias_from_the_feedback_signal() {
+        // Mean of the filtered feedback over 10 ms, a given time after a DC step.
+        let mean_after = |cutoff_hz: f32, settle_samples: usize| {
+            let mut filter = super::FeedbackHighPass::new(48_000.0);
+            let signal = |n: usize| 0.5 + (n as f32 * 0.05).sin() * 0.2;
+            for n in 0..settle_samples {
+                let _ = filter.process(signal(n), -signal(n), cutoff_hz);
+            }
+            let window = 480;
+            let sum = (settle_samples..settle_samples + window)
+                .map(|n| filter.process(signal(n), -signal(n), cutoff_hz))
+                .fold((0.0, 0.0), |sum, (left, right)| {
+                    (sum.0 + left, sum.1 + right)
+                });
+            (sum.0 / window as f32, sum.1 / window as f32)
+        };
+
+        let early = mean_after(200.0, 0);
+        let settled = mean_after(200.0, 4_800);
+        assert!(
+            settled.0.abs() < 0.01 && settled.1.abs() < 0.01,
+            "{settled:?}"
+        );
+        assert!(settled.0.abs() < early.0.abs());
+        // A higher cutoff sheds the bias sooner.
+        assert!(mean_after(800.0, 480).0.abs() < mean_after(20.0, 480).0.abs());
+    }
+
+    #[test]
+    fn lower_feedback_tone_darkens_each_repeat() {
+        // Share of energy in the sample-to-sample difference: a brightness proxy.
+        let brightness = |signal: &[f32]| {
+            let energy: f32 = signal.iter().map(|sample| sample * sample).sum();
+            let high: f32 = signal
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).powi(2))
+                .sum();
+            high / energy.max(1.0e-12)
+        };
+        // Brightness after each of four passes round the feedback filter.
+        let repeats = |tone: f32| {
+            let mut filter = super::FeedbackTone::new(48_000.0);
+            let mut seed = 0x1234_5678_u32;
+            let mut signal: Vec<f32> = (0..4_800)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 17;
+                    seed ^= seed << 5;
+                    seed as f32 / u32::MAX as f32 - 0.5
+                })
+                .collect();
+            (0..4)
+                .map(|_| {
+                    for sample in &mut signal {
+                        *sample = filter.process(*sample, *sample, tone).0;
+                    }
+                    brightness(&signal)
+                })
+                .collect::<Vec<f32>>()
+        };
+
+        let bright = repeats(1.0);
+        assert!(
+            bright
+                .windows(2)
+                .all(|pair| (pair[0] - pair[1]).abs() < 1.0e-6)
+        );
+        let mut previous = bright;
+        for tone in [0.75, 0.5, 0.0] {
+            let darker = repeats(tone);
+            assert!(
+                darker.windows(2).all(|pair| pair[1] < pair[0]),
+                "{darker:?}"
+            );
+            assert!(
+                darker
+                    .iter()
+                    .zip(&previous)
+                    .all(|(dark, light)| dark < light),
+                "{darker:?} vs {previous:?}"
+            );
+            previous = darker;
+        }
+    }
+
+    #[test]
+    fn higher_tension_reports_longer_elastic_delay() {
+        let delay_ms = |tension: f32| {
+            let mut settings = TensionFieldParams::new().settings();
+            settings.tension = tension;
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let mut report = RenderReport::default();
+            for _ in 0..48 {
+                let mut left = vec![0.0_f32; 1024];
+                let mut right = vec![0.0_f32; 1024];
+                report = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            }
+            report.elastic_delay_ms
+        };
+
+        let relaxed = delay_ms(0.1);
+        let taut = delay_ms(0.9);
+        assert!(relaxed > 0.0);
+        assert!(taut > relaxed + 50.0);
+    }
+
+    #[test]
+    fn tension_floor_keeps_drive_above_its_minimum() {
+        let mut settings = TensionFieldParams::new().settings();
+        settings.tension = 0.0;
+        settings.tension_floor = 0.4;
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        // The gesture's weakest drive is a fifth of the effective tension.
+        let minimum = settings.tension_floor * 0.2;
+        for n in 0..4_800 {
+            let mut left = [(n as f32 * 0.05).sin() * 0.3];
+            let mut right = left;
+            let report = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            assert!(report.tension_activity >= minimum - 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn positive_grain_bias_raises_mean_jitter_offset() {
+        let mean_offset = |grain_bias: f32| {
+            let mut buffer = ElasticBuffer::new(48_000.0);
+            buffer.left_head.smooth_delay = 4_800.0;
+            let mut sum = 0.0_f32;
+            let samples = 48_000;
+            for _ in 0..samples {
+                let _ = buffer.process(
+                    0.0,
+                    0.0,
+                    ElasticControl {
+                        delay_samples: 4_800.0,
+                        velocity: 0.0,
+                        pitch_coupling: 0.0,
+                        key_lock: KeyLock::Off,
+                        grain_amount: 1.0,
+                        grain_bias,
+                        spring: 1.0,
+                        dirty: false,
+                        transient_mark: 0.0,
+                        scrub: None,
+                        window_samples: 48_000.0,
+                        freeze: false,
+                        reverse: false,
+                    },
+                    None,
+                );
+                sum += buffer.left_head.smooth_delay - 4_800.0;
+            }
+            sum / samples as f32
+        };
+
+        assert!(mean_offset(0.8) > mean_offset(-0.8));
+    }
+
+    #[test]
+    fn transient_preserve_reduces_attack_smearing() {
+        let attack_correlation = |preserve: f32| {
+            let onset = 24_000;
+            let input: Vec<f32> = (0..onset + 8_000)
+                .map(|n| {
+                    if n < onset {
+                        return 0.0;
+                    }
+                    let t = (n - onset) as f32;
+                    (t * std::f32::consts::TAU * 440.0 / 48_000.0).sin() * (-t / 4_000.0).exp()
+                })
+                .collect();
+
+            let mut detector = TransientDetector::default();
+            let mut buffer = ElasticBuffer::new(48_000.0);
+            let mut output = Vec::with_capacity(input.len());
+            for (n, &sample) in input.iter().enumerate() {
+                let (left, _) = buffer.process(
+                    sample,
+                    sample,
+                    ElasticControl {
+                        delay_samples: 2_000.0,
+                        velocity: (n as f32 * 0.004).sin() * 0.6,
+                        pitch_coupling: 1.0,
+                        key_lock: KeyLock::Off,
+                        grain_amount: 0.0,
+                        grain_bias: 0.0,
+                        spring: 1.0,
+                        dirty: false,
+                        transient_mark: detector.process(sample.abs()) * preserve,
+                        scrub: None,
+                        window_samples: 48_000.0,
+                        freeze: false,
+                        reverse: false,
+                    },
+                    None,
+                );
+                output.push(left);
+            }
+
+            let window = 1_024;
+            let reference = &input[onset..onset + window];
+            let input_lead = reference
+                .iter()
+                .position(|sample| sample.abs() > 0.05)
+                .unwrap_or(0);
+            let arrival = output
+                .iter()
+                .position(|sample| sample.abs() > 0.05)
+                .expect("attack should reach the output");
+            let aligned = arrival.saturating_sub(input_lead) as isize;
+
+            (-16..=16)
+                .map(|shift| {
+                    let start = (aligned + shift).max(0) as usize;
+                    let candidate = &output[start..start + window];
+                    let dot: f32 = reference.iter().zip(candidate).map(|(a, b)| a * b).sum();
+                    let energy_a: f32 = reference.iter().map(|a| a * a).sum();
+                    let energy_b: f32 = candidate.iter().map(|b| b * b).sum();
+                    dot / (energy_a * energy_b).sqrt().max(1.0e-9)
+                })
+                .fold(f32::MIN, f32::max)
+        };
+
+        let smeared = attack_correlation(0.0);
+        let preserved = attack_correlation(1.0);
+        assert!(preserved > smeared + 0.1, "{preserved} vs {smeared}");
+        assert!(preserved > 0.8);
+    }
+
+    #[test]
+    fn note_on_offset_starts_gesture_rise_at_that_frame() {
+        let mut settings = TensionFieldParams::new().settings();
+        // Offset the stopped-transport cycle so the pull shape is non-zero.
+        settings.pull_phase = 0.25;
+        let render = |triggers: &[NoteTrigger]| {
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let mut left: Vec<f32> = (0..16_384).map(|n| (n as f32 * 0.05).sin() * 0.5).collect();
+            let mut right = left.clone();
+            let _ = engine.render_with_triggers(
+                &settings,
+                &mut left,
+                &mut right,
+                TransportState::default(),
+                triggers,
+            );
+            left
+        };
+
+        let offset = 12_000;
+        let untriggered = render(&[]);
+        let triggered = render(&[NoteTrigger {
+            offset,
+            source: TriggerSource::Notes,
+            pressed: true,
+        }]);
+
+        assert_eq!(&triggered[..offset], &untriggered[..offset]);
+        assert!(
+            triggered[offset..]
+                .iter()
+                .zip(&untriggered[offset..])
+                .any(|(a, b)| (a - b).abs() > 1.0e-6)
+        );
+    }
+
+    #[test]
+    fn lookahead_limiter_catches_sharp_transient_without_overshoot() {
+        let transient_peak = |lookahead_ms: f32| {
+            let mut limiter = PeakLimiter::new(48_000.0, lookahead_samples(48_000.0, lookahead_ms));
+            let mut peak = 0.0_f32;
+            for index in 0..4_800 {
+                let input = if index < 1_000 { 0.1 } else { 2.0 };
+                let (left, right) = limiter.process(input, -input);
+                peak = peak.max(left.abs().max(right.abs()));
+            }
+            peak
+        };
+
+        assert!(transient_peak(0.0) > LIMITER_CEILING + 0.1);
+        assert!(transient_peak(2.0) <= LIMITER_CEILING + 1.0e-5);
+    }
+
+    #[test]
+    fn lookahead_limiter_holds_ceiling_through_uneven_overlapping_peaks() {
+        let lookahead = lookahead_samples(48_000.0, 1.0);
+        let mut limiter = PeakLimiter::new(48_000.0, lookahead);
+        let mut seed = 0x2545_f491_u32;
+        let input: Vec<f32> = (0..24_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let level = (seed >> 8) as f32 / (1u32 << 24) as f32;
+                // Mostly quiet material with sparse spikes of random height.
+                if level > 0.97 {
+                    level * 40.0 - 36.0
+                } else {
+                    level * 0.5
+                }
+            })
+            .collect();
+        for (index, &sample) in input.iter().enumerate() {
+            let (left, right) = limiter.process(sample, -sample);
+            assert!(
+                left.abs() <= LIMITER_CEILING + 1.0e-5,
+                "overshoot {left} at {index}"
+            );
+            assert_eq!(left, -right);
+            if index >= lookahead {
+                // The output is the delayed input scaled by a gain no higher than unity.
+                let delayed = input[index - lookahead];
+                assert!(left.abs() <= delayed.abs() + 1.0e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn safety_limit_is_off_by_default_and_only_holds_peaks_when_enabled() {
+        assert!(!TensionFieldParams::new().settings().safety_limit);
+        let output_peak = |limit: bool| {
+            let mut chain = OutputChain::new(48_000.0);
+            chain.limiter = PeakLimiter::new(48_000.0, lookahead_samples(48_000.0, 1.0));
+            let control = OutputChainControl {
+                drive: 1.0,
+                crush: 0.0,
+                crush_steps: 128.0,
+                downsample: 1,
+                dc_block: 0.0,
+                limit,
+                clip: ClipMode::Off,
+            };
+            (0..4_800)
+                .map(|index| {
+                    let input = (index as f32 * 0.05).sin() * 1.5;
+                    chain.process(input, input, control).0.abs()
+                })
+                .fold(0.0_f32, f32::max)
+        };
+
+        assert!(output_peak(false) > 1.4);
+        assert!(output_peak(true) <= LIMITER_CEILING + 1.0e-3);
+    }
+
+    /// Peak of `samples` reconstructed at 8x with a Hann-windowed sinc, as a reference meter.
+    fn reconstructed_peak(samples: &[f32]) -> f32 {
+        use std::f32::consts::PI;
+        const HALF_TAPS: isize = 32;
+        let mut peak = 0.0_f32;
+        for index in 0..samples.len() as isize {
+            for phase in 0..8 {
+                let time = index as f32 + phase as f32 / 8.0;
+                let mut value = 0.0;
+                for tap in (index - HALF_TAPS + 1)..=(index + HALF_TAPS) {
+                    let Some(sample) = usize::try_from(tap).ok().and_then(|tap| samples.get(tap))
+                    else {
+                        continue;
+                    };
+                    let x = time - tap as f32;
+                    let sinc = if x == 0.0 {
+                        1.0
+                    } else {
+                        (PI * x).sin() / (PI * x)
+                    };
+                    let window = 0.5 + 0.5 * (PI * x / HALF_TAPS as f32).cos();
+                    value += sample * sinc * window;
+                }
+                peak = peak.max(value.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn true_peak_limiter_keeps_a_hot_burst_under_full_scale_between_samples() {
+        use std::f32::consts::{FRAC_PI_4, FRAC_PI_8};
+        let burst_true_peak = |enabled: bool| {
+            let mut chain = OutputChain::new(48_000.0);
+            chain.true_peak = enabled.then(|| TruePeakLimiter::new(48_000.0));
+            let control = OutputChainControl {
+                drive: 1.0,
+                crush: 0.0,
+                crush_steps: 128.0,
+                downsample: 1,
+                dc_block: 0.0,
+                limit: true,
+                clip: ClipMode::Off,
+            };
+            // A +6 dB sine at an eighth of the rate, offset so every crest falls
+            // between two samples and the sample peaks read low.
+            let output: Vec<f32> = (0..4_800)
+                .map(|index| {
+                    let input = if (1_000..3_000).contains(&index) {
+                        2.0 * (FRAC_PI_4 * index as f32 + FRAC_PI_8).sin()
+                    } else {
+                        0.0
+                    };
+                    chain.process(input, -input, control).0
+                })
+                .collect();
+            reconstructed_peak(&output)
+        };
+
+        let unlimited = burst_true_peak(false);
+        assert!(unlimited > 1.0, "sample-peak limiter alone {unlimited}");
+        let limited = burst_true_peak(true);
+        assert!(limited < 1.0, "true-peak limited {limited}");
+    }
+
+    #[test]
+    fn half_air_compensation_sits_between_off_and_full() {
+        let high_energy = |air_compensation: f32| {
+            let mut warp = SpectralWarp::new(37, 73, 48_000.0);
+            let control = WarpControl {
+                tension: 0.8,
+                diffusion: 0.0,
+                elasticity: 0.5,
+                air_damping: 0.9,
+                air_compensation,
+                drift_phase_inc: 0.0,
+                warp_motion: 0.0,
+                color: WarpColor::Neutral,
+                high_color: None,
+                character: CharacterMode::Clean,
+                low_keep_hz: 0.0,
+                air_freq_hz: 1_000.0,
+            };
+            let mut previous = 0.0_f32;
+            let mut energy = 0.0_f32;
+            for n in 0..4_096 {
+                let input = if n % 2 == 0 { 0.5 } else { -0.5 };
+                let output = warp.process(input, control);
+                if n >= 1_024 {
+                    let diff = output - previous;
+                    energy += diff * diff;
+                }
+                previous = output;
+            }
+            energy
+        };
+
+        let off = high_energy(0.0);
+        let half = high_energy(0.5);
+        let full = high_energy(1.0);
+        assert!(off < half);
+        assert!(half < full);
+    }
+
+    #[test]
+    fn raising_air_frequency_moves_damping_to_higher_frequencies() {
+        let tone_gain = |frequency: f32, air_freq_hz: f32| {
+            let mut warp = SpectralWarp::new(37, 73, 48_000.0);
+            let control = WarpControl {
+                tension: 0.9,
+                diffusion: 0.0,
+                elasticity: 0.5,
+                air_damping: 0.95,
+                air_compensation: 0.0,
+                drift_phase_inc: 0.0,
+                warp_motion: 0.0,
+                color: WarpColor::Neutral,
+                high_color: None,
+                character: CharacterMode::Clean,
+                low_keep_hz: 0.0,
+                air_freq_hz,
+            };
+            let mut input_energy = 0.0_f32;
+            let mut output_energy = 0.0_f32;
+            for n in 0..48_000 {
+                let input = (n as f32 * frequency / 48_000.0 * std::f32::consts::TAU).sin() * 0.5;
+                let output = warp.process(input, control);
+                if n >= 24_000 {
+                    input_energy += input * input;
+                    output_energy += output * output;
+                }
+            }
+            (output_energy / input_energy).sqrt()
+        };
+
+        // A 1 kHz tone is damped under a low reference but passes under a high one.
+        let low_reference = tone_gain(1_000.0, 500.0);
+        let high_reference = tone_gain(1_000.0, 8_000.0);
+        assert!(high_reference > low_reference + 0.2);
+        // Far above both corners the damping still applies.
+        assert!(tone_gain(16_000.0, 8_000.0) < high_reference);
+    }
+
+    #[test]
+    fn warp_low_keep_leaves_bass_clean_and_warps_highs() {
+        let tone_gain = |frequency: f32, low_keep_hz: f32| {
+            let mut warp = SpectralWarp::new(37, 73, 48_000.0);
+            let control = WarpControl {
+                tension: 0.9,
+                diffusion: 0.0,
+                elasticity: 0.5,
+                air_damping: 0.95,
+                air_compensation: 0.0,
+                drift_phase_inc: 0.0,
+                warp_motion: 0.0,
+                color: WarpColor::Neutral,
+                high_color: None,
+                character: CharacterMode::Clean,
+                low_keep_hz,
+                air_freq_hz: 1_000.0,
+            };
+            let mut input_energy = 0.0_f32;
+            let mut output_energy = 0.0_f32;
+            for n in 0..48_000 {
+                let input = (n as f32 * frequency / 48_000.0 * std::f32::consts::TAU).sin() * 0.5;
+                let output = warp.process(input, control);
+                if n >= 24_000 {
+                    input_energy += input * input;
+                    output_energy += output * output;
+                }
+            }
+            (output_energy / input_energy).sqrt()
+        };
+
+        assert!((tone_gain(100.0, 300.0) - 1.0).abs() < 0.02);
+        assert!((tone_gain(3_000.0, 300.0) - 1.0).abs() > 0.2);
+        assert!((tone_gain(100.0, 0.0) - 1.0).abs() > 0.05);
+    }
+
+    #[test]
+    fn per_band_warp_colors_damp_each_band_independently() {
+        let tone_gain = |frequency: f32, high_color: Option<WarpColor>| {
+            let mut warp = SpectralWarp::new(37, 73, 48_000.0);
+            let control = WarpControl {
+                tension: 0.9,
+                diffusion: 0.0,
+                elasticity: 0.5,
+                air_damping: 0.6,
+                air_compensation: 0.0,
+                drift_phase_inc: 0.0,
+                warp_motion: 0.0,
+                color: WarpColor::Neutral,
+                high_color,
+                character: CharacterMode::Clean,
+                low_keep_hz: 0.0,
+                air_freq_hz: 1_000.0,
+            };
+            let mut input_energy = 0.0_f32;
+            let mut output_energy = 0.0_f32;
+            for n in 0..48_000 {
+                let input = (n as f32 * frequency / 48_000.0 * std::f32::consts::TAU).sin() * 0.5;
+                let output = warp.process(input, control);
+                if n >= 24_000 {
+                    input_energy += input * input;
+                    output_energy += output * output;
+                }
+            }
+            (output_energy / input_energy).sqrt()
+        };
+
+        // Highs follow the high-band color: Dark Drag damps more, Bright Shear less.
+        let neutral_high = tone_gain(8_000.0, Some(WarpColor::Neutral));
+        let dark_high = tone_gain(8_000.0, Some(WarpColor::DarkDrag));
+        let bright_high = tone_gain(8_000.0, Some(WarpColor::BrightShear));
+        assert!(dark_high < neutral_high * 0.9);
+        assert!(bright_high > neutral_high * 1.1);
+
+        // Lows keep the Neutral low-band color whatever the high band uses.
+        let neutral_low = tone_gain(80.0, Some(WarpColor::Neutral));
+        for color in [WarpColor::DarkDrag, WarpColor::BrightShear] {
+            assert!((tone_gain(80.0, Some(color)) - neutral_low).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn rms_activity_favors_sustained_diffusion_over_transients() {
+        let mut sustained = ActivityMeter::default();
+        let mut transient = ActivityMeter::default();
+        for n in 0..512 {
+            let tail = (n as f32 * 0.07).sin() * 0.5;
+            sustained.push(tail, -tail);
+            let click = if n == 0 { 0.5 } else { 0.0 };
+            transient.push(click, click);
+        }
+
+        assert!((sustained.peak - transient.peak).abs() < 1.0e-3);
+        assert!(sustained.rms() > transient.rms() * 4.0);
+    }
+
+    #[test]
+    fn tempo_follow_scales_free_rates_by_host_tempo() {
+        let mut settings = TensionFieldParams::new().settings();
+        settings.pull_rate_hz = 1.5;
+        settings.warp_rate_hz = 0.8;
+        settings.warp_rate_mode = ModRateMode::FreeHz;
+        settings.modulation.source_a.rate_hz = 0.6;
+
+        let followed = tempo_followed(&settings, 140.0);
+        let scale = 140.0 / 120.0;
+        assert!((followed.pull_rate_hz - 1.5 * scale).abs() < 1.0e-6);
+        assert!((followed.warp_rate_hz - 0.8 * scale).abs() < 1.0e-6);
+        assert!((followed.modulation.source_a.rate_hz - 0.6 * scale).abs() < 1.0e-6);
+        assert_eq!(tempo_followed(&settings, 120.0).pull_rate_hz, 1.5);
+
+        // Synced warp rates are beat multipliers and already follow the host.
+        settings.warp_rate_mode = ModRateMode::SyncDivision;
+        assert_eq!(tempo_followed(&settings, 140.0).warp_rate_hz, 0.8);
+    }
+
+    #[test]
+    fn tension_meter_reports_selected_source() {
+        let frame = GestureFrame {
+            delay_samples: 2_400.0,
+            velocity: 0.01,
+            tension_drive: 0.6,
+            envelope: 0.8,
+            drift_phase_inc: 0.001,
+            phase: 0.25,
+        };
+        let effective = 0.45;
+        assert_eq!(
+            tension_meter_value(TensionMeterSource::Drive, &frame, effective),
+            0.6
+        );
+        assert_eq!(
+            tension_meter_value(TensionMeterSource::Envelope, &frame, effective),
+            0.8
+        );
+        assert_eq!(
+            tension_meter_value(TensionMeterSource::Effective, &frame, effective),
+            0.45
+        );
+    }
+
+    #[test]
+    fn peak_to_dbfs_matches_known_levels() {
+        assert_eq!(peak_to_dbfs(1.0), 0.0);
+        assert!((peak_to_dbfs(0.5) + 6.0206).abs() < 1.0e-3);
+        assert!((peak_to_dbfs(0.1) + 20.0).abs() < 1.0e-4);
+        assert!((peak_to_dbfs(2.0) - 6.0206).abs() < 1.0e-3);
+        assert_eq!(peak_to_dbfs(0.0), METER_FLOOR_DBFS);
+        assert_eq!(peak_to_dbfs(1.0e-9), METER_FLOOR_DBFS);
+        assert_eq!(peak_to_dbfs(f32::NAN), METER_FLOOR_DBFS);
+    }
+
+    #[test]
+    fn limiter_threshold_maps_to_output_meter_bar_position() {
+        let fill = level_meter_fill(LIMITER_CEILING);
+        assert!((fill - 0.95 / 1.95).abs() < 1.0e-6);
+        // Matches the fill the output meters report for a peak at the ceiling.
+        assert_eq!(fill, meter_norm(LIMITER_CEILING));
+        // On a 78 px bar the line sits 38 px above the bottom.
+        assert_eq!((78.0 * fill).round() as i32, 38);
+        assert_eq!(level_meter_fill(1.0), 0.5);
+        assert_eq!(level_meter_fill(0.0), 0.0);
+    }
+
+    #[test]
+    fn meter_zone_warns_above_configured_reference_level() {
+        // Level-scale meters: -6 dBFS sits at about a third of the bar.
+        let warn_fill = dbfs_meter_fill(-6.0, false);
+        assert!((warn_fill - 0.3338).abs() < 1.0e-3);
+        assert_eq!(
+            meter_zone(level_meter_fill(0.6), warn_fill),
+            MeterZone::Warn
+        );
+        assert_eq!(
+            meter_zone(level_meter_fill(0.4), warn_fill),
+            MeterZone::Normal
+        );
+
+        // dBFS-scale meters: -6 dBFS sits at 90% of the -60..0 dBFS bar.
+        let warn_fill = dbfs_meter_fill(-6.0, true);
+        assert!((warn_fill - 0.9).abs() < 1.0e-6);
+        assert_eq!(
+            meter_zone(dbfs_meter_fill(-3.0, true), warn_fill),
+            MeterZone::Warn
+        );
+        assert_eq!(
+            meter_zone(dbfs_meter_fill(-12.0, true), warn_fill),
+            MeterZone::Normal
+        );
+    }
+
+    #[test]
+    fn key_lock_snaps_sustained_pitch_offsets_to_scale_degrees() {
+        const C_MAJOR: [f32; 7] = [0.0, 2.0, 4.0, 5.0, 7.0, 9.0, 11.0];
+        for step in -40..=40 {
+            // Sustained gesture velocity spanning roughly -10..+7 semitones.
+            let velocity = step as f32 * 0.025;
+            let free = coupled_pitch_offset(velocity, 1.0, KeyLock::Off);
+            assert!((free - velocity * 0.48).abs() < 1.0e-6);
+
+            let locked = coupled_pitch_offset(velocity, 1.0, KeyLock::Major);
+            let semitones = 12.0 * (1.0 + locked).log2();
+            let nearest = semitones.round();
+            assert!((semitones - nearest).abs() < 1.0e-3);
+            assert!(C_MAJOR.contains(&nearest.rem_euclid(12.0)));
+
+            let free_semitones = 12.0 * (1.0 + free).log2();
+            assert!((semitones - free_semitones).abs() <= 1.0 + 1.0e-3);
+        }
+        assert_eq!(KeyLock::Major.snap_semitones(3.3), 4.0);
+        assert_eq!(KeyLock::Major.snap_semitones(-1.4), -1.0);
+        assert_eq!(KeyLock::Minor.snap_semitones(3.3), 3.0);
+    }
+
+    #[test]
+    fn zero_diffusion_bypasses_diffusers() {
+        let mut clean = SpaceStage::new(48_000.0);
+        let mut primed = SpaceStage::new(48_000.0);
+        for n in 0..97 {
+            let noise = ((n * 7_919) % 101) as f32 / 50.0 - 1.0;
+            let _ = primed.diff_left.process(noise, 0.7);
+            let _ = primed.diff_right.process(-noise, 0.7);
+        }
+
+        for n in 0..512 {
+            let left = (n as f32 * 0.05).sin() * 0.6;
+            let right = (n as f32 * 0.031).cos() * 0.4;
+            let control = space_control(0.8, 0.0, 1.0, 0.0);
+            let a = clean.process(left, right, control);
+            let b = primed.process(left, right, control);
+            assert_eq!(a, b);
+        }
+    }
+
+    fn space_control(width: f32, diffusion: f32, scale: f32, tone: f32) -> SpaceControl {
+        SpaceControl {
+            width,
+            diffusion,
+            scale,
+            tone,
+            haas_samples: 0.0,
+            rotation: 0.0,
+            dirty: 0.0,
+        }
+    }
+
+    #[test]
+    fn image_rotation_turns_the_stereo_field() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, SQRT_2};
+
+        let render = |rotation: f32| {
+            let mut space = SpaceStage::new(48_000.0);
+            let control = SpaceControl {
+                rotation,
+                ..space_control(0.0, 0.0, 1.0, 0.0)
+            };
+            (0..256)
+                .map(|n| {
+                    let left = (n as f32 * 0.05).sin() * 0.8;
+                    let right = (n as f32 * 0.031).cos() * 0.2;
+                    ((left, right), space.process(left, right, control))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for ((left, right), (out_l, out_r)) in render(0.0) {
+            assert!((out_l - left).abs() < 1.0e-6);
+            assert!((out_r - right).abs() < 1.0e-6);
+        }
+        for ((left, right), (out_l, out_r)) in render(FRAC_PI_2) {
+            assert!((out_l + right).abs() < 1.0e-6);
+            assert!((out_r - left).abs() < 1.0e-6);
+        }
+        for ((left, right), (out_l, out_r)) in render(-FRAC_PI_2) {
+            assert!((out_l - right).abs() < 1.0e-6);
+            assert!((out_r + left).abs() < 1.0e-6);
+        }
+
+        // A mono source at +45 degrees lands entirely in the right channel.
+        let mut space = SpaceStage::new(48_000.0);
+        let control = SpaceControl {
+            rotation: FRAC_PI_4,
+            ..space_control(0.0, 0.0, 1.0, 0.0)
+        };
+        let (out_l, out_r) = space.process(0.5, 0.5, control);
+        assert!(out_l.abs() < 1.0e-6);
+        assert!((out_r - 0.5 * SQRT_2).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn haas_delay_offsets_right_channel_by_expected_samples() {
+        let mut space = SpaceStage::new(48_000.0);
+        let control = SpaceControl {
+            haas_samples: 10.0 * 0.001 * 48_000.0,
+            ..space_control(0.0, 0.0, 1.0, 0.0)
+        };
+        let mut left_peak = (0, 0.0_f32);
+        let mut right_peak = (0, 0.0_f32);
+        for n in 0..2_048 {
+            let impulse = if n == 0 { 1.0 } else { 0.0 };
+            let (left, right) = space.process(impulse, impulse, control);
+            if left.abs() > left_peak.1 {
+                left_peak = (n, left.abs());
+            }
+            if right.abs() > right_peak.1 {
+                right_peak = (n, right.abs());
+            }
+        }
+        assert_eq!(left_peak.0, 0);
+        assert_eq!(right_peak.0, 480);
+        assert!((right_peak.1 - left_peak.1).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn negative_space_tone_darkens_diffused_output() {
+        // First-difference energy stands in for the output's high-band energy.
+        let energies = |tone: f32| {
+            let mut space = SpaceStage::new(48_000.0);
+            let control = space_control(0.5, 1.0, 1.0, tone);
+            let mut seed = 0x1234_5678_u32;
+            let mut previous = 0.0_f32;
+            let mut energy = 0.0_f32;
+            let mut high_energy = 0.0_f32;
+            for _ in 0..16_384 {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let noise = (seed >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0;
+                let (left, _) = space.process(noise * 0.5, noise * 0.5, control);
+                energy += left * left;
+                high_energy += (left - previous) * (left - previous);
+                previous = left;
+            }
+            (energy, high_energy)
+        };
+
+        let (neutral, neutral_high) = energies(0.0);
+        let (dark, dark_high) = energies(-1.0);
+        assert!(dark_high < neutral_high * 0.7);
+        assert!(dark_high / dark < neutral_high / neutral);
+    }
+
+    #[test]
+    fn larger_space_size_lengthens_impulse_decay() {
+        let decay_samples = |size: f32| {
+            let mut space = SpaceStage::new(48_000.0);
+            let control = space_control(0.6, 1.0, space_size_scale(size), 0.0);
+            let mut last_audible = 0;
+            for n in 0..4_096 {
+                let impulse = if n == 0 { 1.0 } else { 0.0 };
+                let (left, right) = space.process(impulse, impulse * 0.5, control);
+                if left.abs().max(right.abs()) > 1.0e-3 {
+                    last_audible = n;
+                }
+            }
+            last_audible
+        };
+
+        let small = decay_samples(0.0);
+        let original = decay_samples(0.2);
+        let large = decay_samples(1.0);
+        assert!(original > small);
+        assert!(large > original * 2);
+    }
+
+    #[test]
+    fn inverted_feedback_shifts_comb_spectrum() {
+        let spectrum = |invert: bool| {
+            let mut settings = TensionFieldParams::new().settings();
+            settings.feedback = 0.6;
+            settings.ducking = 0.0;
+            settings.feedback_invert = invert;
+
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let mut output = Vec::with_capacity(16_384);
+            for block in 0..32 {
+                let mut left = vec![0.0_f32; 512];
+                if block == 0 {
+                    left[0] = 1.0;
+                }
+                let mut right = left.clone();
+                let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+                output.extend_from_slice(&left);
+            }
+
+            (1..48)
+                .map(|bin| {
+                    let omega = std::f32::consts::TAU * bin as f32 * 40.0 / 48_000.0;
+                    let (re, im) = output.iter().enumerate().fold(
+                        (0.0_f32, 0.0_f32),
+                        |(re, im), (n, sample)| {
+                            let angle = omega * n as f32;
+                            (re + sample * angle.cos(), im - sample * angle.sin())
+                        },
+                    );
+                    (re * re + im * im).sqrt()
+                })
+                .collect::<Vec<f32>>()
+        };
+
+        let positive = spectrum(false);
+        let inverted = spectrum(true);
+        let total: f32 = positive.iter().sum();
+        let difference: f32 = positive
+            .iter()
+            .zip(inverted.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+        assert!(difference > total * 0.05);
+    }
+
+    #[test]
+    fn positive_feedback_balance_favours_right_tail() {
+        let mut settings = TensionFieldParams::new().settings();
+        settings.feedback = 0.6;
+        settings.ducking = 0.0;
+        settings.feedback_balance = 0.8;
+
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut tail_left = 0.0_f32;
+        let mut tail_right = 0.0_f32;
+        for block in 0..96 {
+            let mut left = vec![0.0_f32; 512];
+            if block == 0 {
+                left[0] = 1.0;
+            }
+            let mut right = left.clone();
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            if block >= 48 {
+                tail_left += left.iter().map(|sample| sample * sample).sum::<f32>();
+                tail_right += right.iter().map(|sample| sample * sample).sum::<f32>();
+            }
+        }
+
+        assert!(tail_right > tail_left * 1.5);
+    }
+
+    #[test]
+    fn wrap_delta_picks_short_path() {
         let len = 100.0;
         assert!((wrap_delta(70.0, len) + 30.0).abs() < 1e-6);
         assert!((wrap_delta(-70.0, len) - 30.0).abs() < 1e-6);
@@ -625,6 +4563,7 @@ mod tests {
                     tempo_bpm: 120.0,
                     is_playing: true,
                     song_pos_beats: None,
+                    ..TransportState::default()
                 },
             );
             assert!(left.iter().all(|sample| sample.is_finite()));
@@ -632,6 +4571,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn low_energy_ceiling_settles_sustained_feedback_below_a_bound() {
+        let settled_rms = |energy_ceiling: f32| {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.7);
+            params.set_param(crate::params::PARAM_DIFFUSION_ID, 1.0);
+            params.set_param(crate::params::PARAM_ENERGY_CEILING_ID, energy_ceiling);
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let mut sum = 0.0_f64;
+            let mut count = 0;
+            for block in 0..300 {
+                let mut left: Vec<f32> = (0..480)
+                    .map(|i| ((block * 480 + i) as f32 * 0.0575).sin() * 3.0)
+                    .collect();
+                let mut right = left.clone();
+                let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+                if block >= 250 {
+                    for (l, r) in left.iter().zip(&right) {
+                        sum += f64::from(l * l + r * r) * 0.5;
+                        count += 1;
+                    }
+                }
+            }
+            (sum / count as f64).sqrt() as f32
+        };
+
+        let contained = settled_rms(0.1);
+        let open = settled_rms(1.0);
+        assert!(contained < 0.3, "contained rms {contained}");
+        assert!(
+            contained < open * 0.75,
+            "contained {contained} vs open {open}"
+        );
+    }
+
     #[test]
     fn lower_energy_ceiling_reduces_peak_growth() {
         let params = TensionFieldParams::new();
@@ -669,6 +4644,7 @@ mod tests {
                     tempo_bpm: 120.0,
                     is_playing: true,
                     song_pos_beats: None,
+                    ..TransportState::default()
                 },
             );
             let _ = relaxed_engine.render(
@@ -679,6 +4655,7 @@ mod tests {
                     tempo_bpm: 120.0,
                     is_playing: true,
                     song_pos_beats: None,
+                    ..TransportState::default()
                 },
             );
 
@@ -700,4 +4677,277 @@ mod tests {
 
         assert!(strict_peak <= relaxed_peak + 1.0e-4);
     }
+
+    #[test]
+    fn character_makeup_narrows_level_spread() {
+        let spread = |makeup: bool| {
+            let levels = [
+                CharacterMode::Clean,
+                CharacterMode::Dirty,
+                CharacterMode::Crush,
+            ]
+            .map(|character| {
+                let mut settings = TensionFieldParams::new().settings();
+                settings.tension = 0.85;
+                settings.warp_motion = 0.8;
+                settings.feedback = 0.3;
+                settings.character = character;
+                settings.character_makeup = makeup;
+                sine_rms(&settings)
+            });
+            let max = levels.iter().copied().fold(f32::MIN, f32::max);
+            let min = levels.iter().copied().fold(f32::MAX, f32::min);
+            max - min
+        };
+
+        assert!(spread(true) < spread(false));
+    }
+
+    #[test]
+    fn character_makeup_matches_dirty_and_crush_rms_to_clean() {
+        for tension in [0.2, 0.5, 0.85] {
+            let [clean, dirty, crush] = [
+                CharacterMode::Clean,
+                CharacterMode::Dirty,
+                CharacterMode::Crush,
+            ]
+            .map(|character| {
+                let mut settings = TensionFieldParams::new().settings();
+                settings.tension = tension;
+                settings.character = character;
+                settings.character_makeup = true;
+                sine_rms(&settings)
+            });
+            for level in [dirty, crush] {
+                let ratio = level / clean;
+                assert!(
+                    (ratio - 1.0).abs() < 0.01,
+                    "tension {tension}: level ratio {ratio}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pull_duck_reduces_level_as_tension_rises() {
+        let duck_ratio = |tension: f32| {
+            let mut settings = TensionFieldParams::new().settings();
+            settings.tension = tension;
+            settings.pull_latch = true;
+            let dry = sine_rms(&settings);
+            settings.pull_duck = 1.0;
+            sine_rms(&settings) / dry
+        };
+
+        let gentle = duck_ratio(0.2);
+        let hard = duck_ratio(0.95);
+        assert!(gentle < 1.0);
+        assert!(hard < gentle);
+    }
+
+    #[test]
+    fn idle_bypass_arms_after_silence_and_releases_on_input() {
+        let settings = TensionFieldParams::new().settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut bypassed_blocks = 0;
+        for _ in 0..1_000 {
+            let mut left = [0.0_f32; 256];
+            let mut right = [0.0_f32; 256];
+            if engine.idle_bypass_armed {
+                bypassed_blocks += 1;
+            }
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            assert!(left.iter().chain(right.iter()).all(|sample| *sample == 0.0));
+        }
+        assert!(engine.idle_bypass_armed);
+        assert!(bypassed_blocks > 100);
+
+        // Input returns on the last sample of a block and keeps going.
+        let tone = |n: usize| (n as f32 * 330.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4;
+        let mut left = [0.0_f32; 256];
+        left[255] = 0.4;
+        let mut right = left;
+        let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+        assert!(!engine.idle_bypass_armed);
+        assert_eq!(engine.idle_samples, 0);
+
+        let mut output_peak = 0.0_f32;
+        for block in 0..32 {
+            let mut left: Vec<f32> = (0..256).map(|i| tone(block * 256 + i)).collect();
+            let mut right = left.clone();
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            output_peak = output_peak.max(left.iter().fold(0.0_f32, |peak, s| peak.max(s.abs())));
+        }
+        assert!(output_peak > 1.0e-4);
+    }
+
+    #[test]
+    fn idle_bypass_waits_for_a_long_delay_to_render_its_hit() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_ELASTIC_SIZE_ID, 6.0);
+        params.set_param(crate::params::PARAM_TENSION_ID, 1.0);
+        params.set_param(crate::params::PARAM_MIX_ID, 1.0);
+        let settings = params.settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+
+        // One short hit, then silence well past the idle window.
+        let mut late_peak = 0.0_f32;
+        for block in 0..375 {
+            let mut left = [0.0_f32; 256];
+            if block == 0 {
+                left[..64].fill(0.8);
+            }
+            let mut right = left;
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            if block as f32 * 256.0 > IDLE_BYPASS_SECONDS * 48_000.0 {
+                late_peak = left.iter().fold(late_peak, |peak, s| peak.max(s.abs()));
+            }
+        }
+        assert!(late_peak > 1.0e-3, "delayed hit was cut off: {late_peak}");
+    }
+
+    #[test]
+    fn silence_after_a_feedback_tail_eventually_sleeps_with_cleared_state() {
+        let params = TensionFieldParams::new();
+        params.set_param(crate::params::PARAM_FEEDBACK_ID, 0.4);
+        params.set_param(crate::params::PARAM_DIFFUSION_ID, 0.8);
+        let settings = params.settings();
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let mut left: Vec<f32> = (0..48_000)
+            .map(|n| (n as f32 * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.4)
+            .collect();
+        let mut right = left.clone();
+        let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+        assert!(!engine.sleeping());
+
+        let mut sleep_block = None;
+        for block in 0..8_000 {
+            let mut left = [0.0_f32; 512];
+            let mut right = [0.0_f32; 512];
+            let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+            if engine.sleeping() {
+                sleep_block = Some(block);
+                break;
+            }
+        }
+        // The engine sleeps only after a whole reported tail of silence.
+        let sleep_block = sleep_block.expect("engine never slept");
+        assert!(sleep_block * 512 >= engine.tail_samples(&settings) as usize);
+        assert!(
+            engine
+                .stages
+                .elastic
+                .left
+                .iter()
+                .all(|sample| *sample == 0.0)
+        );
+        assert!(
+            engine
+                .stages
+                .elastic
+                .right
+                .iter()
+                .all(|sample| *sample == 0.0)
+        );
+        assert_eq!((engine.feedback_left, engine.feedback_right), (0.0, 0.0));
+    }
+
+    #[test]
+    fn dual_gesture_gives_each_channel_its_own_delay_target() {
+        let max_head_spread = |dual: bool| {
+            let params = TensionFieldParams::new();
+            params.set_param(
+                crate::params::PARAM_DUAL_GESTURE_ID,
+                if dual { 1.0 } else { 0.0 },
+            );
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            let mut spread = 0.0_f32;
+            for block in 0..64 {
+                let mut left: Vec<f32> = (0..512)
+                    .map(|i| {
+                        let n = (block * 512 + i) as f32;
+                        (n * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.3
+                    })
+                    .collect();
+                let mut right = left.clone();
+                let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+                spread = spread.max(
+                    (engine.stages.elastic.left_head.smooth_delay
+                        - engine.stages.elastic.right_head.smooth_delay)
+                        .abs(),
+                );
+            }
+            spread
+        };
+        assert_eq!(max_head_spread(false), 0.0);
+        assert!(max_head_spread(true) > 48.0);
+    }
+
+    #[test]
+    fn feedback_solo_is_silent_without_feedback() {
+        let tail_peak = |feedback: f32| {
+            let params = TensionFieldParams::new();
+            params.set_param(crate::params::PARAM_FEEDBACK_ID, feedback);
+            let settings = params.settings();
+            let mut engine = TensionFieldEngine::new(48_000.0);
+            engine.set_feedback_solo(true);
+            let mut peak = 0.0_f32;
+            for block in 0..32 {
+                let mut left: Vec<f32> = (0..512)
+                    .map(|i| {
+                        let n = (block * 512 + i) as f32;
+                        (n * 220.0 / 48_000.0 * std::f32::consts::TAU).sin() * 0.3
+                    })
+                    .collect();
+                let mut right = left.clone();
+                let _ = engine.render(&settings, &mut left, &mut right, TransportState::default());
+                for (l, r) in left.iter().zip(right.iter()) {
+                    peak = peak.max(l.abs().max(r.abs()));
+                }
+            }
+            peak
+        };
+        assert_eq!(tail_peak(0.0), 0.0);
+        assert!(tail_peak(0.5) > 1.0e-3);
+    }
+
+    #[test]
+    fn char_drive_adds_harmonics_at_matched_level() {
+        // Returns (RMS, fraction of power outside the fundamental).
+        let measure = |drive_db: f32| {
+            let mut chain = OutputChain::new(48_000.0);
+            let control = OutputChainControl {
+                drive: super::db_to_gain(drive_db),
+                crush: 0.0,
+                crush_steps: 128.0,
+                downsample: 1,
+                dc_block: 1.0,
+                limit: true,
+                clip: ClipMode::Soft,
+            };
+            let cycles = 200.0;
+            let frames = 48_000;
+            let (mut sum_sq, mut re, mut im) = (0.0_f64, 0.0_f64, 0.0_f64);
+            for n in 0..frames {
+                let phase = n as f32 * cycles / frames as f32 * std::f32::consts::TAU;
+                let (out, _) = chain.process(phase.sin() * 0.4, 0.0, control);
+                sum_sq += (out * out) as f64;
+                re += (out * phase.cos()) as f64;
+                im += (out * phase.sin()) as f64;
+            }
+            let power = sum_sq / frames as f64;
+            let fundamental = 2.0 * (re * re + im * im) / (frames as f64 * frames as f64);
+            (power.sqrt() as f32, (1.0 - fundamental / power) as f32)
+        };
+
+        let (clean_rms, clean_harmonics) = measure(0.0);
+        let (driven_rms, driven_harmonics) = measure(18.0);
+        assert!(driven_harmonics > clean_harmonics * 2.0 + 1.0e-3);
+        let level_ratio = driven_rms / clean_rms;
+        assert!(
+            (0.8..1.25).contains(&level_ratio),
+            "level ratio {level_ratio}"
+        );
+    }
 }
@@ -2,8 +2,11 @@
 
 use std::f32::consts::TAU;
 
-use crate::clock::ClockFrame;
-use crate::params::{PullDivision, PullQuantize, PullShape, TimeMode};
+use crate::clock::{ClockFrame, PhaseHumanizer};
+use crate::params::{
+    ModRateMode, PullDivision, PullQuantize, PullShape, RateMultiplier, ReleaseShape,
+    SEQ_STEP_COUNT, TimeMode,
+};
 
 /// Per-sample control inputs for the gesture engine.
 #[derive(Debug, Copy, Clone)]
@@ -18,24 +21,63 @@ pub(crate) struct GestureInput {
     pub pull_rate_hz: f32,
     /// Synced rate division.
     pub pull_division: PullDivision,
+    /// Half/double-time multiplier on the free rate or synced division.
+    pub rate_multiplier: RateMultiplier,
     /// Swing amount for synced timing.
     pub swing: f32,
+    /// Random phase wander for synced timing (0 = locked to the grid).
+    pub humanize: f32,
     /// Pull waveform shape.
     pub pull_shape: PullShape,
+    /// One-pole smoothing of the pull shape output (0 = hard steps, 1 = slowest).
+    pub shape_smooth: f32,
+    /// Cycle offset applied before evaluating the pull shape (0..1).
+    pub pull_phase: f32,
     /// Momentary pull trigger.
     pub pull_trigger: bool,
     /// Latching pull mode toggle.
     pub pull_latch: bool,
+    /// Sustain pedal down: pulls triggered while it is held stay engaged until it lifts.
+    pub sustain: bool,
     /// Quantization grid for trigger launches.
     pub pull_quantize: PullQuantize,
+    /// Launch only on grid boundaries that are a multiple of this many grids.
+    pub quantize_multiple: u32,
+    /// How far a quantized launch moves from the press toward the grid (0..1).
+    pub quantize_strength: f32,
     /// Rebound amount controlling release shape.
     pub rebound: f32,
     /// Sharpness of pull release.
     pub release_snap: f32,
+    /// Contour of the pull envelope fall after a release.
+    pub release_shape: ReleaseShape,
     /// Direction bias from backward to forward.
     pub pull_direction: f32,
     /// Viscous-to-spring response amount.
     pub elasticity: f32,
+    /// Usable elastic buffer length in seconds; scales the delay center and swing.
+    pub elastic_size: f32,
+    /// Envelope floor that keeps the pull shape moving without a trigger.
+    pub idle_motion: f32,
+    /// Baseline warp drift rate: Hertz in free mode, cycles per pull division in sync mode.
+    pub warp_rate: f32,
+    /// Free or pull-division synced baseline warp drift.
+    pub warp_rate_mode: ModRateMode,
+    /// Speed at which direction springs back to neutral after release (0 = off).
+    pub direction_return: f32,
+    /// Step pattern firing pulls on its grid, when the sequencer is on.
+    pub sequencer: Option<StepSequence>,
+}
+
+/// Step pattern that fires a pull of the step's intensity at each grid step.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct StepSequence {
+    /// Pull intensity (0..1) per step; zero steps rest.
+    pub steps: [f32; SEQ_STEP_COUNT],
+    /// Number of steps cycled through, at most `SEQ_STEP_COUNT`.
+    pub length: usize,
+    /// Synced length of one step.
+    pub division: PullDivision,
 }
 
 /// Per-sample gesture frame used by downstream DSP stages.
@@ -47,8 +89,12 @@ pub(crate) struct GestureFrame {
     pub velocity: f32,
     /// 0..1 tension drive amount.
     pub tension_drive: f32,
+    /// 0..1 pull envelope, before tension and direction shape it.
+    pub envelope: f32,
     /// Drift phase increment used by warp motion.
     pub drift_phase_inc: f32,
+    /// Pull-cycle phase (0..1) after the phase offset, used for strobe metering.
+    pub phase: f32,
 }
 
 /// Runtime state for pull envelopes and timing.
@@ -60,13 +106,73 @@ pub(crate) struct GestureEngine {
     previous_direction: f32,
     was_pull_pressed: bool,
     latched_active: bool,
+    sustained_active: bool,
+    humanizer: PhaseHumanizer,
     pending_quantized_trigger: bool,
+    quantized_launch_beat: f64,
     one_shot_samples: usize,
     previous_beat_position: Option<f64>,
     rng_state: u32,
+    was_gesture_active: bool,
+    direction_returning: bool,
+    direction_return_progress: f32,
+    cycle_offset: f32,
+    one_shot_intensity: f32,
+    smoothed_shape: f32,
+    releasing: bool,
+    release_start: f32,
+    release_progress: f32,
 }
 
+/// Elastic size the delay center and swing are tuned for; other sizes scale them.
+const ELASTIC_SIZE_DEFAULT_SECONDS: f32 = 2.75;
+/// Slowest pull-direction return time, used just above zero return speed.
+const DIRECTION_RETURN_MAX_SECONDS: f32 = 3.0;
+/// Fastest pull-direction return time at full return speed.
+const DIRECTION_RETURN_MIN_SECONDS: f32 = 0.05;
+/// Per-sample linear fall added to the release at full release snap.
+const RELEASE_SNAP_LINEAR_STEP: f32 = 0.0004;
+/// Pull shape smoothing time constant at full Shape Smooth.
+const SHAPE_SMOOTH_MAX_SECONDS: f32 = 0.05;
+/// Share of the release coefficient that shaped releases advance per sample.
+///
+/// Chosen so a linear or S-curve fall lasts about as long as the exponential
+/// release takes to reach a tenth of its start.
+const RELEASE_CURVE_RATE: f32 = 0.4;
+
 impl GestureEngine {
+    /// Advance a linear or S-curve release from where the current fall started.
+    fn shaped_release(&mut self, target: f32, release: f32, shape: ReleaseShape) -> f32 {
+        if !self.releasing {
+            self.releasing = true;
+            self.release_start = self.pull_env;
+            self.release_progress = 0.0;
+        }
+        self.release_progress = (self.release_progress + release * RELEASE_CURVE_RATE).min(1.0);
+        let progress = self.release_progress;
+        let fall = match shape {
+            ReleaseShape::SCurve => progress * progress * (3.0 - 2.0 * progress),
+            ReleaseShape::Linear | ReleaseShape::Exponential => progress,
+        };
+        let contour = self.release_start + (target - self.release_start) * fall;
+        if progress >= 1.0 {
+            self.releasing = false;
+        }
+        contour.min(self.pull_env).max(target)
+    }
+
+    /// Create an engine whose pull cycle and random motion are offset from the default.
+    ///
+    /// Used for the right channel in dual-gesture mode so each side strains on its own.
+    pub(crate) fn with_offset(cycle_offset: f32, seed: u32) -> Self {
+        Self {
+            rng_state: seed,
+            humanizer: PhaseHumanizer::new(seed.rotate_left(16)),
+            cycle_offset,
+            ..Self::default()
+        }
+    }
+
     /// Generate one gesture frame at the current sample.
     pub(crate) fn next(
         &mut self,
@@ -84,38 +190,78 @@ impl GestureEngine {
         if !input.pull_latch {
             self.latched_active = false;
         }
+        if !input.sustain {
+            self.sustained_active = false;
+        } else if input.pull_trigger {
+            self.sustained_active = true;
+        }
 
         if rising_edge {
             if input.pull_latch {
                 self.latched_active = true;
             }
 
-            if input.pull_quantize.beats().is_none() || !clock.is_playing {
-                self.start_pull(sample_rate);
-            } else {
-                self.pending_quantized_trigger = true;
+            match input.pull_quantize.beats() {
+                Some(grid_beats)
+                    if clock.is_playing && clock.host_timeline && input.quantize_strength > 0.0 =>
+                {
+                    let launch_grid = grid_beats as f64 * input.quantize_multiple.max(1) as f64;
+                    let next_boundary =
+                        ((clock.beat_position / launch_grid).floor() + 1.0) * launch_grid;
+                    self.quantized_launch_beat = clock.beat_position
+                        + (next_boundary - clock.beat_position)
+                            * input.quantize_strength.min(1.0) as f64;
+                    self.pending_quantized_trigger = true;
+                }
+                _ => self.start_pull(sample_rate),
             }
         }
 
+        if let Some(sequence) = input.sequencer
+            && clock.is_playing
+        {
+            self.advance_sequencer(sequence, clock.beat_position, sample_rate);
+        }
+
         if self.pending_quantized_trigger {
-            if let Some(grid_beats) = input.pull_quantize.beats() {
-                if self.crossed_quantize_boundary(clock.beat_position, grid_beats as f64) {
+            // Quantized launches only wait on a host-anchored timeline; the
+            // free-running fallback clock has no musical grid, so fire at once.
+            match input.pull_quantize.beats() {
+                Some(grid_beats) if clock.is_playing && clock.host_timeline => {
+                    // Partial strength launches part way to the boundary; a
+                    // crossed boundary still fires in case the transport jumped.
+                    if clock.beat_position >= self.quantized_launch_beat
+                        || self.crossed_quantize_boundary(
+                            clock.beat_position,
+                            grid_beats as f64,
+                            input.quantize_multiple,
+                        )
+                    {
+                        self.start_pull(sample_rate);
+                        self.pending_quantized_trigger = false;
+                    }
+                }
+                _ => {
                     self.start_pull(sample_rate);
                     self.pending_quantized_trigger = false;
                 }
-            } else {
-                self.start_pull(sample_rate);
-                self.pending_quantized_trigger = false;
             }
         }
 
         let phase = match input.time_mode {
             TimeMode::FreeHz => {
-                let increment = (input.pull_rate_hz / sample_rate.max(1.0)).clamp(0.000_01, 0.25);
+                let rate_hz = input.pull_rate_hz * input.rate_multiplier.factor();
+                let increment = (rate_hz / sample_rate.max(1.0)).clamp(0.000_01, 0.25);
                 self.free_phase = (self.free_phase + increment).fract();
                 self.free_phase
             }
-            TimeMode::SyncDivision => clock.phase_for_division(input.pull_division, input.swing),
+            TimeMode::SyncDivision => self.humanizer.apply(
+                clock.phase_for_division(
+                    input.pull_division.shifted(input.rate_multiplier),
+                    input.swing,
+                ),
+                input.humanize,
+            ),
         };
         let anticipation = match input.time_mode {
             TimeMode::SyncDivision => anticipation_amount(phase, input.tension_bias),
@@ -124,7 +270,7 @@ impl GestureEngine {
 
         let envelope_target: f32 = if input.pull_latch {
             if self.latched_active { 1.0 } else { 0.0 }
-        } else if input.pull_trigger {
+        } else if input.pull_trigger || self.sustained_active {
             1.0
         } else {
             0.0
@@ -135,28 +281,60 @@ impl GestureEngine {
             self.one_shot_samples -= 1;
         }
 
-        let target = envelope_target.max(if one_shot_active { 1.0 } else { 0.0 });
+        let target = envelope_target
+            .max(if one_shot_active {
+                self.one_shot_intensity
+            } else {
+                0.0
+            })
+            .max(input.idle_motion.clamp(0.0, 1.0));
         let attack = 0.006 + input.elasticity * 0.028 + anticipation * 0.012;
         let release =
             (0.0009 + input.rebound * 0.022 + input.release_snap * 0.05).clamp(0.0009, 0.09);
-        let smoothing = if target > self.pull_env {
-            attack
+        if target >= self.pull_env || input.release_shape == ReleaseShape::Exponential {
+            self.releasing = false;
+            let smoothing = if target > self.pull_env {
+                attack
+            } else {
+                release
+            };
+            self.pull_env += (target - self.pull_env) * smoothing;
         } else {
-            release
-        };
-        self.pull_env += (target - self.pull_env) * smoothing;
+            self.pull_env = self.shaped_release(target, release, input.release_shape);
+        }
+        if target < self.pull_env {
+            // Snap blends a linear fall into the exponential tail so the release
+            // reaches rest in finite time with a harder contour.
+            self.pull_env = (self.pull_env
+                - input.release_snap.clamp(0.0, 1.0) * RELEASE_SNAP_LINEAR_STEP)
+                .max(target);
+        }
+
+        self.advance_direction_return(envelope_target > 0.0, input.direction_return, sample_rate);
+        let pull_direction = self.returned_direction(input.pull_direction);
 
         let walk_amount = 0.0012 + input.elasticity * 0.005;
         self.random_walk =
             (self.random_walk + next_signed(&mut self.rng_state) * walk_amount).clamp(-1.0, 1.0);
 
-        let shape_value = evaluate_shape(input.pull_shape, phase);
+        let cycle_phase = offset_phase(phase, input.pull_phase + self.cycle_offset);
+        let shape_value = evaluate_shape(
+            input.pull_shape,
+            bias_phase(cycle_phase, input.tension_bias),
+        );
+        self.smoothed_shape = smooth_shape(
+            self.smoothed_shape,
+            shape_value,
+            input.shape_smooth,
+            sample_rate,
+        );
+        let shape_value = self.smoothed_shape;
         let anticipation_push = anticipation * (0.2 + input.tension * 0.45);
-        let motion = (shape_value + anticipation_push * input.pull_direction.signum())
+        let motion = (shape_value + anticipation_push * pull_direction.signum())
             * (0.3 + self.pull_env * 0.7)
             + self.random_walk * (0.04 + input.elasticity * 0.1);
 
-        let directional = (motion * 0.7 + input.pull_direction * 0.65).clamp(-1.0, 1.0);
+        let directional = (motion * 0.7 + pull_direction * 0.65).clamp(-1.0, 1.0);
         let velocity = (directional - self.previous_direction)
             + anticipation * directional.signum() * (0.01 + input.tension * 0.04);
         self.previous_direction = directional;
@@ -164,12 +342,16 @@ impl GestureEngine {
         let tension_drive = (input.tension
             * (0.2 + directional.abs() * 0.72 + anticipation * 0.35))
             .clamp(0.0, 1.0);
-        let center_delay = sample_rate * (0.05 + input.tension * 0.2);
-        let delay_swing = sample_rate * (0.004 + input.elasticity * 0.075 + anticipation * 0.02);
+        let size_scale = input.elastic_size / ELASTIC_SIZE_DEFAULT_SECONDS;
+        let center_delay = sample_rate * (0.05 + input.tension * 0.2) * size_scale;
+        let delay_swing =
+            sample_rate * (0.004 + input.elasticity * 0.075 + anticipation * 0.02) * size_scale;
         let delay_samples = (center_delay + directional * delay_swing).max(12.0);
 
+        let baseline_drift = self.baseline_drift_inc(&input, sample_rate, clock);
         let drift_phase_inc =
-            (0.0002 + velocity.abs() * 0.018 + tension_drive * 0.008).clamp(0.0001, 0.08);
+            (0.0002 + velocity.abs() * 0.018 + tension_drive * 0.008 + baseline_drift)
+                .clamp(0.0001, 0.08);
 
         self.previous_beat_position = Some(clock.beat_position);
 
@@ -177,15 +359,89 @@ impl GestureEngine {
             delay_samples,
             velocity,
             tension_drive,
+            envelope: self.pull_env,
             drift_phase_inc,
+            phase: cycle_phase,
+        }
+    }
+
+    /// Restart the free-running cycle and forget the previous beat after a transport jump.
+    pub(crate) fn reset_phase(&mut self) {
+        self.free_phase = 0.0;
+        self.previous_beat_position = None;
+    }
+
+    /// Ramp the direction back to neutral once a gesture ends, if auto-return is on.
+    fn advance_direction_return(&mut self, active: bool, speed: f32, sample_rate: f32) {
+        let released = self.was_gesture_active && !active;
+        self.was_gesture_active = active;
+        if active || speed <= 0.0 {
+            self.direction_returning = false;
+        } else if released {
+            self.direction_returning = true;
         }
+
+        if !self.direction_returning {
+            self.direction_return_progress = 0.0;
+            return;
+        }
+        let seconds = DIRECTION_RETURN_MIN_SECONDS
+            + (1.0 - speed.clamp(0.0, 1.0))
+                * (DIRECTION_RETURN_MAX_SECONDS - DIRECTION_RETURN_MIN_SECONDS);
+        let step = 1.0 / (seconds * sample_rate.max(1.0));
+        self.direction_return_progress = (self.direction_return_progress + step).min(1.0);
+    }
+
+    /// Scale the requested direction towards neutral by the return progress.
+    fn returned_direction(&self, direction: f32) -> f32 {
+        direction * (1.0 - self.direction_return_progress)
     }
 
     fn start_pull(&mut self, sample_rate: f32) {
         self.one_shot_samples = (sample_rate * 0.11).round() as usize;
+        self.one_shot_intensity = 1.0;
+    }
+
+    /// Fire the pattern step the clock just entered, if it has a nonzero intensity.
+    fn advance_sequencer(&mut self, sequence: StepSequence, beat_position: f64, sample_rate: f32) {
+        let step_beats = sequence.division.beats_per_cycle().max(1.0e-4) as f64;
+        if !self.crossed_quantize_boundary(beat_position, step_beats, 1) {
+            return;
+        }
+        let length = sequence.length.clamp(1, SEQ_STEP_COUNT);
+        let step = ((beat_position / step_beats).floor() as i64).rem_euclid(length as i64);
+        let intensity = sequence.steps[step as usize].clamp(0.0, 1.0);
+        if intensity > 0.0 {
+            self.start_pull(sample_rate);
+            self.one_shot_intensity = intensity;
+        }
+    }
+
+    fn baseline_drift_inc(&self, input: &GestureInput, sample_rate: f32, clock: ClockFrame) -> f32 {
+        let rate = input.warp_rate.max(0.0);
+        match input.warp_rate_mode {
+            ModRateMode::FreeHz => rate / sample_rate.max(1.0),
+            ModRateMode::SyncDivision => {
+                let beat_delta = self
+                    .previous_beat_position
+                    .map_or(0.0, |previous| (clock.beat_position - previous).max(0.0));
+                let division_beats = input.pull_division.beats_per_cycle().max(1.0e-4) as f64;
+                (beat_delta / division_beats) as f32 * rate
+            }
+        }
     }
 
-    fn crossed_quantize_boundary(&self, beat_position: f64, grid_beats: f64) -> bool {
+    /// Return whether the clock crossed a launch boundary since the previous sample.
+    ///
+    /// Launch boundaries fall every `multiple` grids, so a 1/4 grid with a
+    /// multiple of 4 waits for the next bar line.
+    fn crossed_quantize_boundary(
+        &self,
+        beat_position: f64,
+        grid_beats: f64,
+        multiple: u32,
+    ) -> bool {
+        let grid_beats = grid_beats * multiple.max(1) as f64;
         let previous = self.previous_beat_position.unwrap_or(beat_position);
         let prev_index = (previous / grid_beats).floor();
         let current_index = (beat_position / grid_beats).floor();
@@ -193,6 +449,10 @@ impl GestureEngine {
     }
 }
 
+fn offset_phase(phase: f32, offset: f32) -> f32 {
+    (phase + offset).rem_euclid(1.0)
+}
+
 fn evaluate_shape(shape: PullShape, phase: f32) -> f32 {
     let phase = phase.fract();
     match shape {
@@ -222,6 +482,24 @@ fn evaluate_shape(shape: PullShape, phase: f32) -> f32 {
     }
 }
 
+/// Move `previous` one sample toward `target`, rounding off the Ratchet and
+/// Pulse steps; zero smoothing passes the shape through unchanged.
+fn smooth_shape(previous: f32, target: f32, smooth: f32, sample_rate: f32) -> f32 {
+    let time_constant = smooth.clamp(0.0, 1.0) * SHAPE_SMOOTH_MAX_SECONDS * sample_rate.max(1.0);
+    if time_constant <= 1.0 {
+        return target;
+    }
+    previous + (target - previous) * (1.0 - (-1.0 / time_constant).exp())
+}
+
+/// Gamma-warp a cycle phase so a high bias pushes the shape's peak later.
+///
+/// A bias of 0.5 leaves the phase unchanged; the warp keeps 0 and 1 fixed.
+fn bias_phase(phase: f32, tension_bias: f32) -> f32 {
+    let bias = (tension_bias * 2.0 - 1.0).clamp(-1.0, 1.0);
+    phase.clamp(0.0, 1.0).powf(3.0_f32.powf(bias))
+}
+
 fn anticipation_amount(phase: f32, tension_bias: f32) -> f32 {
     let bias = (tension_bias * 2.0 - 1.0).clamp(-1.0, 1.0);
     let window = (0.16 + (1.0 - bias.abs()) * 0.1).clamp(0.08, 0.3);
@@ -242,9 +520,15 @@ fn next_signed(state: &mut u32) -> f32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{GestureEngine, GestureInput, anticipation_amount, evaluate_shape};
-    use crate::clock::ClockFrame;
-    use crate::params::{PullDivision, PullQuantize, PullShape, TimeMode};
+    use super::{
+        GestureEngine, GestureInput, StepSequence, anticipation_amount, bias_phase, evaluate_shape,
+        offset_phase, smooth_shape,
+    };
+    use crate::clock::{ClockFrame, HUMANIZE_MAX_CYCLE_SHIFT};
+    use crate::params::{
+        ModRateMode, PullDivision, PullQuantize, PullShape, RateMultiplier, ReleaseShape,
+        SEQ_STEP_COUNT, TimeMode,
+    };
 
     fn base_input() -> GestureInput {
         GestureInput {
@@ -253,18 +537,74 @@ mod tests {
             time_mode: TimeMode::SyncDivision,
             pull_rate_hz: 0.25,
             pull_division: PullDivision::Div1_4,
+            rate_multiplier: RateMultiplier::Normal,
             swing: 0.0,
+            humanize: 0.0,
             pull_shape: PullShape::Rubber,
+            shape_smooth: 0.0,
+            pull_phase: 0.0,
             pull_trigger: false,
             pull_latch: false,
+            sustain: false,
             pull_quantize: PullQuantize::None,
+            quantize_multiple: 1,
+            quantize_strength: 1.0,
             rebound: 0.5,
             release_snap: 0.35,
+            release_shape: ReleaseShape::Exponential,
             pull_direction: 0.2,
             elasticity: 0.7,
+            elastic_size: super::ELASTIC_SIZE_DEFAULT_SECONDS,
+            idle_motion: 0.0,
+            warp_rate: 0.0,
+            warp_rate_mode: ModRateMode::FreeHz,
+            direction_return: 0.0,
+            sequencer: None,
         }
     }
 
+    #[test]
+    fn sequencer_fires_programmed_intensities_in_step_order() {
+        let mut steps = [0.0; SEQ_STEP_COUNT];
+        for (index, step) in steps.iter_mut().take(8).enumerate() {
+            *step = (index + 1) as f32 * 0.1;
+        }
+        steps[3] = 0.0;
+        let input = GestureInput {
+            sequencer: Some(StepSequence {
+                steps,
+                length: 8,
+                division: PullDivision::Div1_16,
+            }),
+            ..base_input()
+        };
+
+        let mut engine = GestureEngine::default();
+        let mut fired = Vec::new();
+        // 120 BPM at 48 kHz is 24,000 samples per beat; run just past two bars of 1/16 steps.
+        for sample in 0..48_240 {
+            let previous_samples = engine.one_shot_samples;
+            let _ = engine.next(
+                input,
+                48_000.0,
+                ClockFrame {
+                    beat_position: sample as f64 / 24_000.0,
+                    is_playing: true,
+                    host_timeline: true,
+                },
+            );
+            if engine.one_shot_samples > previous_samples {
+                fired.push(engine.one_shot_intensity);
+            }
+        }
+
+        // Entering beat 0 is not a crossing, so steps start at 1 and wrap back to 0; step 3 rests.
+        let expected = [
+            steps[1], steps[2], steps[4], steps[5], steps[6], steps[7], steps[0],
+        ];
+        assert_eq!(fired, expected);
+    }
+
     #[test]
     fn shape_values_stay_in_range() {
         for shape in [
@@ -282,6 +622,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn shape_smoothing_slows_pulse_transitions() {
+        let max_slew = |smooth: f32| {
+            let mut value = evaluate_shape(PullShape::Pulse, 0.0);
+            let mut slew = 0.0_f32;
+            for i in 0..48_000 {
+                let target = evaluate_shape(PullShape::Pulse, i as f32 / 48_000.0);
+                let next = smooth_shape(value, target, smooth, 48_000.0);
+                slew = slew.max((next - value).abs());
+                value = next;
+            }
+            slew
+        };
+
+        // The Pulse shape drops from 0.6 to -1.0 in one sample when unsmoothed.
+        assert!((max_slew(0.0) - 1.6).abs() < 1.0e-5);
+        assert!(max_slew(0.2) < max_slew(0.0) * 0.1);
+        assert!(max_slew(1.0) < max_slew(0.2));
+    }
+
+    #[test]
+    fn phase_offset_shifts_shape_evaluation() {
+        for shape in [PullShape::Linear, PullShape::Pulse, PullShape::Wave] {
+            for base in [0.1_f32, 0.3, 0.7] {
+                let shifted = evaluate_shape(shape, offset_phase(base, 0.5));
+                let expected = evaluate_shape(shape, (base + 0.5).fract());
+                assert!((shifted - expected).abs() < 1.0e-6);
+            }
+        }
+        assert!((offset_phase(0.7, 0.5) - 0.2).abs() < 1.0e-6);
+        assert_eq!(offset_phase(0.25, 0.0), 0.25);
+    }
+
+    #[test]
+    fn idle_motion_animates_tension_without_trigger() {
+        let drive_range = |idle_motion: f32| {
+            let mut engine = GestureEngine::default();
+            let input = GestureInput {
+                idle_motion,
+                pull_direction: 0.0,
+                ..base_input()
+            };
+            let beat_increment = 120.0 / (48_000.0 * 60.0);
+            let mut min = f32::MAX;
+            let mut max = f32::MIN;
+            for n in 0..96_000 {
+                let frame = engine.next(
+                    input,
+                    48_000.0,
+                    ClockFrame {
+                        beat_position: n as f64 * beat_increment,
+                        is_playing: true,
+                        host_timeline: true,
+                    },
+                );
+                if n >= 48_000 {
+                    min = min.min(frame.tension_drive);
+                    max = max.max(frame.tension_drive);
+                }
+            }
+            max - min
+        };
+
+        let idle = drive_range(1.0);
+        assert!(idle > 0.05);
+        assert!(idle > drive_range(0.0));
+    }
+
+    fn drift_increments(input: GestureInput) -> Vec<f32> {
+        let mut engine = GestureEngine::default();
+        let beat_increment = 120.0 / (48_000.0 * 60.0);
+        (0..256)
+            .map(|n| {
+                engine
+                    .next(
+                        input,
+                        48_000.0,
+                        ClockFrame {
+                            beat_position: n as f64 * beat_increment,
+                            is_playing: true,
+                            host_timeline: true,
+                        },
+                    )
+                    .drift_phase_inc
+            })
+            .collect()
+    }
+
+    #[test]
+    fn warp_rate_adds_steady_drift_increment() {
+        let rest = GestureInput {
+            tension: 0.0,
+            ..base_input()
+        };
+        let baseline = drift_increments(rest);
+
+        let free = drift_increments(GestureInput {
+            warp_rate: 2.0,
+            ..rest
+        });
+        for (with_rate, without) in free.iter().zip(baseline.iter()) {
+            assert!((with_rate - without - 2.0 / 48_000.0).abs() < 1.0e-7);
+        }
+
+        let synced = drift_increments(GestureInput {
+            warp_rate: 1.0,
+            warp_rate_mode: ModRateMode::SyncDivision,
+            ..rest
+        });
+        let beat_increment = 120.0 / (48_000.0 * 60.0);
+        for (with_rate, without) in synced.iter().zip(baseline.iter()).skip(1) {
+            assert!((with_rate - without - beat_increment as f32).abs() < 1.0e-7);
+        }
+    }
+
     #[test]
     fn latch_keeps_envelope_active_after_trigger_release() {
         let mut engine = GestureEngine::default();
@@ -295,6 +750,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.0,
                 is_playing: true,
+                host_timeline: true,
             },
         );
 
@@ -305,12 +761,36 @@ mod tests {
             ClockFrame {
                 beat_position: 0.01,
                 is_playing: true,
+                host_timeline: true,
             },
         );
 
         assert!(frame.tension_drive > 0.0);
     }
 
+    #[test]
+    fn tension_bias_moves_shape_peak_later_in_cycle() {
+        let peak_phase = |tension_bias: f32| {
+            let mut best = (0.0_f32, f32::MIN);
+            for step in 0..1_000 {
+                let phase = step as f32 / 1_000.0;
+                let value = evaluate_shape(PullShape::Wave, bias_phase(phase, tension_bias));
+                assert!((-1.0..=1.0).contains(&value));
+                if value > best.1 {
+                    best = (phase, value);
+                }
+            }
+            best.0
+        };
+
+        assert!((peak_phase(0.5) - 0.25).abs() < 0.002);
+        let peaks: Vec<f32> = [0.0, 0.25, 0.5, 0.75, 1.0]
+            .into_iter()
+            .map(peak_phase)
+            .collect();
+        assert!(peaks.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
     #[test]
     fn anticipation_boosts_velocity_near_cycle_end() {
         assert!(anticipation_amount(0.98, 1.0) > anticipation_amount(0.2, 1.0));
@@ -326,6 +806,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.1,
                 is_playing: true,
+                host_timeline: true,
             },
         );
         let near_boundary = engine.next(
@@ -334,6 +815,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.99,
                 is_playing: true,
+                host_timeline: true,
             },
         );
 
@@ -353,6 +835,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.0,
                 is_playing: true,
+                host_timeline: true,
             },
         );
         let _ = snap_engine.next(
@@ -364,6 +847,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.0,
                 is_playing: true,
+                host_timeline: true,
             },
         );
 
@@ -376,6 +860,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.1,
                 is_playing: true,
+                host_timeline: true,
             },
         );
         let snap_frame = snap_engine.next(
@@ -387,9 +872,335 @@ mod tests {
             ClockFrame {
                 beat_position: 0.1,
                 is_playing: true,
+                host_timeline: true,
             },
         );
 
         assert!(snap_frame.tension_drive <= no_snap_frame.tension_drive);
     }
+
+    #[test]
+    fn linear_release_falls_at_a_constant_rate_unlike_exponential() {
+        // Per-sample drops over the first 150 samples after the trigger lifts.
+        let release_drops = |release_shape: ReleaseShape| {
+            let mut engine = GestureEngine::default();
+            let mut input = GestureInput {
+                release_snap: 0.0,
+                release_shape,
+                pull_latch: true,
+                pull_trigger: true,
+                ..base_input()
+            };
+            let clock = ClockFrame {
+                beat_position: 0.0,
+                is_playing: false,
+                host_timeline: false,
+            };
+            let mut previous = 0.0;
+            for _ in 0..9_600 {
+                previous = engine.next(input, 48_000.0, clock).envelope;
+            }
+            input.pull_latch = false;
+            input.pull_trigger = false;
+            (0..150)
+                .map(|_| {
+                    let envelope = engine.next(input, 48_000.0, clock).envelope;
+                    let drop = previous - envelope;
+                    previous = envelope;
+                    drop
+                })
+                .collect::<Vec<f32>>()
+        };
+
+        let linear = release_drops(ReleaseShape::Linear);
+        let (early, late) = (linear[5], linear[145]);
+        assert!(early > 0.0);
+        assert!(
+            (early - late).abs() < early * 0.01,
+            "linear {early} vs {late}"
+        );
+
+        let exponential = release_drops(ReleaseShape::Exponential);
+        assert!(exponential[145] < exponential[5] * 0.5);
+    }
+
+    #[test]
+    fn higher_release_snap_reaches_tenth_of_peak_sooner() {
+        let samples_to_tenth = |release_snap: f32| {
+            let mut engine = GestureEngine::default();
+            let mut input = GestureInput {
+                release_snap,
+                pull_latch: true,
+                pull_trigger: true,
+                ..base_input()
+            };
+            let clock = ClockFrame {
+                beat_position: 0.0,
+                is_playing: false,
+                host_timeline: false,
+            };
+            let mut peak = 0.0_f32;
+            for _ in 0..4_800 {
+                peak = peak.max(engine.next(input, 48_000.0, clock).envelope);
+            }
+            input.pull_latch = false;
+            input.pull_trigger = false;
+            (1..=96_000)
+                .find(|_| engine.next(input, 48_000.0, clock).envelope < peak * 0.1)
+                .expect("envelope should decay")
+        };
+
+        let counts: Vec<usize> = [0.0, 0.25, 0.5, 0.75, 1.0]
+            .into_iter()
+            .map(samples_to_tenth)
+            .collect();
+        assert!(counts.windows(2).all(|pair| pair[1] < pair[0]));
+    }
+
+    fn first_launch(press_sample: usize, host_timeline: bool) -> (usize, f64) {
+        first_launch_with_multiple(press_sample, host_timeline, 1)
+    }
+
+    fn first_launch_with_multiple(
+        press_sample: usize,
+        host_timeline: bool,
+        quantize_multiple: u32,
+    ) -> (usize, f64) {
+        first_launch_with_strength(press_sample, host_timeline, quantize_multiple, 1.0)
+    }
+
+    fn first_launch_with_strength(
+        press_sample: usize,
+        host_timeline: bool,
+        quantize_multiple: u32,
+        quantize_strength: f32,
+    ) -> (usize, f64) {
+        let beat_increment = 120.0 / (48_000.0 * 60.0);
+        let mut engine = GestureEngine::default();
+        let mut input = base_input();
+        input.pull_quantize = PullQuantize::Div1_4;
+        input.quantize_multiple = quantize_multiple;
+        input.quantize_strength = quantize_strength;
+
+        for n in 0..240_000 {
+            input.pull_trigger = n >= press_sample;
+            let beat_position = 0.37 + n as f64 * beat_increment;
+            let _ = engine.next(
+                input,
+                48_000.0,
+                ClockFrame {
+                    beat_position,
+                    is_playing: true,
+                    host_timeline,
+                },
+            );
+            if n >= press_sample && !engine.pending_quantized_trigger {
+                return (n, beat_position);
+            }
+        }
+        panic!("pull never launched");
+    }
+
+    #[test]
+    fn quantized_launch_locks_to_host_grid() {
+        let beat_increment = 120.0 / (48_000.0 * 60.0);
+        for press_sample in [10, 7_777, 15_001, 40_123] {
+            let (launch_sample, beat_position) = first_launch(press_sample, true);
+            assert!(launch_sample > press_sample);
+            assert!(beat_position.fract() < beat_increment * 1.01);
+        }
+    }
+
+    #[test]
+    fn quantize_multiple_waits_for_bar_boundaries() {
+        let beat_increment = 120.0 / (48_000.0 * 60.0);
+        for press_sample in [10, 7_777, 30_001, 60_123] {
+            let (launch_sample, beat_position) = first_launch_with_multiple(press_sample, true, 4);
+            assert!(launch_sample > press_sample);
+            let into_bar = beat_position.rem_euclid(4.0);
+            assert!(into_bar < beat_increment * 1.01);
+            // No earlier bar line lies between the press and the launch.
+            let press_beat = 0.37 + press_sample as f64 * beat_increment;
+            assert!(beat_position - press_beat <= 4.0);
+        }
+    }
+
+    #[test]
+    fn quantize_strength_blends_press_time_and_grid() {
+        let beat_increment = 120.0 / (48_000.0 * 60.0);
+        let press_sample = 7_777;
+        let press_beat = 0.37 + press_sample as f64 * beat_increment;
+        let next_boundary = press_beat.ceil();
+
+        let (free_sample, _) = first_launch_with_strength(press_sample, true, 1, 0.0);
+        assert_eq!(free_sample, press_sample);
+
+        let (_, gridded_beat) = first_launch_with_strength(press_sample, true, 1, 1.0);
+        assert!((gridded_beat - next_boundary).abs() < beat_increment * 1.01);
+
+        let mut previous_beat = press_beat;
+        for strength in [0.25, 0.5, 0.75] {
+            let (_, beat) = first_launch_with_strength(press_sample, true, 1, strength);
+            let expected = press_beat + (next_boundary - press_beat) * strength as f64;
+            assert!((beat - expected).abs() < beat_increment * 1.01);
+            assert!(beat > previous_beat && beat < gridded_beat);
+            previous_beat = beat;
+        }
+    }
+
+    #[test]
+    fn quantized_launch_fires_immediately_without_host_timeline() {
+        for press_sample in [10, 7_777, 15_001] {
+            let (launch_sample, _) = first_launch(press_sample, false);
+            assert_eq!(launch_sample, press_sample);
+        }
+    }
+
+    #[test]
+    fn direction_returns_to_neutral_after_release() {
+        let clock = ClockFrame {
+            beat_position: 0.0,
+            is_playing: false,
+            host_timeline: false,
+        };
+        let run = |direction_return: f32| {
+            let mut engine = GestureEngine::default();
+            let mut input = GestureInput {
+                pull_direction: 0.9,
+                direction_return,
+                ..base_input()
+            };
+            input.pull_trigger = true;
+            for _ in 0..4_800 {
+                let _ = engine.next(input, 48_000.0, clock);
+            }
+            assert_eq!(engine.returned_direction(input.pull_direction), 0.9);
+
+            input.pull_trigger = false;
+            let mut trace = Vec::new();
+            for _ in 0..96_000 {
+                let _ = engine.next(input, 48_000.0, clock);
+                trace.push(engine.returned_direction(input.pull_direction));
+            }
+            trace
+        };
+
+        let returning = run(0.5);
+        assert!(returning[100] > 0.8);
+        assert!(returning.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(returning[returning.len() - 1].abs() < 1.0e-6);
+        assert!(run(0.0).iter().all(|direction| *direction == 0.9));
+    }
+
+    #[test]
+    fn sustain_holds_tension_after_release_until_lifted() {
+        let clock = ClockFrame {
+            beat_position: 0.0,
+            is_playing: false,
+            host_timeline: false,
+        };
+        let drive_after = |sustain_steps: [bool; 3]| {
+            let mut engine = GestureEngine::default();
+            let mut input = GestureInput {
+                pull_phase: 0.25,
+                pull_direction: 0.0,
+                ..base_input()
+            };
+            let mut drive = 0.0;
+            // Trigger held, trigger released, then a final stretch.
+            for (step, sustain) in sustain_steps.into_iter().enumerate() {
+                input.pull_trigger = step == 0;
+                input.sustain = sustain;
+                for _ in 0..24_000 {
+                    drive = engine.next(input, 48_000.0, clock).tension_drive;
+                }
+            }
+            drive
+        };
+        let released = drive_after([false, false, false]);
+        let pedal_down = drive_after([true, true, true]);
+        let pedal_lifted = drive_after([true, true, false]);
+        assert!(pedal_down > released + 0.1);
+        assert!(pedal_lifted < pedal_down - 0.1);
+        assert!((pedal_lifted - released).abs() < 0.05);
+    }
+
+    #[test]
+    fn humanize_wanders_synced_phase_within_bounds() {
+        let max_deviation = |humanize: f32| {
+            let mut engine = GestureEngine::default();
+            let input = GestureInput {
+                humanize,
+                ..base_input()
+            };
+            let mut deviation = 0.0_f32;
+            // Eight beats at 120 BPM, 48 kHz.
+            for n in 0..192_000 {
+                let clock = ClockFrame {
+                    beat_position: n as f64 / 24_000.0,
+                    is_playing: true,
+                    host_timeline: true,
+                };
+                let frame = engine.next(input, 48_000.0, clock);
+                let grid = clock.phase_for_division(input.pull_division, input.swing);
+                let difference = (frame.phase - grid).rem_euclid(1.0);
+                deviation = deviation.max(difference.min(1.0 - difference));
+            }
+            deviation
+        };
+        assert_eq!(max_deviation(0.0), 0.0);
+        let humanized = max_deviation(1.0);
+        assert!(humanized > 0.001);
+        assert!(humanized <= HUMANIZE_MAX_CYCLE_SHIFT + 1.0e-4);
+    }
+
+    #[test]
+    fn rate_multiplier_scales_free_increment_and_synced_cycle() {
+        let free_increment = |rate_multiplier: RateMultiplier| {
+            let mut engine = GestureEngine::default();
+            let input = GestureInput {
+                time_mode: TimeMode::FreeHz,
+                pull_rate_hz: 2.0,
+                rate_multiplier,
+                ..base_input()
+            };
+            let clock = ClockFrame {
+                beat_position: 0.0,
+                is_playing: false,
+                host_timeline: false,
+            };
+            let first = engine.next(input, 48_000.0, clock).phase;
+            let second = engine.next(input, 48_000.0, clock).phase;
+            second - first
+        };
+        let normal = free_increment(RateMultiplier::Normal);
+        assert!((free_increment(RateMultiplier::Double) - normal * 2.0).abs() < 1.0e-7);
+        assert!((free_increment(RateMultiplier::Half) - normal * 0.5).abs() < 1.0e-7);
+
+        // A quarter-note cycle at double time wraps every eighth note.
+        let mut engine = GestureEngine::default();
+        let input = GestureInput {
+            rate_multiplier: RateMultiplier::Double,
+            ..base_input()
+        };
+        for n in 0..48_000 {
+            let clock = ClockFrame {
+                beat_position: n as f64 / 24_000.0,
+                is_playing: true,
+                host_timeline: true,
+            };
+            let frame = engine.next(input, 48_000.0, clock);
+            let half_cycle = clock.phase_for_division(PullDivision::Div1_8, 0.0);
+            assert!((frame.phase - half_cycle).abs() < 1.0e-6);
+        }
+
+        assert_eq!(
+            PullDivision::Div1_16.shifted(RateMultiplier::Double),
+            PullDivision::Div1_16
+        );
+        assert_eq!(
+            PullDivision::Div2Bar.shifted(RateMultiplier::Half),
+            PullDivision::Div2Bar
+        );
+    }
 }
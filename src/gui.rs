@@ -1,6 +1,8 @@
 //! Tabbed performance UI for the Tension Field plugin.
 
+use std::cell::RefCell;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
 use toybox::clack_extensions::gui::Window;
@@ -17,26 +19,62 @@ use toybox::gui::{Color, Point, Rect, Size, Theme};
 use toybox::patchbay_gui::Ui;
 use toybox::raw_window_handle::HasRawWindowHandle;
 
+use crate::audition::Auditions;
+use crate::coalesce::ValueCoalescer;
+use crate::diagnostics::PanicSlot;
+use crate::dsp::{
+    LIMITER_CEILING, METER_FLOOR_DBFS, MeterZone, dbfs_meter_fill, level_meter_fill, meter_zone,
+    peak_to_dbfs,
+};
+use crate::layout::{fit_editor_size, map_size_for_window};
+use crate::mod_matrix::route_contribution;
 use crate::params::{
-    CHARACTER_LABELS, MOD_RATE_MODE_LABELS, MOD_SOURCE_SHAPE_LABELS, PARAM_AIR_COMP_ID,
-    PARAM_AIR_DAMPING_ID, PARAM_CLEAN_DIRTY_ID, PARAM_DIFFUSION_ID, PARAM_DUCKING_ID,
-    PARAM_ELASTICITY_ID, PARAM_ENERGY_CEILING_ID, PARAM_FEEDBACK_ID, PARAM_GRAIN_CONTINUITY_ID,
-    PARAM_HOLD_ID, PARAM_MOD_A_DEPTH_ID, PARAM_MOD_A_DIVISION_ID, PARAM_MOD_A_RATE_HZ_ID,
-    PARAM_MOD_A_RATE_MODE_ID, PARAM_MOD_A_SHAPE_ID, PARAM_MOD_A_TO_DIRECTION_ID,
-    PARAM_MOD_A_TO_FEEDBACK_ID, PARAM_MOD_A_TO_GRAIN_ID, PARAM_MOD_A_TO_TENSION_ID,
-    PARAM_MOD_A_TO_WARP_MOTION_ID, PARAM_MOD_A_TO_WIDTH_ID, PARAM_MOD_B_DEPTH_ID,
-    PARAM_MOD_B_DIVISION_ID, PARAM_MOD_B_RATE_HZ_ID, PARAM_MOD_B_RATE_MODE_ID,
-    PARAM_MOD_B_SHAPE_ID, PARAM_MOD_B_TO_DIRECTION_ID, PARAM_MOD_B_TO_FEEDBACK_ID,
-    PARAM_MOD_B_TO_GRAIN_ID, PARAM_MOD_B_TO_TENSION_ID, PARAM_MOD_B_TO_WARP_MOTION_ID,
-    PARAM_MOD_B_TO_WIDTH_ID, PARAM_MOD_RUN_ID, PARAM_OUTPUT_TRIM_DB_ID, PARAM_PITCH_COUPLING_ID,
-    PARAM_PULL_DIRECTION_ID, PARAM_PULL_DIVISION_ID, PARAM_PULL_LATCH_ID, PARAM_PULL_QUANTIZE_ID,
-    PARAM_PULL_RATE_ID, PARAM_PULL_SHAPE_ID, PARAM_PULL_TRIGGER_ID, PARAM_REBOUND_ID,
-    PARAM_RELEASE_SNAP_ID, PARAM_SWING_ID, PARAM_TENSION_BIAS_ID, PARAM_TENSION_ID,
-    PARAM_TIME_MODE_ID, PARAM_WARP_COLOR_ID, PARAM_WARP_MOTION_ID, PARAM_WIDTH_ID,
-    PULL_DIVISION_LABELS, PULL_QUANTIZE_LABELS, PULL_SHAPE_LABELS, TIME_MODE_LABELS,
-    WARP_COLOR_LABELS, character_mode_value_from_index, mod_rate_mode_value_from_index,
-    mod_source_shape_value_from_index, pull_division_value_from_index,
-    pull_quantize_value_from_index, pull_shape_value_from_index, warp_color_value_from_index,
+    CHARACTER_LABELS, CLIP_MODE_LABELS, DEFAULT_METER_WARN_DBFS, DISPLAY_PRECISION_LABELS,
+    DisplayPrefs, INPUT_GATE_OFF_DB, KEY_LOCK_LABELS, METER_WARN_DBFS_OPTIONS, METER_WARN_LABELS,
+    MOD_RATE_MODE_LABELS, MOD_SOURCE_SHAPE_LABELS, PARAM_AIR_COMP_AMOUNT_ID, PARAM_AIR_COMP_ID,
+    PARAM_AIR_DAMPING_ID, PARAM_AIR_FREQ_HZ_ID, PARAM_CHAR_DRIVE_ID, PARAM_CHAR_MAKEUP_ID,
+    PARAM_CLEAN_DIRTY_ID, PARAM_CLIP_MODE_ID, PARAM_CRUSH_DEPTH_ID, PARAM_DIFFUSION_ID,
+    PARAM_DIR_RETURN_ID, PARAM_DOWNSAMPLE_ID, PARAM_DUAL_GESTURE_ID, PARAM_DUCKING_ID,
+    PARAM_ELASTIC_REVERSE_ID, PARAM_ELASTIC_SIZE_ID, PARAM_ELASTICITY_ID,
+    PARAM_ELASTICITY_TO_WIDTH_ID, PARAM_ENERGY_CEILING_ID, PARAM_FEEDBACK_BALANCE_ID,
+    PARAM_FEEDBACK_HPF_ID, PARAM_FEEDBACK_ID, PARAM_FEEDBACK_POLARITY_ID, PARAM_FEEDBACK_TONE_ID,
+    PARAM_FREEZE_ID, PARAM_GRAIN_BIAS_ID, PARAM_GRAIN_CONTINUITY_ID, PARAM_HAAS_MS_ID,
+    PARAM_HOLD_ID, PARAM_HUMANIZE_ID, PARAM_IDLE_MOTION_ID, PARAM_IMAGE_ROTATE_ID,
+    PARAM_INPUT_GATE_ID, PARAM_KEY_LOCK_ID, PARAM_LIMIT_LOOKAHEAD_MS_ID, PARAM_LIMITER_ID,
+    PARAM_MIX_ID, PARAM_MOD_A_DEPTH_ID, PARAM_MOD_A_DIVISION_ID, PARAM_MOD_A_ENV_ATTACK_ID,
+    PARAM_MOD_A_ENV_RELEASE_ID, PARAM_MOD_A_RATE_HZ_ID, PARAM_MOD_A_RATE_MODE_ID,
+    PARAM_MOD_A_SHAPE_ID, PARAM_MOD_A_TO_DIRECTION_ID, PARAM_MOD_A_TO_FEEDBACK_ID,
+    PARAM_MOD_A_TO_GRAIN_ID, PARAM_MOD_A_TO_TENSION_ID, PARAM_MOD_A_TO_WARP_MOTION_ID,
+    PARAM_MOD_A_TO_WIDTH_ID, PARAM_MOD_B_DEPTH_ID, PARAM_MOD_B_DIVISION_ID,
+    PARAM_MOD_B_ENV_ATTACK_ID, PARAM_MOD_B_ENV_RELEASE_ID, PARAM_MOD_B_RATE_HZ_ID,
+    PARAM_MOD_B_RATE_MODE_ID, PARAM_MOD_B_SHAPE_ID, PARAM_MOD_B_TO_DIRECTION_ID,
+    PARAM_MOD_B_TO_FEEDBACK_ID, PARAM_MOD_B_TO_GRAIN_ID, PARAM_MOD_B_TO_TENSION_ID,
+    PARAM_MOD_B_TO_WARP_MOTION_ID, PARAM_MOD_B_TO_WIDTH_ID, PARAM_MOD_RUN_ID,
+    PARAM_MONO_MONITOR_ID, PARAM_OUTPUT_TRIM_DB_ID, PARAM_PITCH_COUPLING_ID,
+    PARAM_PULL_DIRECTION_ID, PARAM_PULL_DIVISION_ID, PARAM_PULL_DUCK_ID, PARAM_PULL_LATCH_ID,
+    PARAM_PULL_PHASE_ID, PARAM_PULL_QUANTIZE_ID, PARAM_PULL_RATE_ID, PARAM_PULL_SHAPE_ID,
+    PARAM_PULL_TRIGGER_ID, PARAM_QUANTIZE_MULTIPLE_ID, PARAM_QUANTIZE_STRENGTH_ID,
+    PARAM_RATE_MULT_ID, PARAM_REBOUND_ID, PARAM_RELEASE_SHAPE_ID, PARAM_RELEASE_SNAP_ID,
+    PARAM_SAFETY_LIMIT_ID, PARAM_SCRUB_ID, PARAM_SEQ_DIVISION_ID, PARAM_SEQ_ENABLE_ID,
+    PARAM_SEQ_STEPS_ID, PARAM_SHAPE_SMOOTH_ID, PARAM_SPACE_SIZE_ID, PARAM_SPACE_TONE_ID,
+    PARAM_SPRING_ID, PARAM_SUSTAIN_PEDAL_ID, PARAM_SWING_ID, PARAM_TEMPO_FOLLOW_ID,
+    PARAM_TENSION_BIAS_ID, PARAM_TENSION_FLOOR_ID, PARAM_TENSION_ID, PARAM_TENSION_METER_ID,
+    PARAM_TIME_MODE_ID, PARAM_TRANSIENT_PRESERVE_ID, PARAM_VERBOSE_LOG_ID, PARAM_WARP_COLOR_ID,
+    PARAM_WARP_HIGH_COLOR_ID, PARAM_WARP_LOW_KEEP_HZ_ID, PARAM_WARP_MOTION_CURVE_ID,
+    PARAM_WARP_MOTION_ID, PARAM_WARP_RATE_ID, PARAM_WARP_RATE_MODE_ID, PARAM_WIDTH_ID,
+    PARAM_WIDTH_SAFETY_ID, PRESET_FILTER_LABELS, PULL_DIVISION_LABELS, PULL_QUANTIZE_LABELS,
+    PULL_SHAPE_LABELS, ParamLocks, PresetTag, RATE_MULT_LABELS, RELEASE_SHAPE_LABELS,
+    SEQ_STEP_COUNT, SEQ_STEPS_LABELS, StepPattern, TENSION_METER_LABELS, TIME_MODE_LABELS,
+    WARP_COLOR_LABELS, WARP_HIGH_COLOR_LABELS, apply_click_toggle, apply_momentary,
+    apply_unlocked_updates, character_mode_value_from_index, debug_dump, display_precision_preset,
+    filter_presets, mod_rate_mode_value_from_index, mod_source_shape_value_from_index,
+    preset_filter_tag, pull_division_value_from_index, pull_quantize_value_from_index,
+    pull_shape_value_from_index, reset_all, warp_color_value_from_index,
+};
+use crate::strobe::StrobeFlash;
+use crate::trace::{
+    DEFAULT_TRACE_HISTORY_SECONDS, TRACE_HISTORY_LABELS, TRACE_HISTORY_SECONDS,
+    TRACE_SMOOTHING_LABELS, TimedTrace, TraceSmoothing, decimate_and_smooth,
 };
 use crate::{GuiStatus, HostParamRequester};
 
@@ -46,14 +84,52 @@ const PANEL_GAP: i32 = 12;
 const CONTROL_GAP: i32 = 8;
 const BUTTON_WIDTH: u32 = 124;
 const BUTTON_HEIGHT: u32 = 24;
+const DEBUG_BUTTON_WIDTH: u32 = 112;
+const DEBUG_BUTTON_HEIGHT: u32 = 18;
 const TOGGLE_W: u32 = 60;
 const TOGGLE_H: u32 = 18;
+const SEQ_STEP_W: u32 = 18;
+const SEQ_STEP_H: u32 = 40;
 const DROPDOWN_W: u32 = 160;
 const DROPDOWN_H: u32 = 22;
 const MAP_WIDTH: u32 = 620;
 const MAP_HEIGHT: u32 = 360;
 const METER_CELL_W: u32 = 72;
 const METER_CELL_H: u32 = 96;
+const ROUTE_BAR_W: u32 = 44;
+const ROUTE_BAR_H: u32 = 4;
+const MOD_OVERLAY_W: u32 = 4;
+/// Index of the Tension meter, which shows gesture amount rather than audio level.
+const TENSION_METER_INDEX: usize = 8;
+/// Bar fill above which the Tension meter turns to the warning color.
+const TENSION_WARN_FILL: f32 = 0.85;
+const LOCKS_PER_ROW: usize = 5;
+/// Short modulation destination labels, in mod-matrix destination order.
+const MOD_DEST_SHORT_LABELS: [&str; 6] = ["Ten", "Dir", "Grn", "Wid", "Warp", "Feed"];
+
+/// Parameters that preset recalls can overwrite, exposed as lock toggles.
+const PRESET_LOCK_TARGETS: [(&str, &str, ClapId); 20] = [
+    ("tension", "Tension", PARAM_TENSION_ID),
+    ("tension-bias", "Bias", PARAM_TENSION_BIAS_ID),
+    ("direction", "Direction", PARAM_PULL_DIRECTION_ID),
+    ("elasticity", "Elasticity", PARAM_ELASTICITY_ID),
+    ("pull-shape", "Shape", PARAM_PULL_SHAPE_ID),
+    ("pull-division", "Division", PARAM_PULL_DIVISION_ID),
+    ("pull-quant", "Quant", PARAM_PULL_QUANTIZE_ID),
+    ("pull-latch", "Latch", PARAM_PULL_LATCH_ID),
+    ("swing", "Swing", PARAM_SWING_ID),
+    ("release-snap", "Snap", PARAM_RELEASE_SNAP_ID),
+    ("grain", "Grain", PARAM_GRAIN_CONTINUITY_ID),
+    ("warp-motion", "Warp Motion", PARAM_WARP_MOTION_ID),
+    ("warp-color", "Warp Color", PARAM_WARP_COLOR_ID),
+    ("character", "Character", PARAM_CLEAN_DIRTY_ID),
+    ("width", "Width", PARAM_WIDTH_ID),
+    ("diffusion", "Diffusion", PARAM_DIFFUSION_ID),
+    ("feedback", "Feedback", PARAM_FEEDBACK_ID),
+    ("ducking", "Ducking", PARAM_DUCKING_ID),
+    ("energy-ceiling", "Ceiling", PARAM_ENERGY_CEILING_ID),
+    ("output-trim", "Out Trim", PARAM_OUTPUT_TRIM_DB_ID),
+];
 
 const BG: Color = Color::rgb(16, 20, 26);
 const PANEL_BG: Color = Color::rgb(25, 30, 39);
@@ -75,6 +151,27 @@ const METER_HOLD: Color = Color::rgb(250, 234, 158);
 pub struct TensionFieldGui {
     window: GuiHostWindow,
     is_open: bool,
+    requested_size: Arc<RequestedSize>,
+}
+
+/// Last host-requested window size, shared with the open editor.
+#[derive(Default)]
+struct RequestedSize(AtomicU64);
+
+impl RequestedSize {
+    fn set(&self, width: u32, height: u32) {
+        self.0.store(
+            (u64::from(width) << 32) | u64::from(height),
+            Ordering::Relaxed,
+        );
+    }
+
+    fn get(&self) -> Option<(u32, u32)> {
+        match self.0.load(Ordering::Relaxed) {
+            0 => None,
+            packed => Some(((packed >> 32) as u32, packed as u32)),
+        }
+    }
 }
 
 impl TensionFieldGui {
@@ -92,8 +189,13 @@ impl TensionFieldGui {
     pub fn open(
         &mut self,
         params: &Arc<crate::params::TensionFieldParams>,
+        locks: Arc<ParamLocks>,
+        display: Arc<DisplayPrefs>,
         automation_queue: Arc<AutomationQueue>,
         status: Arc<GuiStatus>,
+        auditions: Arc<Auditions>,
+        diagnostics: Arc<PanicSlot>,
+        step_pattern: Arc<StepPattern>,
         param_requester: Option<HostParamRequester>,
     ) -> Result<(), PluginError> {
         if self.is_open {
@@ -102,11 +204,19 @@ impl TensionFieldGui {
 
         let mut state = GuiState::new(
             Arc::clone(params),
+            locks,
+            display,
             automation_queue,
             status,
+            auditions,
+            diagnostics,
+            step_pattern,
             param_requester,
         );
-        let (width, height) = state.measure_window_size();
+        // Measure before attaching the host size so the map starts from its base size.
+        state.preferred_size = state.measure_window_size();
+        state.requested_size = Arc::clone(&self.requested_size);
+        let (width, height) = state.window_size();
 
         self.window.open_parented(
             "Tension Field".to_string(),
@@ -120,7 +230,10 @@ impl TensionFieldGui {
     }
 
     /// Request a resize on the GUI thread.
+    ///
+    /// The editor grows its tension map to fill the new size.
     pub fn request_resize(&self, width: u32, height: u32) {
+        self.requested_size.set(width, height);
         self.window.request_resize(width, height);
     }
 
@@ -138,8 +251,11 @@ pub fn preferred_window_size(
 ) -> (u32, u32) {
     let mut state = GuiState::new(
         Arc::clone(params),
+        Arc::new(ParamLocks::default()),
+        Arc::new(DisplayPrefs::default()),
         Arc::new(AutomationQueue::default()),
         Arc::clone(status),
+        Arc::new(Auditions::default()),
         None,
     );
     state.measure_window_size()
@@ -222,6 +338,16 @@ impl TensionPreset {
         }
     }
 
+    fn tags(self) -> &'static [PresetTag] {
+        match self {
+            Self::PulseDrive | Self::TripletAnxiety | Self::PreDropCoil => &[PresetTag::Rhythmic],
+            Self::RatchetPressure => &[PresetTag::Rhythmic, PresetTag::Aggressive],
+            Self::ElasticSurge | Self::GhostLift | Self::AftershockTail => &[PresetTag::Ambient],
+            Self::ForwardStrain | Self::CrushSqueeze => &[PresetTag::Aggressive],
+            Self::WidePanic => &[PresetTag::Ambient, PresetTag::Aggressive],
+        }
+    }
+
     fn updates(self) -> &'static [(ClapId, f32)] {
         match self {
             Self::PulseDrive => &[
@@ -314,39 +440,84 @@ impl TensionPreset {
 
 struct GuiState {
     params: Arc<crate::params::TensionFieldParams>,
+    locks: Arc<ParamLocks>,
+    display: Arc<DisplayPrefs>,
     automation_queue: Arc<AutomationQueue>,
     automation_config: AutomationConfig,
     status: Arc<GuiStatus>,
+    auditions: Arc<Auditions>,
+    diagnostics: Arc<PanicSlot>,
+    step_pattern: Arc<StepPattern>,
     param_requester: Option<HostParamRequester>,
     active_tab: ActiveTab,
     map_dragging: bool,
-    map_trace: Vec<Point>,
-    meter_smooth: [f32; 9],
-    meter_peak_hold: [f32; 9],
+    map_trace: TimedTrace,
+    trace_smoothing: TraceSmoothing,
+    space_meter_rms: bool,
+    meter_dbfs: bool,
+    meter_modulation: bool,
+    strobe_enabled: bool,
+    strobe: StrobeFlash,
+    bar_count_visible: bool,
+    preset_filter: Option<PresetTag>,
+    coalescer: RefCell<ValueCoalescer>,
+    meter_smooth: [f32; 10],
+    meter_peak_hold: [f32; 10],
     last_frame: Instant,
     frame_dt: f32,
+    preferred_size: (u32, u32),
+    requested_size: Arc<RequestedSize>,
 }
 
 impl GuiState {
     fn new(
         params: Arc<crate::params::TensionFieldParams>,
+        locks: Arc<ParamLocks>,
+        display: Arc<DisplayPrefs>,
         automation_queue: Arc<AutomationQueue>,
         status: Arc<GuiStatus>,
+        auditions: Arc<Auditions>,
+        diagnostics: Arc<PanicSlot>,
+        step_pattern: Arc<StepPattern>,
         param_requester: Option<HostParamRequester>,
     ) -> Self {
         Self {
             params,
+            locks,
+            display,
             automation_queue,
             automation_config: AutomationConfig::default(),
             status,
+            auditions,
+            diagnostics,
+            step_pattern,
             param_requester,
             active_tab: ActiveTab::Perform,
             map_dragging: false,
-            map_trace: Vec::with_capacity(48),
-            meter_smooth: [0.0; 9],
-            meter_peak_hold: [0.0; 9],
+            map_trace: TimedTrace::new(DEFAULT_TRACE_HISTORY_SECONDS),
+            trace_smoothing: TraceSmoothing::default(),
+            space_meter_rms: true,
+            meter_dbfs: false,
+            meter_modulation: false,
+            strobe_enabled: false,
+            strobe: StrobeFlash::default(),
+            bar_count_visible: false,
+            preset_filter: None,
+            coalescer: RefCell::new(ValueCoalescer::default()),
+            meter_smooth: [0.0; 10],
+            meter_peak_hold: [0.0; 10],
             last_frame: Instant::now(),
             frame_dt: 1.0 / 60.0,
+            preferred_size: (0, 0),
+            requested_size: Arc::new(RequestedSize::default()),
+        }
+    }
+
+    /// Aspect-locked window size for the last host request, or the preferred size.
+    fn window_size(&self) -> (u32, u32) {
+        match self.requested_size.get() {
+            Some(requested) => fit_editor_size(requested, self.preferred_size),
+            None => self.preferred_size,
         }
     }
 
@@ -357,6 +528,8 @@ impl GuiState {
     }
 
     fn build_spec(&mut self) -> UiSpec<'static, GuiState> {
+        self.flush_automation();
+
         let now = Instant::now();
         self.frame_dt = (now - self.last_frame).as_secs_f32().clamp(0.0, 0.1);
         self.last_frame = now;
@@ -478,6 +651,15 @@ impl GuiState {
     }
 
     fn build_perform_tab(&self) -> Node<'static, GuiState> {
+        let (map_width, map_height) = map_size_for_window(
+            self.window_size(),
+            self.preferred_size,
+            (MAP_WIDTH, MAP_HEIGHT),
+        );
+        let map_size = Size {
+            width: map_width,
+            height: map_height,
+        };
         Node::Panel(PanelSpec {
             key: "perform-tab".to_string(),
             title: Some("Perform".to_string()),
@@ -514,13 +696,52 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "tension-floor",
+                                "Floor",
+                                PARAM_TENSION_FLOOR_ID,
+                                self.param_value(PARAM_TENSION_FLOOR_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                             self.pull_button(),
+                            self.pull_toggle_switch(),
                             self.param_toggle(
                                 "pull-latch",
                                 "Latch",
                                 PARAM_PULL_LATCH_ID,
                                 self.param_bool(PARAM_PULL_LATCH_ID, false),
                             ),
+                            self.param_toggle(
+                                "scrub",
+                                "Scrub",
+                                PARAM_SCRUB_ID,
+                                self.param_bool(PARAM_SCRUB_ID, false),
+                            ),
+                            self.param_toggle(
+                                "freeze",
+                                "Freeze",
+                                PARAM_FREEZE_ID,
+                                self.param_bool(PARAM_FREEZE_ID, false),
+                            ),
+                            self.param_toggle(
+                                "elastic-reverse",
+                                "Reverse",
+                                PARAM_ELASTIC_REVERSE_ID,
+                                self.param_bool(PARAM_ELASTIC_REVERSE_ID, false),
+                            ),
+                            self.param_toggle(
+                                "sustain-pedal",
+                                "Pedal",
+                                PARAM_SUSTAIN_PEDAL_ID,
+                                self.param_bool(PARAM_SUSTAIN_PEDAL_ID, true),
+                            ),
+                            self.param_toggle(
+                                "dual-gesture",
+                                "Dual",
+                                PARAM_DUAL_GESTURE_ID,
+                                self.param_bool(PARAM_DUAL_GESTURE_ID, false),
+                            ),
                             self.param_dropdown(
                                 "pull-quant",
                                 "Quant",
@@ -532,15 +753,30 @@ impl GuiState {
                                 self.param_value(PARAM_PULL_QUANTIZE_ID, 1.0).round() as usize,
                                 pull_quantize_value_from_index,
                             ),
+                            self.param_dropdown(
+                                "quant-multiple",
+                                "Every",
+                                PARAM_QUANTIZE_MULTIPLE_ID,
+                                (1..=16).map(|multiple| format!("x{multiple}")).collect(),
+                                (self.param_value(PARAM_QUANTIZE_MULTIPLE_ID, 1.0).round()
+                                    as usize)
+                                    .saturating_sub(1),
+                                |index| (index + 1) as f32,
+                            ),
+                            self.param_knob(
+                                "quant-strength",
+                                "Strength",
+                                PARAM_QUANTIZE_STRENGTH_ID,
+                                self.param_value(PARAM_QUANTIZE_STRENGTH_ID, 1.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                         ],
                     }),
                     self.quantize_indicator(),
                     Node::Widget(WidgetSpec {
                         key: "tension-map-widget".to_string(),
-                        size: SizeSpec::Fixed(Size {
-                            width: MAP_WIDTH,
-                            height: MAP_HEIGHT,
-                        }),
+                        size: SizeSpec::Fixed(map_size),
                         render: Box::new(|ui, rect, state: &mut GuiState| {
                             state.draw_tension_map(ui, rect);
                         }),
@@ -559,6 +795,14 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "dir-return",
+                                "Dir Return",
+                                PARAM_DIR_RETURN_ID,
+                                self.param_value(PARAM_DIR_RETURN_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                             self.param_knob(
                                 "elasticity",
                                 "Elasticity",
@@ -567,6 +811,30 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "elastic-size",
+                                "Size",
+                                PARAM_ELASTIC_SIZE_ID,
+                                self.param_value(PARAM_ELASTIC_SIZE_ID, 2.75),
+                                (0.25, 6.0),
+                                "s",
+                            ),
+                            self.param_knob(
+                                "spring",
+                                "Spring",
+                                PARAM_SPRING_ID,
+                                self.param_value(PARAM_SPRING_ID, 0.0),
+                                (-1.0, 1.0),
+                                "%",
+                            ),
+                            self.param_knob(
+                                "idle-motion",
+                                "Idle Motion",
+                                PARAM_IDLE_MOTION_ID,
+                                self.param_value(PARAM_IDLE_MOTION_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                             self.param_dropdown(
                                 "pull-shape",
                                 "Pull Shape",
@@ -575,6 +843,22 @@ impl GuiState {
                                 self.param_value(PARAM_PULL_SHAPE_ID, 1.0).round() as usize,
                                 pull_shape_value_from_index,
                             ),
+                            self.param_knob(
+                                "shape-smooth",
+                                "Shape Smooth",
+                                PARAM_SHAPE_SMOOTH_ID,
+                                self.param_value(PARAM_SHAPE_SMOOTH_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
+                            self.trace_dropdown(),
+                            self.trace_history_dropdown(),
+                            self.strobe_toggle(),
+                            Node::Label(LabelSpec {
+                                text: format!("Delay {:.0} ms", self.status.elastic_delay_ms()),
+                                size: SizeSpec::Auto,
+                                color: Some(SUBTITLE),
+                            }),
                         ],
                     }),
                     self.build_preset_bank(),
@@ -631,6 +915,26 @@ impl GuiState {
                                 (0.02, 4.0),
                                 "Hz",
                             ),
+                            self.param_dropdown(
+                                "rate-mult",
+                                "Rate Mult",
+                                PARAM_RATE_MULT_ID,
+                                RATE_MULT_LABELS.iter().map(|v| (*v).to_string()).collect(),
+                                self.param_value(PARAM_RATE_MULT_ID, 1.0).round() as usize,
+                                |index| index.min(2) as f32,
+                            ),
+                            self.param_toggle(
+                                "tempo-follow",
+                                "Tempo Follow",
+                                PARAM_TEMPO_FOLLOW_ID,
+                                self.param_bool(PARAM_TEMPO_FOLLOW_ID, false),
+                            ),
+                            self.bar_count_toggle(),
+                            Node::Label(LabelSpec {
+                                text: self.bar_count_text(),
+                                size: SizeSpec::Auto,
+                                color: Some(TITLE),
+                            }),
                         ],
                     }),
                     Node::Row(FlexSpec {
@@ -647,6 +951,22 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "humanize",
+                                "Humanize",
+                                PARAM_HUMANIZE_ID,
+                                self.param_value(PARAM_HUMANIZE_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
+                            self.param_knob(
+                                "pull-phase",
+                                "Pull Phase",
+                                PARAM_PULL_PHASE_ID,
+                                self.param_value(PARAM_PULL_PHASE_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                             self.param_knob(
                                 "rebound",
                                 "Rebound",
@@ -663,6 +983,17 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_dropdown(
+                                "release-shape",
+                                "Release Shape",
+                                PARAM_RELEASE_SHAPE_ID,
+                                RELEASE_SHAPE_LABELS
+                                    .iter()
+                                    .map(|v| (*v).to_string())
+                                    .collect(),
+                                self.param_value(PARAM_RELEASE_SHAPE_ID, 0.0).round() as usize,
+                                |index| index.min(2) as f32,
+                            ),
                         ],
                     }),
                     Node::Row(FlexSpec {
@@ -696,11 +1027,113 @@ impl GuiState {
                             ),
                         ],
                     }),
+                    Node::Row(FlexSpec {
+                        size: SizeSpec::Auto,
+                        gap: CONTROL_GAP,
+                        padding: Padding::default(),
+                        align: Align::Start,
+                        children: vec![
+                            self.param_toggle(
+                                "sequencer",
+                                "Sequencer",
+                                PARAM_SEQ_ENABLE_ID,
+                                self.param_bool(PARAM_SEQ_ENABLE_ID, false),
+                            ),
+                            self.param_dropdown(
+                                "seq-division",
+                                "Seq Division",
+                                PARAM_SEQ_DIVISION_ID,
+                                PULL_DIVISION_LABELS
+                                    .iter()
+                                    .map(|v| (*v).to_string())
+                                    .collect(),
+                                self.param_value(PARAM_SEQ_DIVISION_ID, 0.0).round() as usize,
+                                pull_division_value_from_index,
+                            ),
+                            self.param_dropdown(
+                                "seq-steps",
+                                "Seq Steps",
+                                PARAM_SEQ_STEPS_ID,
+                                SEQ_STEPS_LABELS.iter().map(|v| (*v).to_string()).collect(),
+                                self.param_value(PARAM_SEQ_STEPS_ID, 1.0).round() as usize,
+                                |index| index.min(1) as f32,
+                            ),
+                        ],
+                    }),
+                    self.build_step_grid(),
                 ],
             })),
         })
     }
 
+    /// One click target per sequencer step; each click raises the step by a quarter.
+    fn build_step_grid(&self) -> Node<'static, GuiState> {
+        let mut children = Vec::with_capacity(SEQ_STEP_COUNT);
+        for index in 0..SEQ_STEP_COUNT {
+            children.push(Node::Region(RegionSpec {
+                key: format!("seq-step-{index}"),
+                size: Size {
+                    width: SEQ_STEP_W,
+                    height: SEQ_STEP_H,
+                },
+                on_interaction: Some(Box::new(move |state: &mut GuiState, event| {
+                    if event.response.released && event.response.hovered {
+                        state.step_pattern.cycle_step(index);
+                    }
+                })),
+                draw: Some(Box::new(
+                    move |canvas, rect, state: &mut GuiState, response| {
+                        let steps = if state.param_value(PARAM_SEQ_STEPS_ID, 1.0) >= 0.5 {
+                            SEQ_STEP_COUNT
+                        } else {
+                            SEQ_STEP_COUNT / 2
+                        };
+                        let active = index < steps;
+                        canvas.fill_rect(
+                            rect,
+                            if response.hovered {
+                                Color::rgb(43, 51, 66)
+                            } else {
+                                Color::rgb(22, 27, 35)
+                            },
+                        );
+                        let intensity = state.step_pattern.state_values()[index];
+                        let fill_h = (intensity * rect.size.height as f32).round() as u32;
+                        if fill_h > 0 {
+                            canvas.fill_rect(
+                                Rect {
+                                    origin: Point {
+                                        x: rect.origin.x,
+                                        y: rect.origin.y + (rect.size.height - fill_h) as i32,
+                                    },
+                                    size: Size {
+                                        width: rect.size.width,
+                                        height: fill_h,
+                                    },
+                                },
+                                if active { ACCENT } else { PANEL_BORDER },
+                            );
+                        }
+                        // Mark each beat of four steps so the grid reads at a glance.
+                        let border = if index % 4 == 0 {
+                            SUBTITLE
+                        } else {
+                            PANEL_BORDER
+                        };
+                        canvas.stroke_rect(rect, 1, border);
+                    },
+                )),
+            }));
+        }
+        Node::Row(FlexSpec {
+            size: SizeSpec::Auto,
+            gap: 2,
+            padding: Padding::default(),
+            align: Align::Start,
+            children,
+        })
+    }
+
     fn build_tone_tab(&self) -> Node<'static, GuiState> {
         Node::Panel(PanelSpec {
             key: "tone-tab".to_string(),
@@ -730,6 +1163,22 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "transient-preserve",
+                                "Transients",
+                                PARAM_TRANSIENT_PRESERVE_ID,
+                                self.param_value(PARAM_TRANSIENT_PRESERVE_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
+                            self.param_knob(
+                                "grain-bias",
+                                "Grain Bias",
+                                PARAM_GRAIN_BIAS_ID,
+                                self.param_value(PARAM_GRAIN_BIAS_ID, 0.0),
+                                (-1.0, 1.0),
+                                "%",
+                            ),
                             self.param_knob(
                                 "pitch-coupling",
                                 "Pitch Coupling",
@@ -738,6 +1187,14 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_dropdown(
+                                "key-lock",
+                                "Key Lock",
+                                PARAM_KEY_LOCK_ID,
+                                KEY_LOCK_LABELS.iter().map(|v| (*v).to_string()).collect(),
+                                self.param_value(PARAM_KEY_LOCK_ID, 0.0).round() as usize,
+                                |index| index.min(3) as f32,
+                            ),
                             self.param_knob(
                                 "warp-motion",
                                 "Warp Motion",
@@ -746,6 +1203,33 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "warp-motion-curve",
+                                "Motion Curve",
+                                PARAM_WARP_MOTION_CURVE_ID,
+                                self.param_value(PARAM_WARP_MOTION_CURVE_ID, 1.0),
+                                (0.5, 4.0),
+                                "",
+                            ),
+                            self.param_knob(
+                                "warp-rate",
+                                "Warp Rate",
+                                PARAM_WARP_RATE_ID,
+                                self.param_value(PARAM_WARP_RATE_ID, 0.0),
+                                (0.0, 8.0),
+                                "Hz",
+                            ),
+                            self.param_dropdown(
+                                "warp-rate-mode",
+                                "Rate Mode",
+                                PARAM_WARP_RATE_MODE_ID,
+                                MOD_RATE_MODE_LABELS
+                                    .iter()
+                                    .map(|v| (*v).to_string())
+                                    .collect(),
+                                self.param_value(PARAM_WARP_RATE_MODE_ID, 0.0).round() as usize,
+                                mod_rate_mode_value_from_index,
+                            ),
                             self.param_dropdown(
                                 "warp-color",
                                 "Warp Color",
@@ -754,6 +1238,17 @@ impl GuiState {
                                 self.param_value(PARAM_WARP_COLOR_ID, 0.0).round() as usize,
                                 warp_color_value_from_index,
                             ),
+                            self.param_dropdown(
+                                "warp-high-color",
+                                "High Color",
+                                PARAM_WARP_HIGH_COLOR_ID,
+                                WARP_HIGH_COLOR_LABELS
+                                    .iter()
+                                    .map(|v| (*v).to_string())
+                                    .collect(),
+                                self.param_value(PARAM_WARP_HIGH_COLOR_ID, 0.0).round() as usize,
+                                |index| index.min(3) as f32,
+                            ),
                             self.param_dropdown(
                                 "character",
                                 "Character",
@@ -762,6 +1257,38 @@ impl GuiState {
                                 self.param_value(PARAM_CLEAN_DIRTY_ID, 0.0).round() as usize,
                                 character_mode_value_from_index,
                             ),
+                            self.param_toggle(
+                                "char-makeup",
+                                "Makeup",
+                                PARAM_CHAR_MAKEUP_ID,
+                                self.param_bool(PARAM_CHAR_MAKEUP_ID, false),
+                            ),
+                            self.param_knob(
+                                "char-drive",
+                                "Drive",
+                                PARAM_CHAR_DRIVE_ID,
+                                self.param_value(PARAM_CHAR_DRIVE_ID, 0.0),
+                                (0.0, 24.0),
+                                "dB",
+                            ),
+                            self.param_dropdown(
+                                "crush-depth",
+                                "Crush Bits",
+                                PARAM_CRUSH_DEPTH_ID,
+                                (2..=16).map(|bits| format!("{bits} bit")).collect(),
+                                (self.param_value(PARAM_CRUSH_DEPTH_ID, 7.0).round() as usize)
+                                    .saturating_sub(2),
+                                |index| (index + 2) as f32,
+                            ),
+                            self.param_dropdown(
+                                "downsample",
+                                "Downsample",
+                                PARAM_DOWNSAMPLE_ID,
+                                (1..=32).map(|factor| format!("x{factor}")).collect(),
+                                (self.param_value(PARAM_DOWNSAMPLE_ID, 1.0).round() as usize)
+                                    .saturating_sub(1),
+                                |index| (index + 1) as f32,
+                            ),
                         ],
                     }),
                     Node::Row(FlexSpec {
@@ -778,6 +1305,14 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "elastic-width",
+                                "Elastic Width",
+                                PARAM_ELASTICITY_TO_WIDTH_ID,
+                                self.param_value(PARAM_ELASTICITY_TO_WIDTH_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                             self.param_knob(
                                 "diffusion",
                                 "Diffusion",
@@ -786,6 +1321,38 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "space-size",
+                                "Size",
+                                PARAM_SPACE_SIZE_ID,
+                                self.param_value(PARAM_SPACE_SIZE_ID, 0.2),
+                                (0.0, 1.0),
+                                "%",
+                            ),
+                            self.param_knob(
+                                "space-tone",
+                                "Tone",
+                                PARAM_SPACE_TONE_ID,
+                                self.param_value(PARAM_SPACE_TONE_ID, 0.0),
+                                (-1.0, 1.0),
+                                "%",
+                            ),
+                            self.param_knob(
+                                "haas",
+                                "Haas",
+                                PARAM_HAAS_MS_ID,
+                                self.param_value(PARAM_HAAS_MS_ID, 0.0),
+                                (0.0, 20.0),
+                                "ms",
+                            ),
+                            self.param_knob(
+                                "image-rotate",
+                                "Rotate",
+                                PARAM_IMAGE_ROTATE_ID,
+                                self.param_value(PARAM_IMAGE_ROTATE_ID, 0.0),
+                                (-45.0, 45.0),
+                                "deg",
+                            ),
                             self.param_knob(
                                 "air-damping",
                                 "Air Damping",
@@ -794,14 +1361,43 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "air-freq",
+                                "Air Freq",
+                                PARAM_AIR_FREQ_HZ_ID,
+                                self.param_value(PARAM_AIR_FREQ_HZ_ID, 1000.0),
+                                (200.0, 8000.0),
+                                "Hz",
+                            ),
+                            self.param_knob(
+                                "warp-low-keep",
+                                "Low Keep",
+                                PARAM_WARP_LOW_KEEP_HZ_ID,
+                                self.param_value(PARAM_WARP_LOW_KEEP_HZ_ID, 0.0),
+                                (0.0, 1000.0),
+                                "Hz",
+                            ),
                             self.param_toggle(
                                 "air-comp",
                                 "Air Comp",
                                 PARAM_AIR_COMP_ID,
                                 self.param_bool(PARAM_AIR_COMP_ID, true),
                             ),
+                            self.param_knob(
+                                "air-comp-amount",
+                                "Air Amount",
+                                PARAM_AIR_COMP_AMOUNT_ID,
+                                self.param_value(PARAM_AIR_COMP_AMOUNT_ID, 1.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                         ],
                     }),
+                    Node::Label(LabelSpec {
+                        text: self.haas_warning_text().to_string(),
+                        size: SizeSpec::Auto,
+                        color: Some(SUBTITLE),
+                    }),
                     self.build_mod_matrix_panel(),
                 ],
             })),
@@ -837,6 +1433,42 @@ impl GuiState {
                                 (0.0, 0.7),
                                 "%",
                             ),
+                            self.param_knob(
+                                "feedback-balance",
+                                "FB Balance",
+                                PARAM_FEEDBACK_BALANCE_ID,
+                                self.param_value(PARAM_FEEDBACK_BALANCE_ID, 0.0),
+                                (-1.0, 1.0),
+                                "%",
+                            ),
+                            self.param_knob(
+                                "feedback-hpf",
+                                "FB Low Cut",
+                                PARAM_FEEDBACK_HPF_ID,
+                                self.param_value(PARAM_FEEDBACK_HPF_ID, 20.0),
+                                (20.0, 800.0),
+                                "Hz",
+                            ),
+                            self.param_knob(
+                                "feedback-tone",
+                                "FB Tone",
+                                PARAM_FEEDBACK_TONE_ID,
+                                self.param_value(PARAM_FEEDBACK_TONE_ID, 1.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
+                            self.param_toggle(
+                                "width-safety",
+                                "Width Safety",
+                                PARAM_WIDTH_SAFETY_ID,
+                                self.param_bool(PARAM_WIDTH_SAFETY_ID, false),
+                            ),
+                            self.param_toggle(
+                                "feedback-invert",
+                                "Invert",
+                                PARAM_FEEDBACK_POLARITY_ID,
+                                self.param_bool(PARAM_FEEDBACK_POLARITY_ID, false),
+                            ),
                             self.param_knob(
                                 "ducking",
                                 "Ducking",
@@ -845,6 +1477,14 @@ impl GuiState {
                                 (0.0, 1.0),
                                 "%",
                             ),
+                            self.param_knob(
+                                "pull-duck",
+                                "Pull Duck",
+                                PARAM_PULL_DUCK_ID,
+                                self.param_value(PARAM_PULL_DUCK_ID, 0.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
                             self.param_knob(
                                 "energy-ceiling",
                                 "Energy Ceiling",
@@ -861,6 +1501,81 @@ impl GuiState {
                                 (-12.0, 6.0),
                                 "dB",
                             ),
+                            self.param_dropdown(
+                                "clip-mode",
+                                "Clip",
+                                PARAM_CLIP_MODE_ID,
+                                CLIP_MODE_LABELS.iter().map(|v| (*v).to_string()).collect(),
+                                self.param_value(PARAM_CLIP_MODE_ID, 0.0).round() as usize,
+                                |index| index.min(3) as f32,
+                            ),
+                            self.param_knob(
+                                "mix",
+                                "Mix",
+                                PARAM_MIX_ID,
+                                self.param_value(PARAM_MIX_ID, 1.0),
+                                (0.0, 1.0),
+                                "%",
+                            ),
+                            self.param_toggle(
+                                "safety-limit",
+                                "Limiter",
+                                PARAM_SAFETY_LIMIT_ID,
+                                self.param_bool(PARAM_SAFETY_LIMIT_ID, false),
+                            ),
+                            self.param_knob(
+                                "limit-lookahead",
+                                "Lookahead",
+                                PARAM_LIMIT_LOOKAHEAD_MS_ID,
+                                self.param_value(PARAM_LIMIT_LOOKAHEAD_MS_ID, 0.0),
+                                (0.0, 10.0),
+                                "ms",
+                            ),
+                            self.param_toggle(
+                                "true-peak-limit",
+                                "True Peak",
+                                PARAM_LIMITER_ID,
+                                self.param_bool(PARAM_LIMITER_ID, false),
+                            ),
+                            Node::Label(LabelSpec {
+                                text: format!(
+                                    "TP GR {:.1} dB",
+                                    self.status.true_peak_reduction_db()
+                                ),
+                                size: SizeSpec::Auto,
+                                color: Some(SUBTITLE),
+                            }),
+                            self.param_knob(
+                                "input-gate",
+                                "In Gate",
+                                PARAM_INPUT_GATE_ID,
+                                self.param_value(PARAM_INPUT_GATE_ID, INPUT_GATE_OFF_DB),
+                                (INPUT_GATE_OFF_DB, -24.0),
+                                "dB",
+                            ),
+                            self.mono_button(),
+                            Node::Toggle(ToggleSpec {
+                                key: "feedback-solo".to_string(),
+                                label: "FB Solo".to_string(),
+                                value: self.auditions.feedback_solo(),
+                                control_size: Size {
+                                    width: TOGGLE_W,
+                                    height: TOGGLE_H,
+                                },
+                                size: SizeSpec::Auto,
+                                on_interaction: Some(Box::new(
+                                    |state: &mut GuiState, event: ToggleEvent| {
+                                        state.auditions.set_feedback_solo(event.value);
+                                    },
+                                )),
+                            }),
+                            self.param_toggle(
+                                "verbose-log",
+                                "Verbose Log",
+                                PARAM_VERBOSE_LOG_ID,
+                                self.param_bool(PARAM_VERBOSE_LOG_ID, false),
+                            ),
+                            self.precision_dropdown(),
                         ],
                     }),
                     Node::Label(LabelSpec {
@@ -869,15 +1584,77 @@ impl GuiState {
                         size: SizeSpec::Auto,
                         color: Some(SUBTITLE),
                     }),
+                    self.build_lock_panel(),
+                    self.debug_info_button(),
                 ],
             })),
         })
     }
 
-    fn build_mod_matrix_panel(&self) -> Node<'static, GuiState> {
+    fn haas_warning_text(&self) -> &'static str {
+        if self.param_value(PARAM_HAAS_MS_ID, 0.0) > 0.0 {
+            "Haas delay is on: the wet signal can comb-filter when summed to mono."
+        } else {
+            "Haas delays the right channel for width; check mono compatibility when using it."
+        }
+    }
+
+    fn debug_info_button(&self) -> Node<'static, GuiState> {
+        Node::Region(RegionSpec {
+            key: "copy-debug-info".to_string(),
+            size: Size {
+                width: DEBUG_BUTTON_WIDTH,
+                height: DEBUG_BUTTON_HEIGHT,
+            },
+            on_interaction: Some(Box::new(|state: &mut GuiState, event| {
+                if event.response.released && event.response.hovered {
+                    let dump = debug_dump(
+                        &state.params,
+                        state.status.sample_rate(),
+                        state.diagnostics.latest().as_ref(),
+                    );
+                    copy_to_clipboard(&dump);
+                }
+            })),
+            draw: Some(Box::new(|canvas, rect, _state: &mut GuiState, response| {
+                if !response.hovered {
+                    return;
+                }
+                canvas.fill_rect(rect, Color::rgb(32, 38, 49));
+                canvas.stroke_rect(rect, 1, PANEL_BORDER);
+                canvas.draw_text(
+                    Point {
+                        x: rect.origin.x + 6,
+                        y: rect.origin.y + 4,
+                    },
+                    "Copy Debug Info",
+                    SUBTITLE,
+                    1,
+                );
+            })),
+        })
+    }
+
+    fn build_lock_panel(&self) -> Node<'static, GuiState> {
+        let rows = PRESET_LOCK_TARGETS
+            .chunks(LOCKS_PER_ROW)
+            .map(|chunk| {
+                Node::Row(FlexSpec {
+                    size: SizeSpec::Auto,
+                    gap: CONTROL_GAP,
+                    padding: Padding::default(),
+                    align: Align::Start,
+                    children: chunk
+                        .iter()
+                        .map(|(key, label, param_id)| self.lock_toggle(key, label, *param_id))
+                        .collect(),
+                })
+            })
+            .collect();
+
         Node::Panel(PanelSpec {
-            key: "mod-matrix-panel".to_string(),
-            title: Some("DSP Mod Matrix".to_string()),
+            key: "preset-locks".to_string(),
+            title: Some("Preset Locks".to_string()),
             padding: 8,
             background: Some(Color::rgb(21, 26, 34)),
             outline: Some(PANEL_BORDER),
@@ -888,28 +1665,67 @@ impl GuiState {
                 gap: CONTROL_GAP,
                 padding: Padding::default(),
                 align: Align::Start,
-                children: vec![
-                    self.param_toggle(
-                        "mod-run",
-                        "Run",
-                        PARAM_MOD_RUN_ID,
-                        self.param_bool(PARAM_MOD_RUN_ID, true),
-                    ),
-                    self.mod_source_row(
-                        "A",
-                        PARAM_MOD_A_SHAPE_ID,
-                        PARAM_MOD_A_RATE_MODE_ID,
-                        PARAM_MOD_A_RATE_HZ_ID,
-                        PARAM_MOD_A_DIVISION_ID,
-                        PARAM_MOD_A_DEPTH_ID,
-                    ),
-                    self.mod_source_row(
-                        "B",
+                children: rows,
+            })),
+        })
+    }
+
+    fn lock_toggle(&self, key: &str, label: &str, param_id: ClapId) -> Node<'static, GuiState> {
+        Node::Toggle(ToggleSpec {
+            key: format!("lock-{key}"),
+            label: label.to_string(),
+            value: self.locks.is_locked(param_id),
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(move |state: &mut GuiState, event: ToggleEvent| {
+                state.locks.set_locked(param_id, event.value);
+            })),
+        })
+    }
+
+    fn build_mod_matrix_panel(&self) -> Node<'static, GuiState> {
+        Node::Panel(PanelSpec {
+            key: "mod-matrix-panel".to_string(),
+            title: Some("DSP Mod Matrix".to_string()),
+            padding: 8,
+            background: Some(Color::rgb(21, 26, 34)),
+            outline: Some(PANEL_BORDER),
+            header_height: None,
+            size: SizeSpec::Auto,
+            content: Box::new(Node::Column(FlexSpec {
+                size: SizeSpec::Auto,
+                gap: CONTROL_GAP,
+                padding: Padding::default(),
+                align: Align::Start,
+                children: vec![
+                    self.param_toggle(
+                        "mod-run",
+                        "Run",
+                        PARAM_MOD_RUN_ID,
+                        self.param_bool(PARAM_MOD_RUN_ID, true),
+                    ),
+                    self.mod_source_row(
+                        "A",
+                        PARAM_MOD_A_SHAPE_ID,
+                        PARAM_MOD_A_RATE_MODE_ID,
+                        PARAM_MOD_A_RATE_HZ_ID,
+                        PARAM_MOD_A_DIVISION_ID,
+                        PARAM_MOD_A_DEPTH_ID,
+                        PARAM_MOD_A_ENV_ATTACK_ID,
+                        PARAM_MOD_A_ENV_RELEASE_ID,
+                    ),
+                    self.mod_source_row(
+                        "B",
                         PARAM_MOD_B_SHAPE_ID,
                         PARAM_MOD_B_RATE_MODE_ID,
                         PARAM_MOD_B_RATE_HZ_ID,
                         PARAM_MOD_B_DIVISION_ID,
                         PARAM_MOD_B_DEPTH_ID,
+                        PARAM_MOD_B_ENV_ATTACK_ID,
+                        PARAM_MOD_B_ENV_RELEASE_ID,
                     ),
                     Node::Label(LabelSpec {
                         text: "Routes (A/B): Tension Direction Grain Width Warp Feedback"
@@ -919,6 +1735,7 @@ impl GuiState {
                     }),
                     self.mod_routes_row(
                         "A",
+                        0,
                         [
                             PARAM_MOD_A_TO_TENSION_ID,
                             PARAM_MOD_A_TO_DIRECTION_ID,
@@ -930,6 +1747,7 @@ impl GuiState {
                     ),
                     self.mod_routes_row(
                         "B",
+                        1,
                         [
                             PARAM_MOD_B_TO_TENSION_ID,
                             PARAM_MOD_B_TO_DIRECTION_ID,
@@ -939,6 +1757,7 @@ impl GuiState {
                             PARAM_MOD_B_TO_FEEDBACK_ID,
                         ],
                     ),
+                    self.mod_solo_row(),
                 ],
             })),
         })
@@ -952,6 +1771,8 @@ impl GuiState {
         rate_hz_id: ClapId,
         division_id: ClapId,
         depth_id: ClapId,
+        env_attack_id: ClapId,
+        env_release_id: ClapId,
     ) -> Node<'static, GuiState> {
         Node::Row(FlexSpec {
             size: SizeSpec::Auto,
@@ -1013,79 +1834,167 @@ impl GuiState {
                     (0.0, 1.0),
                     "%",
                 ),
+                self.param_knob(
+                    format!("mod-{label}-env-attack"),
+                    "Env Atk",
+                    env_attack_id,
+                    self.param_value(env_attack_id, 0.35),
+                    (0.1, 1000.0),
+                    "ms",
+                ),
+                self.param_knob(
+                    format!("mod-{label}-env-release"),
+                    "Env Rel",
+                    env_release_id,
+                    self.param_value(env_release_id, 0.35),
+                    (0.1, 1000.0),
+                    "ms",
+                ),
             ],
         })
     }
 
-    fn mod_routes_row(&self, label: &'static str, ids: [ClapId; 6]) -> Node<'static, GuiState> {
+    fn mod_routes_row(
+        &self,
+        label: &'static str,
+        source_index: usize,
+        ids: [ClapId; 6],
+    ) -> Node<'static, GuiState> {
+        let keys = ["tension", "direction", "grain", "width", "warp", "feedback"];
+        let mut children = vec![Node::Label(LabelSpec {
+            text: label.to_string(),
+            size: SizeSpec::Auto,
+            color: Some(TITLE),
+        })];
+        for ((key, short), id) in keys.iter().zip(MOD_DEST_SHORT_LABELS).zip(ids) {
+            children.push(Node::Column(FlexSpec {
+                size: SizeSpec::Auto,
+                gap: 2,
+                padding: Padding::default(),
+                align: Align::Start,
+                children: vec![
+                    self.param_knob(
+                        format!("route-{label}-{key}"),
+                        short,
+                        id,
+                        self.param_value(id, 0.0),
+                        (-1.0, 1.0),
+                        "",
+                    ),
+                    Self::route_activity_bar(format!("route-{label}-{key}-live"), source_index, id),
+                ],
+            }));
+        }
         Node::Row(FlexSpec {
             size: SizeSpec::Auto,
             gap: CONTROL_GAP,
             padding: Padding::default(),
             align: Align::Start,
-            children: vec![
-                Node::Label(LabelSpec {
-                    text: label.to_string(),
-                    size: SizeSpec::Auto,
-                    color: Some(TITLE),
-                }),
-                self.param_knob(
-                    format!("route-{label}-tension"),
-                    "Ten",
-                    ids[0],
-                    self.param_value(ids[0], 0.0),
-                    (-1.0, 1.0),
-                    "",
-                ),
-                self.param_knob(
-                    format!("route-{label}-direction"),
-                    "Dir",
-                    ids[1],
-                    self.param_value(ids[1], 0.0),
-                    (-1.0, 1.0),
-                    "",
-                ),
-                self.param_knob(
-                    format!("route-{label}-grain"),
-                    "Grn",
-                    ids[2],
-                    self.param_value(ids[2], 0.0),
-                    (-1.0, 1.0),
-                    "",
-                ),
-                self.param_knob(
-                    format!("route-{label}-width"),
-                    "Wid",
-                    ids[3],
-                    self.param_value(ids[3], 0.0),
-                    (-1.0, 1.0),
-                    "",
-                ),
-                self.param_knob(
-                    format!("route-{label}-warp"),
-                    "Warp",
-                    ids[4],
-                    self.param_value(ids[4], 0.0),
-                    (-1.0, 1.0),
-                    "",
-                ),
-                self.param_knob(
-                    format!("route-{label}-feedback"),
-                    "Feed",
-                    ids[5],
-                    self.param_value(ids[5], 0.0),
-                    (-1.0, 1.0),
-                    "",
-                ),
-            ],
+            children,
+        })
+    }
+
+    /// Live bar under a route knob showing the route's current contribution.
+    ///
+    /// The bar grows left or right from the centre line with the sign of source value times depth.
+    fn route_activity_bar(
+        key: String,
+        source_index: usize,
+        route_id: ClapId,
+    ) -> Node<'static, GuiState> {
+        Node::Widget(WidgetSpec {
+            key,
+            size: SizeSpec::Fixed(Size {
+                width: ROUTE_BAR_W,
+                height: ROUTE_BAR_H,
+            }),
+            render: Box::new(move |ui, rect, state: &mut GuiState| {
+                let contribution = route_contribution(
+                    state.status.mod_sources()[source_index],
+                    state.param_value(route_id, 0.0),
+                );
+                let half = rect.size.width as i32 / 2;
+                let center = rect.origin.x + half;
+                let reach = (half as f32 * contribution.abs()).round() as i32;
+                ui.canvas().fill_rect(rect, Color::rgb(32, 37, 46));
+                if reach > 0 {
+                    let x = if contribution < 0.0 {
+                        center - reach
+                    } else {
+                        center
+                    };
+                    ui.canvas().fill_rect(
+                        Rect {
+                            origin: Point {
+                                x,
+                                y: rect.origin.y,
+                            },
+                            size: Size {
+                                width: reach as u32,
+                                height: rect.size.height,
+                            },
+                        },
+                        METER_FILL,
+                    );
+                }
+                ui.canvas().draw_line(
+                    Point {
+                        x: center,
+                        y: rect.origin.y,
+                    },
+                    Point {
+                        x: center,
+                        y: rect.origin.y + rect.size.height as i32,
+                    },
+                    SUBTITLE,
+                );
+            }),
+        })
+    }
+
+    fn mod_solo_row(&self) -> Node<'static, GuiState> {
+        let soloed = self.auditions.mod_solo();
+        let mut children = vec![Node::Label(LabelSpec {
+            text: "Solo".to_string(),
+            size: SizeSpec::Auto,
+            color: Some(TITLE),
+        })];
+        for (index, label) in MOD_DEST_SHORT_LABELS.iter().enumerate() {
+            children.push(Node::Toggle(ToggleSpec {
+                key: format!("mod-solo-{index}"),
+                label: (*label).to_string(),
+                value: soloed == Some(index),
+                control_size: Size {
+                    width: TOGGLE_W,
+                    height: TOGGLE_H,
+                },
+                size: SizeSpec::Auto,
+                on_interaction: Some(Box::new(move |state: &mut GuiState, event: ToggleEvent| {
+                    state.auditions.set_mod_solo(event.value.then_some(index));
+                })),
+            }));
+        }
+        Node::Row(FlexSpec {
+            size: SizeSpec::Auto,
+            gap: CONTROL_GAP,
+            padding: Padding::default(),
+            align: Align::Start,
+            children,
         })
     }
 
     fn build_preset_bank(&self) -> Node<'static, GuiState> {
-        let mut children = Vec::with_capacity(TensionPreset::all().len());
-        for preset in TensionPreset::all() {
+        let presets = filter_presets(
+            &TensionPreset::all(),
+            TensionPreset::tags,
+            self.preset_filter,
+        );
+        let mut children = Vec::with_capacity(presets.len() + 2);
+        children.push(self.preset_filter_dropdown());
+        for preset in presets {
             children.push(self.preset_button(preset));
         }
+        children.push(self.reset_all_button());
         Node::Panel(PanelSpec {
             key: "preset-bank".to_string(),
             title: Some("Tension Bank".to_string()),
@@ -1104,6 +2013,31 @@ impl GuiState {
         })
     }
 
+    fn preset_filter_dropdown(&self) -> Node<'static, GuiState> {
+        let selected = (0..PRESET_FILTER_LABELS.len())
+            .find(|index| preset_filter_tag(*index) == self.preset_filter)
+            .unwrap_or(0);
+        Node::Dropdown(DropdownSpec {
+            key: "preset-filter".to_string(),
+            label: "Show".to_string(),
+            options: PRESET_FILTER_LABELS
+                .iter()
+                .map(|v| (*v).to_string())
+                .collect(),
+            selected,
+            control_size: Size {
+                width: DROPDOWN_W,
+                height: DROPDOWN_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: DropdownEvent| {
+                if event.response.changed {
+                    state.preset_filter = preset_filter_tag(event.selected);
+                }
+            })),
+        })
+    }
+
     fn preset_button(&self, preset: TensionPreset) -> Node<'static, GuiState> {
         Node::Button(ButtonSpec {
             key: format!("preset-{:?}", preset),
@@ -1121,6 +2055,23 @@ impl GuiState {
         })
     }
 
+    fn reset_all_button(&self) -> Node<'static, GuiState> {
+        Node::Button(ButtonSpec {
+            key: "reset-all".to_string(),
+            label: "Reset All".to_string(),
+            control_size: Size {
+                width: 124,
+                height: 26,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ButtonEvent| {
+                if event.response.clicked {
+                    state.reset_all();
+                }
+            })),
+        })
+    }
+
     fn quantize_indicator(&self) -> Node<'static, GuiState> {
         Node::Widget(WidgetSpec {
             key: "quantize-indicator".to_string(),
@@ -1148,12 +2099,16 @@ impl GuiState {
 
     fn build_meter_panel(&self) -> Node<'static, GuiState> {
         let labels = [
-            "In L", "In R", "Elastic", "Warp", "Space", "Feed", "Out L", "Out R", "Tension",
+            "In L", "In R", "Elastic", "Warp", "Space", "Feed", "Out L", "Out R", "Tension", "Duck",
         ];
-        let mut children = Vec::with_capacity(labels.len());
+        let mut children = Vec::with_capacity(labels.len() + 1);
+        let safety_limit = self.param_bool(PARAM_SAFETY_LIMIT_ID, false);
         for (index, label) in labels.iter().enumerate() {
             let meter_index = index;
             let meter_label = (*label).to_string();
+            // Out L/Out R show where the safety limiter starts holding the level.
+            let threshold = (safety_limit && (6..=7).contains(&index))
+                .then(|| level_meter_fill(LIMITER_CEILING));
             children.push(Node::Widget(WidgetSpec {
                 key: format!("meter-{meter_index}"),
                 size: SizeSpec::Fixed(Size {
@@ -1161,10 +2116,63 @@ impl GuiState {
                     height: METER_CELL_H,
                 }),
                 render: Box::new(move |ui, rect, state: &mut GuiState| {
-                    state.draw_meter_cell(ui, rect, meter_index, &meter_label);
+                    state.draw_meter_cell(ui, rect, meter_index, &meter_label, threshold);
                 }),
             }));
         }
+        children.push(Node::Toggle(ToggleSpec {
+            key: "space-meter-rms".to_string(),
+            label: "Space RMS".to_string(),
+            value: self.space_meter_rms,
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ToggleEvent| {
+                state.space_meter_rms = event.value;
+            })),
+        }));
+        children.push(Node::Toggle(ToggleSpec {
+            key: "meter-dbfs".to_string(),
+            label: "dBFS".to_string(),
+            value: self.meter_dbfs,
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ToggleEvent| {
+                state.meter_dbfs = event.value;
+            })),
+        }));
+        children.push(Node::Toggle(ToggleSpec {
+            key: "meter-modulation".to_string(),
+            label: "Mod Overlay".to_string(),
+            value: self.meter_modulation,
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ToggleEvent| {
+                state.meter_modulation = event.value;
+            })),
+        }));
+        children.push(self.meter_warn_dropdown());
+        children.push(
+            self.param_dropdown(
+                "tension-meter",
+                "Tension Src",
+                PARAM_TENSION_METER_ID,
+                TENSION_METER_LABELS
+                    .iter()
+                    .map(|v| (*v).to_string())
+                    .collect(),
+                self.param_value(PARAM_TENSION_METER_ID, 0.0).round() as usize,
+                |index| index.min(2) as f32,
+            ),
+        );
 
         Node::Panel(PanelSpec {
             key: "meters-panel".to_string(),
@@ -1201,9 +2209,14 @@ impl GuiState {
         range: (f32, f32),
         unit: &'static str,
     ) -> Node<'static, GuiState> {
+        let label = if self.locks.is_locked(param_id) {
+            format!("{label} [L]")
+        } else {
+            label.to_string()
+        };
         Node::Knob(KnobSpec {
             key: key.into(),
-            label: label.to_string(),
+            label,
             value_label: Some(format_value(value, range, unit)),
             value,
             range,
@@ -1274,6 +2287,156 @@ impl GuiState {
         })
     }
 
+    fn trace_dropdown(&self) -> Node<'static, GuiState> {
+        Node::Dropdown(DropdownSpec {
+            key: "trace-detail".to_string(),
+            label: "Trace".to_string(),
+            options: TRACE_SMOOTHING_LABELS
+                .iter()
+                .map(|v| (*v).to_string())
+                .collect(),
+            selected: self.trace_smoothing.index(),
+            control_size: Size {
+                width: DROPDOWN_W,
+                height: DROPDOWN_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: DropdownEvent| {
+                if event.response.changed {
+                    state.trace_smoothing = TraceSmoothing::from_index(event.selected);
+                }
+            })),
+        })
+    }
+
+    fn trace_history_dropdown(&self) -> Node<'static, GuiState> {
+        let current = self.map_trace.window_seconds();
+        let selected = TRACE_HISTORY_SECONDS
+            .iter()
+            .position(|seconds| *seconds == current)
+            .unwrap_or(1);
+        Node::Dropdown(DropdownSpec {
+            key: "trace-history".to_string(),
+            label: "Trail".to_string(),
+            options: TRACE_HISTORY_LABELS
+                .iter()
+                .map(|v| (*v).to_string())
+                .collect(),
+            selected,
+            control_size: Size {
+                width: DROPDOWN_W,
+                height: DROPDOWN_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: DropdownEvent| {
+                if event.response.changed {
+                    let seconds = TRACE_HISTORY_SECONDS
+                        .get(event.selected)
+                        .copied()
+                        .unwrap_or(DEFAULT_TRACE_HISTORY_SECONDS);
+                    state.map_trace.set_window_seconds(seconds);
+                }
+            })),
+        })
+    }
+
+    fn precision_dropdown(&self) -> Node<'static, GuiState> {
+        let current = self.display.precision();
+        let selected = (0..DISPLAY_PRECISION_LABELS.len())
+            .find(|index| display_precision_preset(*index) == current)
+            .unwrap_or(0);
+        Node::Dropdown(DropdownSpec {
+            key: "display-precision".to_string(),
+            label: "Readout".to_string(),
+            options: DISPLAY_PRECISION_LABELS
+                .iter()
+                .map(|v| (*v).to_string())
+                .collect(),
+            selected,
+            control_size: Size {
+                width: DROPDOWN_W,
+                height: DROPDOWN_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: DropdownEvent| {
+                if event.response.changed {
+                    state
+                        .display
+                        .set_precision(display_precision_preset(event.selected));
+                }
+            })),
+        })
+    }
+
+    fn meter_warn_dropdown(&self) -> Node<'static, GuiState> {
+        let current = self.display.meter_warn_dbfs();
+        let selected = METER_WARN_DBFS_OPTIONS
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - current).abs().total_cmp(&(*b - current).abs()))
+            .map_or(0, |(index, _)| index);
+        Node::Dropdown(DropdownSpec {
+            key: "meter-warn".to_string(),
+            label: "Warn At".to_string(),
+            options: METER_WARN_LABELS.iter().map(|v| (*v).to_string()).collect(),
+            selected,
+            control_size: Size {
+                width: DROPDOWN_W,
+                height: DROPDOWN_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: DropdownEvent| {
+                if event.response.changed {
+                    let dbfs = METER_WARN_DBFS_OPTIONS
+                        .get(event.selected)
+                        .copied()
+                        .unwrap_or(DEFAULT_METER_WARN_DBFS);
+                    state.display.set_meter_warn_dbfs(dbfs);
+                }
+            })),
+        })
+    }
+
+    fn strobe_toggle(&self) -> Node<'static, GuiState> {
+        Node::Toggle(ToggleSpec {
+            key: "map-strobe".to_string(),
+            label: "Strobe".to_string(),
+            value: self.strobe_enabled,
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ToggleEvent| {
+                state.strobe_enabled = event.value;
+            })),
+        })
+    }
+
+    fn bar_count_toggle(&self) -> Node<'static, GuiState> {
+        Node::Toggle(ToggleSpec {
+            key: "bar-count".to_string(),
+            label: "Bars".to_string(),
+            value: self.bar_count_visible,
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ToggleEvent| {
+                state.bar_count_visible = event.value;
+            })),
+        })
+    }
+
+    fn bar_count_text(&self) -> String {
+        if self.bar_count_visible {
+            format!("Bar {}", self.status.bar_count() + 1)
+        } else {
+            String::new()
+        }
+    }
+
     fn pull_button(&self) -> Node<'static, GuiState> {
         Node::Region(RegionSpec {
             key: "pull-button".to_string(),
@@ -1282,15 +2445,17 @@ impl GuiState {
                 height: BUTTON_HEIGHT,
             },
             on_interaction: Some(Box::new(|state: &mut GuiState, event| {
+                if state.display.pull_toggle() {
+                    if event.response.clicked {
+                        state.click_toggle(PARAM_PULL_TRIGGER_ID);
+                    }
+                    return;
+                }
                 if event.response.pressed {
-                    state.push_begin(PARAM_PULL_TRIGGER_ID);
-                    state.params.set_param(PARAM_PULL_TRIGGER_ID, 1.0);
-                    state.push_value(PARAM_PULL_TRIGGER_ID, 1.0);
+                    state.press_momentary(PARAM_PULL_TRIGGER_ID);
                 }
                 if event.response.released {
-                    state.params.set_param(PARAM_PULL_TRIGGER_ID, 0.0);
-                    state.push_value(PARAM_PULL_TRIGGER_ID, 0.0);
-                    state.push_end(PARAM_PULL_TRIGGER_ID);
+                    state.release_momentary(PARAM_PULL_TRIGGER_ID);
                 }
             })),
             draw: Some(Box::new(|canvas, rect, state: &mut GuiState, response| {
@@ -1317,6 +2482,45 @@ impl GuiState {
         })
     }
 
+    fn mono_button(&self) -> Node<'static, GuiState> {
+        Node::Region(RegionSpec {
+            key: "mono-monitor-button".to_string(),
+            size: Size {
+                width: BUTTON_WIDTH,
+                height: BUTTON_HEIGHT,
+            },
+            on_interaction: Some(Box::new(|state: &mut GuiState, event| {
+                if event.response.pressed {
+                    state.press_momentary(PARAM_MONO_MONITOR_ID);
+                }
+                if event.response.released {
+                    state.release_momentary(PARAM_MONO_MONITOR_ID);
+                }
+            })),
+            draw: Some(Box::new(|canvas, rect, state: &mut GuiState, response| {
+                let active = response.active || state.param_bool(PARAM_MONO_MONITOR_ID, false);
+                let fill = if active {
+                    ACCENT
+                } else if response.hovered {
+                    Color::rgb(62, 74, 94)
+                } else {
+                    Color::rgb(44, 52, 66)
+                };
+                canvas.fill_rect(rect, fill);
+                canvas.stroke_rect(rect, 1, PANEL_BORDER);
+                canvas.draw_text(
+                    Point {
+                        x: rect.origin.x + 14,
+                        y: rect.origin.y + 8,
+                    },
+                    "HOLD FOR MONO",
+                    Color::rgb(12, 14, 20),
+                    1,
+                );
+            })),
+        })
+    }
+
     fn draw_tension_map(&mut self, ui: &mut Ui<'_>, rect: Rect) {
         {
             let canvas = ui.canvas();
@@ -1382,16 +2586,37 @@ impl GuiState {
                 as i32;
         let point = Point { x: px, y: py };
 
-        self.map_trace.push(point);
-        if self.map_trace.len() > 36 {
-            self.map_trace.remove(0);
-        }
+        self.map_trace
+            .push((point.x as f32, point.y as f32), self.frame_dt);
 
         {
+            let raw_trace: Vec<(f32, f32)> = self.map_trace.points().collect();
+            let trace = decimate_and_smooth(&raw_trace, self.trace_smoothing);
+            let strobe = if self.strobe_enabled {
+                self.strobe
+                    .advance(self.status.gesture_phase(), self.frame_dt)
+            } else {
+                0.0
+            };
             let canvas = ui.canvas();
-            for pair in self.map_trace.windows(2) {
+            if strobe > 0.0 {
+                let alpha = (strobe * 56.0).round() as u8;
+                canvas.fill_rect(rect, Color::rgba(247, 217, 143, alpha));
+                canvas.stroke_rect(rect, 1, Color::rgba(247, 217, 143, alpha.saturating_mul(3)));
+            }
+            for pair in trace.windows(2) {
                 if let [a, b] = pair {
-                    canvas.draw_line(*a, *b, MAP_TRACE);
+                    canvas.draw_line(
+                        Point {
+                            x: a.0.round() as i32,
+                            y: a.1.round() as i32,
+                        },
+                        Point {
+                            x: b.0.round() as i32,
+                            y: b.1.round() as i32,
+                        },
+                        MAP_TRACE,
+                    );
                 }
             }
 
@@ -1460,21 +2685,47 @@ impl GuiState {
         }
     }
 
-    fn draw_meter_cell(&mut self, ui: &mut Ui<'_>, rect: Rect, index: usize, label: &str) {
-        let values = [
+    fn draw_meter_cell(
+        &mut self,
+        ui: &mut Ui<'_>,
+        rect: Rect,
+        index: usize,
+        label: &str,
+        threshold: Option<f32>,
+    ) {
+        // In dBFS mode the stage meters show absolute output level on a
+        // floor-to-0 dBFS scale instead of how much each stage changed the signal.
+        let stage_dbfs = self.status.stage_levels().map(peak_to_dbfs);
+        let stage_fill =
+            |index: usize| (stage_dbfs[index] / -METER_FLOOR_DBFS + 1.0).clamp(0.0, 1.0);
+        let dbfs_stage = (2..=5).contains(&index) && self.meter_dbfs;
+        let mut values = [
             self.status.input_left(),
             self.status.input_right(),
             self.status.elastic_activity(),
             self.status.warp_activity(),
-            self.status.space_activity(),
+            if self.space_meter_rms {
+                self.status.space_rms()
+            } else {
+                self.status.space_activity()
+            },
             self.status.feedback_activity(),
             self.status.output_left(),
             self.status.output_right(),
             self.status.tension_activity(),
+            self.status.duck_level(),
         ];
+        if dbfs_stage {
+            values[index] = stage_fill(index - 2);
+        }
 
-        self.meter_smooth[index] +=
-            (values[index] - self.meter_smooth[index]) * (self.frame_dt * 12.0);
+        // Skip editor ballistics when the audio thread already smooths the stored values.
+        if self.status.meter_smoothing() > 0.0 {
+            self.meter_smooth[index] = values[index];
+        } else {
+            self.meter_smooth[index] +=
+                (values[index] - self.meter_smooth[index]) * (self.frame_dt * 12.0);
+        }
         self.meter_peak_hold[index] = if values[index] >= self.meter_peak_hold[index] {
             values[index]
         } else {
@@ -1509,7 +2760,15 @@ impl GuiState {
                     height: fill_h,
                 },
             };
-            let color = if value > 0.85 { METER_WARN } else { METER_FILL };
+            let warn_fill = if index == TENSION_METER_INDEX {
+                TENSION_WARN_FILL
+            } else {
+                dbfs_meter_fill(self.display.meter_warn_dbfs(), dbfs_stage)
+            };
+            let color = match meter_zone(value, warn_fill) {
+                MeterZone::Warn => METER_WARN,
+                MeterZone::Normal => METER_FILL,
+            };
             ui.canvas().fill_rect(fill_rect, color);
         }
 
@@ -1527,6 +2786,60 @@ impl GuiState {
             METER_HOLD,
         );
 
+        if let Some(threshold) = threshold {
+            let threshold_y = bar_rect.origin.y + bar_rect.size.height as i32
+                - (bar_rect.size.height as f32 * threshold.clamp(0.0, 1.0)).round() as i32;
+            ui.canvas().draw_line(
+                Point {
+                    x: bar_rect.origin.x,
+                    y: threshold_y,
+                },
+                Point {
+                    x: bar_rect.origin.x + bar_rect.size.width as i32,
+                    y: threshold_y,
+                },
+                METER_WARN,
+            );
+        }
+
+        // The modulation overlay is a bipolar strip on the right edge, growing
+        // up or down from the middle with the stage's current mod offset.
+        if self.meter_modulation && (2..=5).contains(&index) {
+            let modulation = self.status.stage_modulation()[index - 2].clamp(-1.0, 1.0);
+            let half = bar_rect.size.height as i32 / 2;
+            let center = bar_rect.origin.y + half;
+            let reach = (half as f32 * modulation.abs()).round() as i32;
+            let strip_x = bar_rect.origin.x + bar_rect.size.width as i32 - MOD_OVERLAY_W as i32;
+            if reach > 0 {
+                let y = if modulation > 0.0 {
+                    center - reach
+                } else {
+                    center
+                };
+                ui.canvas().fill_rect(
+                    Rect {
+                        origin: Point { x: strip_x, y },
+                        size: Size {
+                            width: MOD_OVERLAY_W,
+                            height: reach as u32,
+                        },
+                    },
+                    ACCENT,
+                );
+            }
+            ui.canvas().draw_line(
+                Point {
+                    x: strip_x,
+                    y: center,
+                },
+                Point {
+                    x: strip_x + MOD_OVERLAY_W as i32,
+                    y: center,
+                },
+                SUBTITLE,
+            );
+        }
+
         ui.text_with_color(
             Point {
                 x: rect.origin.x,
@@ -1535,6 +2848,22 @@ impl GuiState {
             label,
             SUBTITLE,
         );
+        if dbfs_stage {
+            let db = stage_dbfs[index - 2];
+            let text = if db <= METER_FLOOR_DBFS {
+                "-inf".to_string()
+            } else {
+                format!("{db:.1}")
+            };
+            ui.text_with_color(
+                Point {
+                    x: bar_rect.origin.x + 4,
+                    y: bar_rect.origin.y + 4,
+                },
+                &text,
+                SUBTITLE,
+            );
+        }
     }
 
     fn update_map_from_pointer(&self, pointer: Point, rect: Rect) {
@@ -1553,13 +2882,64 @@ impl GuiState {
         self.push_value(param_id, value);
     }
 
+    fn press_momentary(&self, param_id: ClapId) {
+        self.push_begin(param_id);
+        let value = apply_momentary(&self.params, param_id, true);
+        self.push_value(param_id, value);
+    }
+
+    fn release_momentary(&self, param_id: ClapId) {
+        let value = apply_momentary(&self.params, param_id, false);
+        self.push_value(param_id, value);
+        self.push_end(param_id);
+    }
+
+    fn click_toggle(&self, param_id: ClapId) {
+        self.push_begin(param_id);
+        let value = apply_click_toggle(&self.params, param_id);
+        self.push_value(param_id, value);
+        self.push_end(param_id);
+    }
+
+    fn pull_toggle_switch(&self) -> Node<'static, GuiState> {
+        Node::Toggle(ToggleSpec {
+            key: "pull-toggle-mode".to_string(),
+            label: "Click Pull".to_string(),
+            value: self.display.pull_toggle(),
+            control_size: Size {
+                width: TOGGLE_W,
+                height: TOGGLE_H,
+            },
+            size: SizeSpec::Auto,
+            on_interaction: Some(Box::new(|state: &mut GuiState, event: ToggleEvent| {
+                state.display.set_pull_toggle(event.value);
+                // Leaving toggle mode must not strand a latched pull with no button to release it.
+                if !event.value && state.param_bool(PARAM_PULL_TRIGGER_ID, false) {
+                    state.click_toggle(PARAM_PULL_TRIGGER_ID);
+                }
+            })),
+        })
+    }
+
     fn apply_preset(&mut self, preset: TensionPreset) {
-        for (param_id, value) in preset.updates() {
-            self.push_begin(*param_id);
-            self.params.set_param(*param_id, *value);
-            self.push_value(*param_id, *value);
-            self.push_end(*param_id);
-        }
+        apply_unlocked_updates(
+            &self.params,
+            &self.locks,
+            preset.updates(),
+            |param_id, value| {
+                self.push_begin(param_id);
+                self.push_value(param_id, value);
+                self.push_end(param_id);
+            },
+        );
+    }
+
+    fn reset_all(&mut self) {
+        reset_all(&self.params, |param_id, value| {
+            self.push_begin(param_id);
+            self.push_value(param_id, value);
+            self.push_end(param_id);
+        });
     }
 
     fn request_flush(&self) {
@@ -1568,22 +2948,92 @@ impl GuiState {
         }
     }
 
+    /// Send coalesced values to the automation queue and request at most one flush.
+    fn flush_automation(&self) {
+        let mut coalescer = self.coalescer.borrow_mut();
+        coalescer.drain(|param_id, value| {
+            self.automation_queue
+                .push_value(&self.automation_config, param_id, value as f64);
+        });
+        if coalescer.take_flush_request() {
+            self.request_flush();
+        }
+    }
+
+    /// Queue any pending coalesced value for `param_id` ahead of a gesture event.
+    fn flush_pending_value(&self, param_id: ClapId) {
+        if let Some(value) = self.coalescer.borrow_mut().take(param_id) {
+            self.automation_queue
+                .push_value(&self.automation_config, param_id, value as f64);
+        }
+    }
+
     fn push_value(&self, param_id: ClapId, value: f32) {
-        self.automation_queue
-            .push_value(&self.automation_config, param_id, value as f64);
-        self.request_flush();
+        self.coalescer.borrow_mut().push(param_id, value);
     }
 
     fn push_begin(&self, param_id: ClapId) {
+        self.flush_pending_value(param_id);
         self.automation_queue
             .push_gesture_begin(&self.automation_config, param_id);
-        self.request_flush();
+        self.coalescer.borrow_mut().request_flush();
     }
 
     fn push_end(&self, param_id: ClapId) {
+        self.flush_pending_value(param_id);
         self.automation_queue
             .push_gesture_end(&self.automation_config, param_id);
-        self.request_flush();
+        self.coalescer.borrow_mut().request_flush();
+    }
+}
+
+/// Place `text` on the Windows clipboard as Unicode text.
+///
+/// Talks to the Win32 clipboard directly so the GUI thread never waits on a
+/// child process; if another app holds the clipboard open the copy is skipped.
+fn copy_to_clipboard(text: &str) {
+    use std::ffi::c_void;
+
+    const CF_UNICODETEXT: u32 = 13;
+    const GMEM_MOVEABLE: u32 = 0x0002;
+
+    #[link(name = "user32")]
+    unsafe extern "system" {
+        fn OpenClipboard(owner: *mut c_void) -> i32;
+        fn EmptyClipboard() -> i32;
+        fn SetClipboardData(format: u32, memory: *mut c_void) -> *mut c_void;
+        fn CloseClipboard() -> i32;
+    }
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GlobalAlloc(flags: u32, bytes: usize) -> *mut c_void;
+        fn GlobalLock(memory: *mut c_void) -> *mut c_void;
+        fn GlobalUnlock(memory: *mut c_void) -> i32;
+        fn GlobalFree(memory: *mut c_void) -> *mut c_void;
+    }
+
+    let wide: Vec<u16> = text.encode_utf16().chain(std::iter::once(0)).collect();
+    // The buffer is sized for `wide` including its NUL, written only while locked,
+    // and owned by the clipboard once `SetClipboardData` accepts it; every other
+    // path frees it before the clipboard is closed again.
+    unsafe {
+        if OpenClipboard(std::ptr::null_mut()) == 0 {
+            return;
+        }
+        let memory = GlobalAlloc(GMEM_MOVEABLE, wide.len() * size_of::<u16>());
+        if !memory.is_null() {
+            let locked = GlobalLock(memory).cast::<u16>();
+            if locked.is_null() {
+                GlobalFree(memory);
+            } else {
+                std::ptr::copy_nonoverlapping(wide.as_ptr(), locked, wide.len());
+                GlobalUnlock(memory);
+                if EmptyClipboard() == 0 || SetClipboardData(CF_UNICODETEXT, memory).is_null() {
+                    GlobalFree(memory);
+                }
+            }
+        }
+        CloseClipboard();
     }
 }
 
@@ -1596,6 +3046,9 @@ fn format_value(value: f32, range: (f32, f32), unit: &'static str) -> String {
         }
         "Hz" => format!("{value:.2} Hz"),
         "dB" => format!("{value:+.1} dB"),
+        "ms" => format!("{value:.1} ms"),
+        "deg" => format!("{value:+.1}°"),
+        "s" => format!("{value:.2} s"),
         _ => format!("{value:.2}"),
     }
 }
@@ -0,0 +1,54 @@
+//! Throttled host-log reporting for audio-thread diagnostics.
+
+/// Sample-counted rate limiter so repeated events log at most once per interval.
+pub(crate) struct LogThrottle {
+    interval_samples: u64,
+    elapsed_samples: u64,
+}
+
+impl LogThrottle {
+    /// Create a throttle allowing one message per `interval_seconds`.
+    pub(crate) fn new(sample_rate: f32, interval_seconds: f32) -> Self {
+        let interval_samples = (sample_rate.max(1.0) * interval_seconds.max(0.0)) as u64;
+        Self {
+            interval_samples,
+            elapsed_samples: interval_samples,
+        }
+    }
+
+    /// Advance by `frames` and return whether an `event` in this block may be logged.
+    pub(crate) fn allow(&mut self, frames: usize, event: bool) -> bool {
+        self.elapsed_samples = self.elapsed_samples.saturating_add(frames as u64);
+        if event && self.elapsed_samples >= self.interval_samples {
+            self.elapsed_samples = 0;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogThrottle;
+
+    #[test]
+    fn throttle_allows_at_most_one_message_per_interval() {
+        let mut throttle = LogThrottle::new(48_000.0, 1.0);
+        let mut allowed = 0;
+        // Ten seconds of 64-frame blocks, every block reporting an event.
+        for _ in 0..(48_000 * 10 / 64) {
+            if throttle.allow(64, true) {
+                allowed += 1;
+            }
+        }
+        assert_eq!(allowed, 10);
+    }
+
+    #[test]
+    fn throttle_ignores_quiet_blocks_and_fires_first_event() {
+        let mut throttle = LogThrottle::new(48_000.0, 1.0);
+        assert!(!throttle.allow(512, false));
+        assert!(throttle.allow(512, true));
+        assert!(!throttle.allow(512, true));
+    }
+}
@@ -0,0 +1,61 @@
+//! Aspect-locked editor sizing for host-driven resizes.
+
+/// Fit a host-requested size to the preferred aspect ratio.
+///
+/// The preferred size is the content-measured layout, so it is also the
+/// minimum: anything smaller would clip controls.
+pub(crate) fn fit_editor_size(requested: (u32, u32), preferred: (u32, u32)) -> (u32, u32) {
+    let preferred_width = preferred.0.max(1) as f32;
+    let preferred_height = preferred.1.max(1) as f32;
+    let scale = (requested.0 as f32 / preferred_width)
+        .min(requested.1 as f32 / preferred_height)
+        .max(1.0);
+    (
+        (preferred_width * scale).round() as u32,
+        (preferred_height * scale).round() as u32,
+    )
+}
+
+/// Grow the tension map by the space a resized window adds over the preferred layout.
+///
+/// Every other control keeps its size, so the map absorbing the difference makes
+/// the measured layout match the window exactly.
+pub(crate) fn map_size_for_window(
+    window: (u32, u32),
+    preferred: (u32, u32),
+    base_map: (u32, u32),
+) -> (u32, u32) {
+    (
+        base_map.0 + window.0.saturating_sub(preferred.0),
+        base_map.1 + window.1.saturating_sub(preferred.1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fit_editor_size, map_size_for_window};
+
+    const PREFERRED: (u32, u32) = (1280, 860);
+    const MAP: (u32, u32) = (620, 360);
+
+    #[test]
+    fn requested_sizes_keep_aspect_and_grow_the_map() {
+        let large = fit_editor_size((1920, 1290), PREFERRED);
+        assert_eq!(large, (1920, 1290));
+        assert_eq!(map_size_for_window(large, PREFERRED, MAP), (1260, 790));
+
+        // A wide request is limited by its height so the aspect ratio holds.
+        let wide = fit_editor_size((2560, 946), PREFERRED);
+        assert_eq!(wide, (1408, 946));
+        let wide_map = map_size_for_window(wide, PREFERRED, MAP);
+        assert_eq!(wide_map.0 - MAP.0, wide.0 - PREFERRED.0);
+        assert_eq!(wide_map.1 - MAP.1, wide.1 - PREFERRED.1);
+    }
+
+    #[test]
+    fn requests_below_the_preferred_size_snap_to_it() {
+        let small = fit_editor_size((800, 600), PREFERRED);
+        assert_eq!(small, PREFERRED);
+        assert_eq!(map_size_for_window(small, PREFERRED, MAP), MAP);
+    }
+}
@@ -11,36 +11,71 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 use toybox::clack_common::plugin::features as plugin_features;
 use toybox::clack_extensions::audio_ports::*;
+use toybox::clack_extensions::audio_ports_config::*;
 #[cfg(target_os = "windows")]
 use toybox::clack_extensions::gui::{
     GuiApiType, GuiConfiguration, GuiSize, PluginGui, PluginGuiImpl, Window,
 };
+use toybox::clack_extensions::latency::{PluginLatency, PluginLatencyImpl};
+use toybox::clack_extensions::log::{HostLog, LogSeverity};
+use toybox::clack_extensions::note_ports::{
+    NoteDialect, NoteDialects, NotePortInfo, NotePortInfoWriter, PluginNotePorts,
+    PluginNotePortsImpl,
+};
 use toybox::clack_extensions::params::*;
 use toybox::clack_extensions::state::{PluginState, PluginStateImpl};
+use toybox::clack_extensions::tail::{PluginTail, PluginTailImpl, TailLength};
+use toybox::clack_plugin::events::Event;
 use toybox::clack_plugin::events::event_types::{TransportEvent, TransportFlags};
+use toybox::clack_plugin::events::spaces::CoreEventSpace;
 use toybox::clack_plugin::prelude::*;
 use toybox::clack_plugin::stream::{InputStream, OutputStream};
 use toybox::clap::automation::{AutomationDrainBuffer, AutomationQueue};
 use toybox::clap::params::apply_param_events;
 
+mod audition;
 mod clock;
+#[cfg(any(target_os = "windows", test))]
+mod coalesce;
+mod diagnostics;
 mod dsp;
 mod gesture;
 #[cfg(target_os = "windows")]
 mod gui;
+mod host_log;
+#[cfg(any(target_os = "windows", test))]
+mod layout;
 mod mod_matrix;
+pub mod offline;
 mod params;
 mod state;
-
-use dsp::{RenderReport, TensionFieldEngine};
+#[cfg(any(target_os = "windows", test))]
+mod strobe;
+#[cfg(any(target_os = "windows", test))]
+mod trace;
+
+use audition::Auditions;
+use diagnostics::PanicSlot;
+use dsp::{NoteTrigger, RenderReport, TensionFieldEngine, TriggerSource};
 #[cfg(target_os = "windows")]
 use gui::TensionFieldGui;
+use host_log::LogThrottle;
 use params::{
-    TensionFieldParams, apply_state_values, param_count, state_values, text_to_value,
-    value_to_text, write_param_info,
+    DisplayPrefs, ParamLocks, StepPattern, TensionFieldParams, apply_state_values, param_count,
+    state_values, text_to_value, value_to_text, write_param_info,
 };
 use state::{PluginStateSnapshot, read_snapshot, write_snapshot};
 
+/// Most note gate changes kept per block before later ones are folded together.
+const MAX_NOTE_TRIGGERS: usize = 64;
+/// MIDI controller number of the sustain pedal.
+const MIDI_CC_SUSTAIN: u8 = 64;
+/// Minimum number of frames folded into one GUI meter update.
+const METER_PUBLISH_FRAMES: usize = 32;
+/// Audio-thread meter smoothing used by builds without the editor's own ballistics.
+#[cfg(not(target_os = "windows"))]
+const HEADLESS_METER_SMOOTHING: f32 = 0.9;
+
 /// CLAP plugin type for Tension Field.
 pub struct TensionFieldPlugin;
 
@@ -55,8 +90,12 @@ impl Plugin for TensionFieldPlugin {
     ) {
         builder
             .register::<PluginAudioPorts>()
+            .register::<PluginAudioPortsConfig>()
             .register::<PluginParams>()
-            .register::<PluginState>();
+            .register::<PluginState>()
+            .register::<PluginLatency>()
+            .register::<PluginTail>()
+            .register::<PluginNotePorts>();
         #[cfg(target_os = "windows")]
         {
             builder.register::<PluginGui>();
@@ -76,8 +115,14 @@ impl DefaultPluginFactory for TensionFieldPlugin {
     fn new_shared(_host: HostSharedHandle<'_>) -> Result<Self::Shared<'_>, PluginError> {
         Ok(TensionFieldShared {
             params: Arc::new(TensionFieldParams::new()),
+            locks: Arc::new(ParamLocks::default()),
+            display: Arc::new(DisplayPrefs::default()),
             automation_queue: Arc::new(AutomationQueue::default()),
-            status: Arc::new(GuiStatus::default()),
+            status: Arc::new(new_gui_status()),
+            auditions: Arc::new(Auditions::default()),
+            diagnostics: Arc::new(PanicSlot::default()),
+            step_pattern: Arc::new(StepPattern::default()),
+            latency_samples: AtomicU32::new(0),
         })
     }
 
@@ -94,6 +139,7 @@ impl DefaultPluginFactory for TensionFieldPlugin {
                 gui_size: GuiSize { width, height },
                 gui: TensionFieldGui::default(),
                 automation_drain: AutomationDrainBuffer::default(),
+                mono_ports: false,
             })
         }
         #[cfg(not(target_os = "windows"))]
@@ -102,6 +148,7 @@ impl DefaultPluginFactory for TensionFieldPlugin {
             Ok(TensionFieldMainThread {
                 shared,
                 automation_drain: AutomationDrainBuffer::default(),
+                mono_ports: false,
             })
         }
     }
@@ -119,75 +166,189 @@ pub struct GuiStatus {
     output_left: AtomicU32,
     output_right: AtomicU32,
     tension_activity: AtomicU32,
+    duck_level: AtomicU32,
+    space_rms: AtomicU32,
+    elastic_level: AtomicU32,
+    warp_level: AtomicU32,
+    space_level: AtomicU32,
+    feedback_level: AtomicU32,
+    gesture_phase: AtomicU32,
+    bar_count: AtomicU32,
+    mod_sources: [AtomicU32; 2],
+    elastic_delay_ms: AtomicU32,
+    stage_modulation: [AtomicU32; 4],
+    true_peak_reduction_db: AtomicU32,
+    sample_rate: AtomicU32,
+    meter_smoothing: AtomicU32,
 }
 
 impl GuiStatus {
-    fn update(&self, report: RenderReport) {
-        self.input_left
-            .store(f32_to_bits(report.input_left), Ordering::Relaxed);
-        self.input_right
-            .store(f32_to_bits(report.input_right), Ordering::Relaxed);
-        self.elastic_activity
-            .store(f32_to_bits(report.elastic_activity), Ordering::Relaxed);
-        self.warp_activity
-            .store(f32_to_bits(report.warp_activity), Ordering::Relaxed);
-        self.space_activity
-            .store(f32_to_bits(report.space_activity), Ordering::Relaxed);
-        self.feedback_activity
-            .store(f32_to_bits(report.feedback_activity), Ordering::Relaxed);
-        self.output_left
-            .store(f32_to_bits(report.output_left), Ordering::Relaxed);
-        self.output_right
-            .store(f32_to_bits(report.output_right), Ordering::Relaxed);
-        self.tension_activity
-            .store(f32_to_bits(report.tension_activity), Ordering::Relaxed);
+    fn set_sample_rate(&self, sample_rate: f32) {
+        self.sample_rate
+            .store(f32_to_bits(sample_rate), Ordering::Relaxed);
     }
 
     #[cfg(target_os = "windows")]
+    pub(crate) fn sample_rate(&self) -> f32 {
+        bits_to_f32(self.sample_rate.load(Ordering::Relaxed))
+    }
+
+    /// Enable exponential smoothing of meter writes (0 = raw block peaks).
+    ///
+    /// `amount` is the share of the previous reading kept on each published update.
+    /// The editor applies its own ballistics, so only builds without it turn this on.
+    #[cfg(any(not(target_os = "windows"), test))]
+    pub(crate) fn set_meter_smoothing(&self, amount: f32) {
+        self.meter_smoothing
+            .store(f32_to_bits(amount.clamp(0.0, 0.99)), Ordering::Relaxed);
+    }
+
+    /// Audio-thread meter smoothing amount; non-zero means stored values are already smoothed.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn meter_smoothing(&self) -> f32 {
+        bits_to_f32(self.meter_smoothing.load(Ordering::Relaxed))
+    }
+
+    fn update(&self, report: RenderReport) {
+        let smoothing = bits_to_f32(self.meter_smoothing.load(Ordering::Relaxed));
+        let store = |slot: &AtomicU32, value: f32| {
+            let previous = bits_to_f32(slot.load(Ordering::Relaxed));
+            let smoothed = value + (previous - value) * smoothing;
+            slot.store(f32_to_bits(smoothed), Ordering::Relaxed);
+        };
+        store(&self.input_left, report.input_left);
+        store(&self.input_right, report.input_right);
+        store(&self.elastic_activity, report.elastic_activity);
+        store(&self.warp_activity, report.warp_activity);
+        store(&self.space_activity, report.space_activity);
+        store(&self.space_rms, report.space_rms);
+        store(&self.feedback_activity, report.feedback_activity);
+        store(&self.output_left, report.output_left);
+        store(&self.output_right, report.output_right);
+        store(&self.tension_activity, report.tension_activity);
+        store(&self.duck_level, report.duck_level);
+        store(&self.elastic_level, report.elastic_level);
+        store(&self.warp_level, report.warp_level);
+        store(&self.space_level, report.space_level);
+        store(&self.feedback_level, report.feedback_level);
+        store(&self.true_peak_reduction_db, report.true_peak_reduction_db);
+        self.gesture_phase
+            .store(f32_to_bits(report.gesture_phase), Ordering::Relaxed);
+        self.bar_count.store(report.bar_count, Ordering::Relaxed);
+        for (slot, value) in self.mod_sources.iter().zip(report.mod_sources) {
+            slot.store(f32_to_bits(value), Ordering::Relaxed);
+        }
+        self.elastic_delay_ms
+            .store(f32_to_bits(report.elastic_delay_ms), Ordering::Relaxed);
+        for (slot, value) in self.stage_modulation.iter().zip(report.stage_modulation) {
+            slot.store(f32_to_bits(value), Ordering::Relaxed);
+        }
+    }
+
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn input_left(&self) -> f32 {
         bits_to_f32(self.input_left.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn input_right(&self) -> f32 {
         bits_to_f32(self.input_right.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn elastic_activity(&self) -> f32 {
         bits_to_f32(self.elastic_activity.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn warp_activity(&self) -> f32 {
         bits_to_f32(self.warp_activity.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn space_activity(&self) -> f32 {
         bits_to_f32(self.space_activity.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn space_rms(&self) -> f32 {
+        bits_to_f32(self.space_rms.load(Ordering::Relaxed))
+    }
+
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn feedback_activity(&self) -> f32 {
         bits_to_f32(self.feedback_activity.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn output_left(&self) -> f32 {
         bits_to_f32(self.output_left.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn output_right(&self) -> f32 {
         bits_to_f32(self.output_right.load(Ordering::Relaxed))
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn tension_activity(&self) -> f32 {
         bits_to_f32(self.tension_activity.load(Ordering::Relaxed))
     }
 
+    /// Ducking detector level, following the sidechain when one is connected.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn duck_level(&self) -> f32 {
+        bits_to_f32(self.duck_level.load(Ordering::Relaxed))
+    }
+
+    /// Absolute peak output levels of the elastic, warp, space, and feedback stages.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn stage_levels(&self) -> [f32; 4] {
+        [
+            bits_to_f32(self.elastic_level.load(Ordering::Relaxed)),
+            bits_to_f32(self.warp_level.load(Ordering::Relaxed)),
+            bits_to_f32(self.space_level.load(Ordering::Relaxed)),
+            bits_to_f32(self.feedback_level.load(Ordering::Relaxed)),
+        ]
+    }
+
+    #[cfg(target_os = "windows")]
+    pub(crate) fn gesture_phase(&self) -> f32 {
+        bits_to_f32(self.gesture_phase.load(Ordering::Relaxed))
+    }
+
+    #[cfg(target_os = "windows")]
+    pub(crate) fn bar_count(&self) -> u32 {
+        self.bar_count.load(Ordering::Relaxed)
+    }
+
+    /// Latest mod matrix source A and B values (-1..1).
+    #[cfg(target_os = "windows")]
+    pub(crate) fn mod_sources(&self) -> [f32; 2] {
+        self.mod_sources
+            .each_ref()
+            .map(|slot| bits_to_f32(slot.load(Ordering::Relaxed)))
+    }
+
+    #[cfg(target_os = "windows")]
+    pub(crate) fn elastic_delay_ms(&self) -> f32 {
+        bits_to_f32(self.elastic_delay_ms.load(Ordering::Relaxed))
+    }
+
+    /// Deepest true-peak limiter gain reduction in dB over the latest block.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn true_peak_reduction_db(&self) -> f32 {
+        bits_to_f32(self.true_peak_reduction_db.load(Ordering::Relaxed))
+    }
+
+    /// Latest modulation on the elastic, warp, space, and feedback stage parameters.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn stage_modulation(&self) -> [f32; 4] {
+        self.stage_modulation
+            .each_ref()
+            .map(|slot| bits_to_f32(slot.load(Ordering::Relaxed)))
+    }
+
     fn snapshot(&self) -> [f32; state::METER_COUNT] {
         [
             bits_to_f32(self.input_left.load(Ordering::Relaxed)),
@@ -228,10 +389,22 @@ impl GuiStatus {
 pub struct TensionFieldShared {
     /// Parameter storage shared between main and audio threads.
     params: Arc<TensionFieldParams>,
+    /// Preset-lock flags edited by the GUI and persisted with plugin state.
+    locks: Arc<ParamLocks>,
+    /// User display preferences edited by the GUI and persisted with plugin state.
+    display: Arc<DisplayPrefs>,
     /// Pending GUI automation events waiting for host flush.
     automation_queue: Arc<AutomationQueue>,
     /// Metering/status values produced by the audio thread.
     status: Arc<GuiStatus>,
+    /// Modulation and feedback solos set from the editor as audition aids.
+    auditions: Arc<Auditions>,
+    /// Last snapshot taken when the audio thread sanitized non-finite audio.
+    diagnostics: Arc<PanicSlot>,
+    /// Gesture sequencer pattern edited by the GUI and persisted with plugin state.
+    step_pattern: Arc<StepPattern>,
+    /// Latency reported to the host, fixed at activation from the limiter lookaheads.
+    latency_samples: AtomicU32,
 }
 
 impl PluginShared<'_> for TensionFieldShared {}
@@ -270,31 +443,121 @@ pub struct TensionFieldMainThread<'a> {
     #[cfg(target_os = "windows")]
     gui: TensionFieldGui,
     automation_drain: AutomationDrainBuffer,
+    /// Whether the host selected the mono audio-port configuration.
+    mono_ports: bool,
 }
 
 impl<'a> PluginMainThread<'a, TensionFieldShared> for TensionFieldMainThread<'a> {}
 
 impl PluginAudioPortsImpl for TensionFieldMainThread<'_> {
-    fn count(&mut self, _is_input: bool) -> u32 {
-        1
+    fn count(&mut self, is_input: bool) -> u32 {
+        if is_input { 2 } else { 1 }
     }
 
-    fn get(&mut self, index: u32, _is_input: bool, writer: &mut AudioPortInfoWriter) {
+    fn get(&mut self, index: u32, is_input: bool, writer: &mut AudioPortInfoWriter) {
+        if is_input && index == SIDECHAIN_INPUT_INDEX as u32 {
+            writer.set(&AudioPortInfo {
+                id: ClapId::new(1),
+                name: b"sidechain",
+                channel_count: 2,
+                flags: AudioPortFlags::empty(),
+                port_type: Some(AudioPortType::STEREO),
+                in_place_pair: None,
+            });
+            return;
+        }
         if index != 0 {
             return;
         }
 
+        let (channel_count, port_type) = if self.mono_ports {
+            (1, AudioPortType::MONO)
+        } else {
+            (2, AudioPortType::STEREO)
+        };
         writer.set(&AudioPortInfo {
             id: ClapId::new(0),
             name: b"main",
-            channel_count: 2,
+            channel_count,
             flags: AudioPortFlags::IS_MAIN,
-            port_type: Some(AudioPortType::STEREO),
+            port_type: Some(port_type),
             in_place_pair: None,
         })
     }
 }
 
+/// Input port index of the stereo sidechain that drives ducking.
+const SIDECHAIN_INPUT_INDEX: usize = 1;
+
+/// Audio-port configuration id for the default stereo in/out layout.
+const STEREO_PORTS_CONFIG_ID: ClapId = ClapId::new(0);
+/// Audio-port configuration id for mono in/out, processed as dual mono.
+const MONO_PORTS_CONFIG_ID: ClapId = ClapId::new(1);
+
+impl PluginAudioPortsConfigImpl for TensionFieldMainThread<'_> {
+    fn count(&mut self) -> u32 {
+        2
+    }
+
+    fn get(&mut self, index: u32, writer: &mut AudioPortConfigWriter) {
+        let (id, name, channel_count, port_type): (_, &[u8], _, _) = match index {
+            0 => (STEREO_PORTS_CONFIG_ID, b"Stereo", 2, AudioPortType::STEREO),
+            1 => (MONO_PORTS_CONFIG_ID, b"Mono", 1, AudioPortType::MONO),
+            _ => return,
+        };
+        let main_port = || {
+            Some(MainPortInfo {
+                channel_count,
+                port_type: Some(port_type),
+            })
+        };
+        writer.write(&AudioPortsConfiguration {
+            id,
+            name,
+            input_port_count: 2,
+            output_port_count: 1,
+            main_input: main_port(),
+            main_output: main_port(),
+        });
+    }
+
+    fn select(&mut self, config_id: ClapId) -> Result<(), PluginError> {
+        self.mono_ports = if config_id == MONO_PORTS_CONFIG_ID {
+            true
+        } else if config_id == STEREO_PORTS_CONFIG_ID {
+            false
+        } else {
+            return Err(PluginError::Message("Unknown audio port configuration"));
+        };
+        Ok(())
+    }
+}
+
+impl PluginNotePortsImpl for TensionFieldMainThread<'_> {
+    fn count(&mut self, is_input: bool) -> u32 {
+        if is_input { 1 } else { 0 }
+    }
+
+    fn get(&mut self, index: u32, is_input: bool, writer: &mut NotePortInfoWriter) {
+        if !is_input || index != 0 {
+            return;
+        }
+
+        writer.set(&NotePortInfo {
+            id: ClapId::new(1),
+            name: b"pull trigger",
+            supported_dialects: NoteDialects::CLAP | NoteDialects::MIDI,
+            preferred_dialect: Some(NoteDialect::Clap),
+        })
+    }
+}
+
+impl PluginLatencyImpl for TensionFieldMainThread<'_> {
+    fn get(&mut self) -> u32 {
+        self.shared.latency_samples.load(Ordering::Relaxed)
+    }
+}
+
 impl PluginMainThreadParams for TensionFieldMainThread<'_> {
     fn count(&mut self) -> u32 {
         param_count()
@@ -317,7 +580,7 @@ impl PluginMainThreadParams for TensionFieldMainThread<'_> {
         value: f64,
         writer: &mut ParamDisplayWriter,
     ) -> std::fmt::Result {
-        value_to_text(param_id, value, writer)
+        value_to_text(param_id, value, self.shared.display.precision(), writer)
     }
 
     fn text_to_value(&mut self, param_id: ClapId, text: &std::ffi::CStr) -> Option<f64> {
@@ -343,6 +606,11 @@ impl PluginStateImpl for TensionFieldMainThread<'_> {
         let snapshot = PluginStateSnapshot {
             param_values: state_values(&self.shared.params),
             meter_values: self.shared.status.snapshot(),
+            param_locks: self.shared.locks.state_values(),
+            display_precision: self.shared.display.precision(),
+            pull_toggle: self.shared.display.pull_toggle(),
+            step_pattern: self.shared.step_pattern.state_values(),
+            meter_warn_dbfs: self.shared.display.meter_warn_dbfs(),
         };
         write_snapshot(output, &snapshot)?;
         Ok(())
@@ -353,6 +621,17 @@ impl PluginStateImpl for TensionFieldMainThread<'_> {
             read_snapshot(input).map_err(|error| PluginError::Message(error.as_message()))?;
         apply_state_values(&self.shared.params, snapshot.param_values);
         self.shared.status.apply_snapshot(snapshot.meter_values);
+        self.shared.locks.apply_state_values(snapshot.param_locks);
+        self.shared
+            .display
+            .set_precision(snapshot.display_precision);
+        self.shared.display.set_pull_toggle(snapshot.pull_toggle);
+        self.shared
+            .display
+            .set_meter_warn_dbfs(snapshot.meter_warn_dbfs);
+        self.shared
+            .step_pattern
+            .apply_state_values(snapshot.step_pattern);
         Ok(())
     }
 }
@@ -396,8 +675,13 @@ impl PluginGuiImpl for TensionFieldMainThread<'_> {
     fn show(&mut self) -> Result<(), PluginError> {
         let result = self.gui.open(
             &self.shared.params,
+            Arc::clone(&self.shared.locks),
+            Arc::clone(&self.shared.display),
             Arc::clone(&self.shared.automation_queue),
             Arc::clone(&self.shared.status),
+            Arc::clone(&self.shared.auditions),
+            Arc::clone(&self.shared.diagnostics),
+            Arc::clone(&self.shared.step_pattern),
             host_param_requester(self.host),
         );
         if let Some((width, height)) = self.gui.last_size() {
@@ -426,10 +710,17 @@ impl PluginGuiImpl for TensionFieldMainThread<'_> {
         true
     }
 
+    fn adjust_size(&mut self, size: GuiSize) -> Option<GuiSize> {
+        let preferred = gui::preferred_window_size(&self.shared.params, &self.shared.status);
+        let (width, height) = layout::fit_editor_size((size.width, size.height), preferred);
+        Some(GuiSize { width, height })
+    }
+
     fn set_size(&mut self, size: GuiSize) -> Result<(), PluginError> {
-        self.gui_size = size;
-        self.gui
-            .request_resize(size.width.max(1), size.height.max(1));
+        let preferred = gui::preferred_window_size(&self.shared.params, &self.shared.status);
+        let (width, height) = layout::fit_editor_size((size.width, size.height), preferred);
+        self.gui_size = GuiSize { width, height };
+        self.gui.request_resize(width, height);
         Ok(())
     }
 }
@@ -438,26 +729,58 @@ impl PluginGuiImpl for TensionFieldMainThread<'_> {
 pub struct TensionFieldAudioProcessor<'a> {
     shared: &'a TensionFieldShared,
     engine: TensionFieldEngine,
+    restart_requested: bool,
+    note_triggers: Vec<NoteTrigger>,
+    held_notes: u32,
     automation_drain: AutomationDrainBuffer,
     scratch_left: Vec<f32>,
     scratch_right: Vec<f32>,
+    sidechain_levels: Vec<f32>,
+    meter_pending: RenderReport,
+    meter_pending_frames: usize,
+    host: HostSharedHandle<'a>,
+    host_log: Option<HostLog>,
+    log_throttle: LogThrottle,
+    panic_throttle: LogThrottle,
 }
 
 impl<'a> PluginAudioProcessor<'a, TensionFieldShared, TensionFieldMainThread<'a>>
     for TensionFieldAudioProcessor<'a>
 {
     fn activate(
-        _host: HostAudioProcessorHandle<'a>,
+        host: HostAudioProcessorHandle<'a>,
         _main_thread: &mut TensionFieldMainThread<'a>,
         shared: &'a TensionFieldShared,
         audio_config: PluginAudioConfiguration,
     ) -> Result<Self, PluginError> {
+        shared
+            .status
+            .set_sample_rate(audio_config.sample_rate as f32);
+        let mut engine = TensionFieldEngine::new(audio_config.sample_rate as f32);
+        let settings = shared.params.settings();
+        engine.set_limit_lookahead_ms(settings.limit_lookahead_ms);
+        engine.set_true_peak_limit(settings.true_peak_limit);
+        let max_frames = audio_config.max_frames_count as usize;
+        engine.reserve_sidechain(max_frames);
+        shared
+            .latency_samples
+            .store(engine.latency_samples(), Ordering::Relaxed);
         Ok(Self {
             shared,
-            engine: TensionFieldEngine::new(audio_config.sample_rate as f32),
+            engine,
+            restart_requested: false,
+            note_triggers: Vec::with_capacity(MAX_NOTE_TRIGGERS),
+            held_notes: 0,
             automation_drain: AutomationDrainBuffer::default(),
             scratch_left: Vec::new(),
             scratch_right: Vec::new(),
+            sidechain_levels: Vec::with_capacity(max_frames),
+            meter_pending: RenderReport::default(),
+            meter_pending_frames: 0,
+            host: host.shared(),
+            host_log: host.shared().get_extension::<HostLog>(),
+            log_throttle: LogThrottle::new(audio_config.sample_rate as f32, 1.0),
+            panic_throttle: LogThrottle::new(audio_config.sample_rate as f32, 1.0),
         })
     }
 
@@ -471,33 +794,69 @@ impl<'a> PluginAudioProcessor<'a, TensionFieldShared, TensionFieldMainThread<'a>
             self.shared.params.set_param(param_id, value as f32);
         });
 
+        self.collect_note_triggers(events.input);
+
         let settings = self.shared.params.settings();
+        self.request_restart_on_lookahead_change(&settings);
+        self.engine.set_mod_solo(self.shared.auditions.mod_solo());
+        self.engine
+            .set_feedback_solo(self.shared.auditions.feedback_solo());
+        self.engine
+            .set_step_pattern(self.shared.step_pattern.state_values());
         let transport = transport_state_from_transport(process.transport.copied());
-        for mut port_pair in &mut audio {
-            let Some(mut channels) = port_pair.channels()?.into_f32() else {
-                continue;
-            };
-
-            let mut channel_iter = channels.iter_mut();
-            let Some(left_pair) = channel_iter.next() else {
-                continue;
-            };
-            let Some(right_pair) = channel_iter.next() else {
-                continue;
-            };
-
-            self.process_stereo_pair(left_pair, right_pair, &settings, transport);
+        let sidechain_connected = self.read_sidechain(&audio)?;
+        self.engine
+            .set_sidechain(sidechain_connected.then_some(&self.sidechain_levels[..]));
+        if let Some(mut port_pair) = audio.port_pair(0) {
+            self.process_main_port(&mut port_pair, &settings, transport)?;
         }
 
         let _ = self
             .automation_drain
             .drain(&self.shared.automation_queue, events.output);
 
-        Ok(ProcessStatus::Continue)
+        // Silent input with output still ringing asks the host to keep the tail running;
+        // once input and internal state have settled the host may stop calling.
+        Ok(if self.engine.sleeping() {
+            ProcessStatus::Sleep
+        } else if self.engine.tail_pending() {
+            ProcessStatus::Tail
+        } else {
+            ProcessStatus::Continue
+        })
+    }
+}
+
+impl PluginTailImpl for TensionFieldAudioProcessor<'_> {
+    fn get(&self) -> TailLength {
+        TailLength::Finite(self.engine.tail_samples(&self.shared.params.settings()))
     }
 }
 
 impl TensionFieldAudioProcessor<'_> {
+    fn process_main_port(
+        &mut self,
+        port_pair: &mut PortPair<'_>,
+        settings: &params::TensionFieldSettings,
+        transport: clock::TransportState,
+    ) -> Result<(), PluginError> {
+        let Some(mut channels) = port_pair.channels()?.into_f32() else {
+            return Ok(());
+        };
+
+        let mut channel_iter = channels.iter_mut();
+        let Some(left_pair) = channel_iter.next() else {
+            return Ok(());
+        };
+        match channel_iter.next() {
+            Some(right_pair) => {
+                self.process_stereo_pair(left_pair, right_pair, settings, transport)
+            }
+            None => self.process_mono_channel(left_pair, settings, transport),
+        }
+        Ok(())
+    }
+
     fn process_stereo_pair(
         &mut self,
         left: ChannelPair<'_, f32>,
@@ -505,12 +864,12 @@ impl TensionFieldAudioProcessor<'_> {
         settings: &params::TensionFieldSettings,
         transport: clock::TransportState,
     ) {
-        let (left_input, left_output, left_in_place) = split_channel(left);
-        let (right_input, right_output, right_in_place) = split_channel(right);
+        let (left_source, left_output) = split_channel(left);
+        let (right_source, right_output) = split_channel(right);
 
         let frames = min_len(&[
-            left_input.map(|buf| buf.len()),
-            right_input.map(|buf| buf.len()),
+            left_source.len(),
+            right_source.len(),
             left_output.as_ref().map(|buf| buf.len()),
             right_output.as_ref().map(|buf| buf.len()),
         ]);
@@ -520,40 +879,18 @@ impl TensionFieldAudioProcessor<'_> {
 
         self.ensure_scratch(frames);
         for frame in 0..frames {
-            self.scratch_left[frame] = if left_in_place {
-                left_output
-                    .as_deref()
-                    .and_then(|buf| buf.get(frame))
-                    .copied()
-                    .unwrap_or(0.0)
-            } else {
-                left_input
-                    .and_then(|buf| buf.get(frame))
-                    .copied()
-                    .unwrap_or(0.0)
-            };
-
-            self.scratch_right[frame] = if right_in_place {
-                right_output
-                    .as_deref()
-                    .and_then(|buf| buf.get(frame))
-                    .copied()
-                    .unwrap_or(0.0)
-            } else {
-                right_input
-                    .and_then(|buf| buf.get(frame))
-                    .copied()
-                    .unwrap_or(0.0)
-            };
+            self.scratch_left[frame] = left_source.sample(left_output.as_deref(), frame);
+            self.scratch_right[frame] = right_source.sample(right_output.as_deref(), frame);
         }
 
-        let report = self.engine.render(
+        let report = self.engine.render_with_triggers(
             settings,
             &mut self.scratch_left[..frames],
             &mut self.scratch_right[..frames],
             transport,
+            &self.note_triggers,
         );
-        self.shared.status.update(report);
+        self.report_block(report, frames, settings);
 
         let mut left_output = left_output;
         let mut right_output = right_output;
@@ -565,6 +902,189 @@ impl TensionFieldAudioProcessor<'_> {
         }
     }
 
+    /// Run a single-channel port through the engine as dual mono.
+    fn process_mono_channel(
+        &mut self,
+        channel: ChannelPair<'_, f32>,
+        settings: &params::TensionFieldSettings,
+        transport: clock::TransportState,
+    ) {
+        let (source, output) = split_channel(channel);
+        let frames = min_len(&[source.len(), output.as_ref().map(|buf| buf.len())]);
+        let Some(frames) = frames else {
+            return;
+        };
+
+        self.ensure_scratch(frames);
+        for frame in 0..frames {
+            self.scratch_left[frame] = source.sample(output.as_deref(), frame);
+        }
+
+        let report = self.engine.render_mono_with_triggers(
+            settings,
+            &mut self.scratch_left[..frames],
+            &mut self.scratch_right[..frames],
+            transport,
+            &self.note_triggers,
+        );
+        self.report_block(report, frames, settings);
+
+        let mut output = output;
+        if let Some(out) = output.as_deref_mut() {
+            out[..frames].copy_from_slice(&self.scratch_left[..frames]);
+        }
+    }
+
+    /// Publish meters, overshoot logging and panic snapshots for a rendered block.
+    fn report_block(
+        &mut self,
+        report: RenderReport,
+        frames: usize,
+        settings: &params::TensionFieldSettings,
+    ) {
+        self.publish_meters(report, frames);
+        if settings.verbose_log && self.log_throttle.allow(frames, report.output_overshoot) {
+            self.log_warning(c"Tension Field: output overshoot, soft clip engaged hard");
+        }
+        let snapshot = self.engine.take_panic_snapshot();
+        if let Some(snapshot) = snapshot {
+            self.shared.diagnostics.publish(snapshot);
+        }
+        if self.panic_throttle.allow(frames, snapshot.is_some()) {
+            self.log_warning(
+                c"Tension Field: non-finite audio sanitized; snapshot kept for Copy Debug Info",
+            );
+        }
+    }
+
+    /// Accumulate meter peaks so tiny host blocks do not publish single-sample readings.
+    fn publish_meters(&mut self, report: RenderReport, frames: usize) {
+        self.meter_pending.merge(report);
+        self.meter_pending_frames += frames;
+        if self.meter_pending_frames >= METER_PUBLISH_FRAMES {
+            self.shared.status.update(self.meter_pending);
+            self.meter_pending = RenderReport::default();
+            self.meter_pending_frames = 0;
+        }
+    }
+
+    /// Turn note on/off and sustain-pedal events into sample-offset gate changes.
+    ///
+    /// Any held note keeps the pull engaged; the gate opens on the first note-on
+    /// and closes when the last held note is released. MIDI CC64 drives the
+    /// separate sustain gate.
+    fn collect_note_triggers(&mut self, input: &InputEvents) {
+        self.note_triggers.clear();
+        for event in input.iter() {
+            let (time, source, pressed) = match event.as_core_event() {
+                Some(CoreEventSpace::NoteOn(note)) => {
+                    let Some(held) = self.change_held_notes(true) else {
+                        continue;
+                    };
+                    (note.header().time(), TriggerSource::Notes, held)
+                }
+                Some(CoreEventSpace::NoteOff(note)) => {
+                    let Some(held) = self.change_held_notes(false) else {
+                        continue;
+                    };
+                    (note.header().time(), TriggerSource::Notes, held)
+                }
+                Some(CoreEventSpace::Midi(midi)) => {
+                    let data = midi.data();
+                    if let Some(down) = sustain_pedal_state(data) {
+                        (midi.header().time(), TriggerSource::SustainPedal, down)
+                    } else if let Some(note_on) = midi_note_state(data) {
+                        let Some(held) = self.change_held_notes(note_on) else {
+                            continue;
+                        };
+                        (midi.header().time(), TriggerSource::Notes, held)
+                    } else {
+                        continue;
+                    }
+                }
+                _ => continue,
+            };
+
+            let trigger = NoteTrigger {
+                offset: time as usize,
+                source,
+                pressed,
+            };
+            if self.note_triggers.len() < MAX_NOTE_TRIGGERS {
+                self.note_triggers.push(trigger);
+            } else if let Some(last) = self
+                .note_triggers
+                .iter_mut()
+                .rev()
+                .find(|last| last.source == source)
+            {
+                // Never allocate on the audio thread; keep the final gate state instead.
+                last.pressed = pressed;
+            }
+        }
+    }
+
+    /// Count a note on/off and return the new gate state when it changed.
+    fn change_held_notes(&mut self, note_on: bool) -> Option<bool> {
+        let was_held = self.held_notes > 0;
+        self.held_notes = if note_on {
+            self.held_notes.saturating_add(1)
+        } else {
+            self.held_notes.saturating_sub(1)
+        };
+        let held = self.held_notes > 0;
+        (held != was_held).then_some(held)
+    }
+
+    /// Lookahead and true-peak limiter changes alter latency, which hosts only
+    /// pick up across a restart.
+    fn request_restart_on_lookahead_change(&mut self, settings: &params::TensionFieldSettings) {
+        if !self.restart_requested
+            && (!self
+                .engine
+                .limit_lookahead_matches(settings.limit_lookahead_ms)
+                || !self
+                    .engine
+                    .true_peak_limit_matches(settings.true_peak_limit))
+        {
+            self.restart_requested = true;
+            self.host.request_restart();
+        }
+    }
+
+    fn log_warning(&self, message: &std::ffi::CStr) {
+        if let Some(log) = self.host_log {
+            log.log(&self.host, LogSeverity::Warning, message);
+        }
+    }
+
+    /// Collect the per-frame peak of the sidechain input, returning whether one is connected.
+    ///
+    /// Writes stay inside the capacity reserved in `activate`, so this never allocates.
+    fn read_sidechain(&mut self, audio: &Audio) -> Result<bool, PluginError> {
+        self.sidechain_levels.clear();
+        let Some(port) = audio.input_port(SIDECHAIN_INPUT_INDEX) else {
+            return Ok(false);
+        };
+        let Some(channels) = port.channels()?.into_f32() else {
+            return Ok(false);
+        };
+        if channels.channel_count() == 0 {
+            return Ok(false);
+        }
+        let capacity = self.sidechain_levels.capacity();
+        for channel in channels.iter() {
+            let frames = channel.len().min(capacity);
+            if self.sidechain_levels.len() < frames {
+                self.sidechain_levels.resize(frames, 0.0);
+            }
+            for (level, sample) in self.sidechain_levels.iter_mut().zip(channel) {
+                *level = level.max(sample.abs());
+            }
+        }
+        Ok(true)
+    }
+
     fn ensure_scratch(&mut self, frames: usize) {
         if self.scratch_left.len() < frames {
             self.scratch_left.resize(frames, 0.0);
@@ -604,19 +1124,73 @@ fn transport_state_from_transport(transport: Option<TransportEvent>) -> clock::T
             } else {
                 None
             },
+            beats_per_bar: if event.flags.contains(TransportFlags::HAS_TIME_SIGNATURE)
+                && event.time_signature_denominator > 0
+            {
+                event.time_signature_numerator as f64 * 4.0
+                    / event.time_signature_denominator as f64
+            } else {
+                4.0
+            },
         },
         None => clock::TransportState::default(),
     }
 }
 
-fn split_channel<'a>(
-    pair: ChannelPair<'a, f32>,
-) -> (Option<&'a [f32]>, Option<&'a mut [f32]>, bool) {
+/// Return the pedal state for a MIDI sustain (CC64) message on any channel.
+fn sustain_pedal_state(data: [u8; 3]) -> Option<bool> {
+    (data[0] & 0xF0 == 0xB0 && data[1] == MIDI_CC_SUSTAIN).then_some(data[2] >= 64)
+}
+
+/// Return `Some(true)` for a MIDI note-on and `Some(false)` for a note-off.
+fn midi_note_state(data: [u8; 3]) -> Option<bool> {
+    match data[0] & 0xF0 {
+        0x90 => Some(data[2] > 0),
+        0x80 => Some(false),
+        _ => None,
+    }
+}
+
+/// Where a channel's input samples come from.
+#[derive(Clone, Copy)]
+enum ChannelSource<'a> {
+    /// A separate input buffer.
+    Input(&'a [f32]),
+    /// The output buffer, which holds the input when processing in place.
+    InPlace,
+    /// No input: an output-only channel, whose buffer contents are undefined.
+    Silent,
+}
+
+impl ChannelSource<'_> {
+    /// Length of the separate input buffer, if there is one.
+    fn len(self) -> Option<usize> {
+        match self {
+            Self::Input(input) => Some(input.len()),
+            Self::InPlace | Self::Silent => None,
+        }
+    }
+
+    /// Read one input sample; output-only channels always read silence.
+    fn sample(self, output: Option<&[f32]>, frame: usize) -> f32 {
+        let buffer = match self {
+            Self::Input(input) => Some(input),
+            Self::InPlace => output,
+            Self::Silent => None,
+        };
+        buffer
+            .and_then(|buf| buf.get(frame))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
+fn split_channel<'a>(pair: ChannelPair<'a, f32>) -> (ChannelSource<'a>, Option<&'a mut [f32]>) {
     match pair {
-        ChannelPair::InputOnly(input) => (Some(input), None, false),
-        ChannelPair::OutputOnly(output) => (None, Some(output), false),
-        ChannelPair::InputOutput(input, output) => (Some(input), Some(output), false),
-        ChannelPair::InPlace(output) => (None, Some(output), true),
+        ChannelPair::InputOnly(input) => (ChannelSource::Input(input), None),
+        ChannelPair::OutputOnly(output) => (ChannelSource::Silent, Some(output)),
+        ChannelPair::InputOutput(input, output) => (ChannelSource::Input(input), Some(output)),
+        ChannelPair::InPlace(output) => (ChannelSource::InPlace, Some(output)),
     }
 }
 
@@ -629,6 +1203,14 @@ fn min_len(lengths: &[Option<usize>]) -> Option<usize> {
         .filter(|len| *len > 0)
 }
 
+/// Build the status block, smoothing meters on the audio thread when no editor does it.
+fn new_gui_status() -> GuiStatus {
+    let status = GuiStatus::default();
+    #[cfg(not(target_os = "windows"))]
+    status.set_meter_smoothing(HEADLESS_METER_SMOOTHING);
+    status
+}
+
 fn f32_to_bits(value: f32) -> u32 {
     u32::from_ne_bytes(value.to_ne_bytes())
 }
@@ -638,3 +1220,115 @@ fn bits_to_f32(value: u32) -> f32 {
 }
 
 toybox::clap_plugin_entry!(TensionFieldPlugin);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ChannelPair, GuiStatus, RenderReport, midi_note_state, split_channel, sustain_pedal_state,
+    };
+    use crate::dsp::TensionFieldEngine;
+    use crate::params::TensionFieldParams;
+
+    #[test]
+    fn output_only_channels_process_silence_not_stale_buffer_contents() {
+        // Host-owned output buffers may hold anything, including NaN.
+        let mut left_out = vec![f32::NAN; 256];
+        let mut right_out = vec![7.5; 256];
+        let (left_source, left_output) = split_channel(ChannelPair::OutputOnly(&mut left_out));
+        let (right_source, right_output) = split_channel(ChannelPair::OutputOnly(&mut right_out));
+        let mut left: Vec<f32> = (0..256)
+            .map(|frame| left_source.sample(left_output.as_deref(), frame))
+            .collect();
+        let mut right: Vec<f32> = (0..256)
+            .map(|frame| right_source.sample(right_output.as_deref(), frame))
+            .collect();
+        assert!(left.iter().chain(&right).all(|sample| *sample == 0.0));
+
+        let mut engine = TensionFieldEngine::new(48_000.0);
+        let _ = engine.render(
+            &TensionFieldParams::new().settings(),
+            &mut left,
+            &mut right,
+            Default::default(),
+        );
+        assert!(left.iter().chain(&right).all(|sample| sample.is_finite()));
+
+        // In place, the output buffer is the input.
+        let mut in_place = vec![0.25; 4];
+        let (source, output) = split_channel(ChannelPair::InPlace(&mut in_place));
+        assert_eq!(source.sample(output.as_deref(), 2), 0.25);
+    }
+
+    #[test]
+    fn meter_accessors_read_back_stored_values() {
+        let status = GuiStatus::default();
+        status.update(RenderReport {
+            input_left: 0.1,
+            input_right: 0.2,
+            elastic_activity: 0.3,
+            warp_activity: 0.4,
+            space_activity: 0.5,
+            space_rms: 0.25,
+            feedback_activity: 0.6,
+            output_left: 0.7,
+            output_right: 0.8,
+            tension_activity: 0.9,
+            duck_level: 0.35,
+            true_peak_reduction_db: 1.5,
+            ..RenderReport::default()
+        });
+
+        let meters = [
+            status.input_left(),
+            status.input_right(),
+            status.elastic_activity(),
+            status.warp_activity(),
+            status.space_activity(),
+            status.space_rms(),
+            status.feedback_activity(),
+            status.output_left(),
+            status.output_right(),
+            status.tension_activity(),
+            status.duck_level(),
+            status.true_peak_reduction_db(),
+        ];
+        assert_eq!(
+            meters,
+            [0.1, 0.2, 0.3, 0.4, 0.5, 0.25, 0.6, 0.7, 0.8, 0.9, 0.35, 1.5]
+        );
+    }
+
+    #[test]
+    fn sustain_pedal_parses_cc64_on_any_channel() {
+        assert_eq!(sustain_pedal_state([0xB0, 64, 127]), Some(true));
+        assert_eq!(sustain_pedal_state([0xB5, 64, 0]), Some(false));
+        assert_eq!(sustain_pedal_state([0xB0, 1, 127]), None);
+        assert_eq!(sustain_pedal_state([0x90, 64, 127]), None);
+        assert_eq!(midi_note_state([0x90, 60, 100]), Some(true));
+        assert_eq!(midi_note_state([0x90, 60, 0]), Some(false));
+        assert_eq!(midi_note_state([0x80, 60, 64]), Some(false));
+    }
+
+    #[test]
+    fn smoothed_updates_approach_a_step_gradually() {
+        let status = GuiStatus::default();
+        status.set_meter_smoothing(0.75);
+        assert_eq!(status.meter_smoothing(), 0.75);
+        let step = RenderReport {
+            output_left: 1.0,
+            ..RenderReport::default()
+        };
+
+        status.update(step);
+        let first = status.output_left();
+        assert!((first - 0.25).abs() < 1.0e-6);
+        let mut previous = first;
+        for _ in 0..40 {
+            status.update(step);
+            let current = status.output_left();
+            assert!(current > previous && current <= 1.0);
+            previous = current;
+        }
+        assert!(previous > 0.99);
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::f32::consts::TAU;
 
-use crate::clock::ClockFrame;
+use crate::clock::{ClockFrame, PhaseHumanizer};
 use crate::params::{ModRateMode, ModSettings, ModSourceSettings, ModSourceShape};
 
 const DEST_COUNT: usize = 6;
@@ -14,6 +14,7 @@ struct ModSourceState {
     previous_sync_phase: f32,
     walk_state: f32,
     env_state: f32,
+    humanizer: PhaseHumanizer,
 }
 
 impl Default for ModSourceState {
@@ -23,6 +24,7 @@ impl Default for ModSourceState {
             previous_sync_phase: 0.0,
             walk_state: 0.0,
             env_state: 0.0,
+            humanizer: PhaseHumanizer::default(),
         }
     }
 }
@@ -32,6 +34,8 @@ pub(crate) struct ModMatrix {
     source_a: ModSourceState,
     source_b: ModSourceState,
     smoothed: [f32; DEST_COUNT],
+    solo_mask: [f32; DEST_COUNT],
+    source_values: [f32; 2],
     noise_state: u32,
 }
 
@@ -39,14 +43,32 @@ impl Default for ModMatrix {
     fn default() -> Self {
         Self {
             source_a: ModSourceState::default(),
-            source_b: ModSourceState::default(),
+            // A distinct seed keeps the two sources from wandering in lockstep.
+            source_b: ModSourceState {
+                humanizer: PhaseHumanizer::new(0x68E3_1DA4),
+                ..ModSourceState::default()
+            },
             smoothed: [0.0; DEST_COUNT],
+            solo_mask: [1.0; DEST_COUNT],
+            source_values: [0.0; 2],
             noise_state: 0xA5A5_9151,
         }
     }
 }
 
 impl ModMatrix {
+    /// Mask every destination except `destination`; `None` restores all routes.
+    ///
+    /// Smoothing keeps running underneath so un-soloing does not jump.
+    pub(crate) fn set_solo(&mut self, destination: Option<usize>) {
+        for (index, gain) in self.solo_mask.iter_mut().enumerate() {
+            *gain = match destination {
+                Some(solo) if solo != index => 0.0,
+                _ => 1.0,
+            };
+        }
+    }
+
     /// Generate one sample of destination modulation values.
     pub(crate) fn next(
         &mut self,
@@ -59,7 +81,8 @@ impl ModMatrix {
             for value in &mut self.smoothed {
                 *value *= 0.98;
             }
-            return self.smoothed;
+            self.source_values = [0.0; 2];
+            return self.masked();
         }
 
         let a = source_value(
@@ -68,6 +91,7 @@ impl ModMatrix {
             clock,
             input_envelope,
             sample_rate,
+            settings.humanize,
             &mut self.noise_state,
         );
         let b = source_value(
@@ -76,13 +100,16 @@ impl ModMatrix {
             clock,
             input_envelope,
             sample_rate,
+            settings.humanize,
             &mut self.noise_state,
         );
 
+        self.source_values = [a, b];
+
         let mut destination_raw = [0.0; DEST_COUNT];
         for (index, raw) in destination_raw.iter_mut().enumerate() {
-            let combined =
-                a * settings.route_depths[0][index] + b * settings.route_depths[1][index];
+            let combined = route_contribution(a, settings.route_depths[0][index])
+                + route_contribution(b, settings.route_depths[1][index]);
             *raw = destination_curve(index, combined);
         }
 
@@ -92,8 +119,48 @@ impl ModMatrix {
             self.smoothed[index] += filtered_delta * destination_smoothing(index);
         }
 
-        self.smoothed
+        self.masked()
+    }
+
+    /// Latest output of sources A and B (-1..1), after their depth.
+    pub(crate) fn source_values(&self) -> [f32; 2] {
+        self.source_values
     }
+
+    /// Clear smoothing and restart both cycles on a transport start.
+    ///
+    /// Only applies while a source is synced to the host, so free-running
+    /// modulation keeps its motion across stops.
+    pub(crate) fn transport_started(&mut self, settings: &ModSettings) {
+        let synced = [&settings.source_a, &settings.source_b]
+            .iter()
+            .any(|source| source.rate_mode == ModRateMode::SyncDivision);
+        if synced {
+            self.smoothed = [0.0; DEST_COUNT];
+            self.reset_phases();
+        }
+    }
+
+    /// Restart both source cycles after a transport jump.
+    pub(crate) fn reset_phases(&mut self) {
+        for source in [&mut self.source_a, &mut self.source_b] {
+            source.phase = 0.0;
+            source.previous_sync_phase = 0.0;
+        }
+    }
+
+    fn masked(&self) -> [f32; DEST_COUNT] {
+        let mut output = self.smoothed;
+        for (value, gain) in output.iter_mut().zip(self.solo_mask) {
+            *value *= gain;
+        }
+        output
+    }
+}
+
+/// One route's share of a destination: the source value scaled by the route depth.
+pub(crate) fn route_contribution(source_value: f32, depth: f32) -> f32 {
+    (source_value * depth).clamp(-1.0, 1.0)
 }
 
 fn destination_curve(index: usize, value: f32) -> f32 {
@@ -123,6 +190,7 @@ fn source_value(
     clock: ClockFrame,
     input_envelope: f32,
     sample_rate: f32,
+    humanize: f32,
     noise_state: &mut u32,
 ) -> f32 {
     let phase = match settings.rate_mode {
@@ -132,7 +200,10 @@ fn source_value(
             state.phase
         }
         ModRateMode::SyncDivision => {
-            let sync_phase = clock.phase_for_division(settings.rate_division, 0.0);
+            let sync_phase = state.humanizer.apply(
+                clock.phase_for_division(settings.rate_division, 0.0),
+                humanize,
+            );
             state.phase = sync_phase;
             sync_phase
         }
@@ -165,7 +236,13 @@ fn source_value(
         }
         ModSourceShape::Envelope => {
             let target = input_envelope.clamp(0.0, 1.0);
-            state.env_state += (target - state.env_state) * 0.06;
+            let time_ms = if target > state.env_state {
+                settings.env_attack_ms
+            } else {
+                settings.env_release_ms
+            };
+            state.env_state +=
+                (target - state.env_state) * follower_coefficient(time_ms, sample_rate);
             state.env_state * 2.0 - 1.0
         }
     };
@@ -173,6 +250,12 @@ fn source_value(
     core * settings.depth.clamp(0.0, 1.0)
 }
 
+/// One-pole coefficient reaching ~63% of a step after `time_ms`.
+fn follower_coefficient(time_ms: f32, sample_rate: f32) -> f32 {
+    let time_samples = time_ms.max(0.01) * 0.001 * sample_rate.max(1.0);
+    1.0 - (-1.0 / time_samples).exp()
+}
+
 fn triangle(phase: f32) -> f32 {
     let p = phase.fract();
     if p < 0.5 {
@@ -189,7 +272,7 @@ fn signed_noise(state: &mut u32) -> f32 {
 
 #[cfg(test)]
 mod tests {
-    use super::ModMatrix;
+    use super::{ModMatrix, ModSourceState, route_contribution, source_value};
     use crate::clock::ClockFrame;
     use crate::params::{
         ModRateMode, ModSettings, ModSourceSettings, ModSourceShape, PullDivision,
@@ -204,6 +287,8 @@ mod tests {
                 rate_hz: 0.5,
                 rate_division: PullDivision::Div1_4,
                 depth: 1.0,
+                env_attack_ms: 0.35,
+                env_release_ms: 0.35,
             },
             source_b: ModSourceSettings {
                 shape: ModSourceShape::Triangle,
@@ -211,11 +296,22 @@ mod tests {
                 rate_hz: 0.3,
                 rate_division: PullDivision::Div1_2,
                 depth: 0.0,
+                env_attack_ms: 0.35,
+                env_release_ms: 0.35,
             },
             route_depths: [[1.0, 0.0, 0.0, 0.0, 0.0, 0.0], [0.0; 6]],
+            humanize: 0.0,
         }
     }
 
+    #[test]
+    fn route_contribution_scales_source_by_signed_depth() {
+        assert_eq!(route_contribution(0.5, 0.5), 0.25);
+        assert_eq!(route_contribution(0.5, -1.0), -0.5);
+        assert_eq!(route_contribution(-0.8, 0.0), 0.0);
+        assert_eq!(route_contribution(1.0, 1.0), 1.0);
+    }
+
     #[test]
     fn route_depth_drives_destination() {
         let mut matrix = ModMatrix::default();
@@ -226,6 +322,7 @@ mod tests {
                 ClockFrame {
                     beat_position: n as f64 / 48_000.0,
                     is_playing: true,
+                    host_timeline: true,
                 },
                 0.5,
                 48_000.0,
@@ -238,6 +335,43 @@ mod tests {
         assert!(has_motion);
     }
 
+    #[test]
+    fn transport_start_clears_stale_smoothing_for_synced_sources() {
+        let mut settings = test_settings();
+        settings.source_a.rate_mode = ModRateMode::SyncDivision;
+        let frame = |beat_position: f64| ClockFrame {
+            beat_position,
+            is_playing: true,
+            host_timeline: true,
+        };
+
+        let mut matrix = ModMatrix::default();
+        for n in 0..6_000 {
+            let _ = matrix.next(&settings, frame(n as f64 / 24_000.0), 0.5, 48_000.0);
+        }
+        let stale = matrix.next(&settings, frame(0.0), 0.5, 48_000.0);
+
+        let mut restarted = ModMatrix::default();
+        for n in 0..6_000 {
+            let _ = restarted.next(&settings, frame(n as f64 / 24_000.0), 0.5, 48_000.0);
+        }
+        restarted.transport_started(&settings);
+        let first = restarted.next(&settings, frame(0.0), 0.5, 48_000.0);
+        let fresh = ModMatrix::default().next(&settings, frame(0.0), 0.5, 48_000.0);
+
+        assert!((stale[0] - fresh[0]).abs() > 0.1);
+        assert_eq!(first, fresh);
+
+        // Free-running sources keep their smoothing across a restart.
+        let mut free = ModMatrix::default();
+        for n in 0..6_000 {
+            let _ = free.next(&test_settings(), frame(n as f64 / 24_000.0), 0.5, 48_000.0);
+        }
+        let before = free.smoothed;
+        free.transport_started(&test_settings());
+        assert_eq!(free.smoothed, before);
+    }
+
     #[test]
     fn disabled_matrix_decays_to_zero() {
         let mut matrix = ModMatrix::default();
@@ -248,6 +382,7 @@ mod tests {
             ClockFrame {
                 beat_position: 0.0,
                 is_playing: true,
+                host_timeline: true,
             },
             0.5,
             48_000.0,
@@ -259,10 +394,97 @@ mod tests {
             ClockFrame {
                 beat_position: 0.0,
                 is_playing: true,
+                host_timeline: true,
             },
             0.5,
             48_000.0,
         );
         assert!(output.iter().all(|value| value.abs() <= 1.0));
     }
+
+    fn samples_to_track_burst(env_attack_ms: f32) -> usize {
+        let settings = ModSourceSettings {
+            shape: ModSourceShape::Envelope,
+            env_attack_ms,
+            ..test_settings().source_a
+        };
+        let mut state = ModSourceState::default();
+        let mut noise_state = 1;
+        let clock = ClockFrame {
+            beat_position: 0.0,
+            is_playing: false,
+            host_timeline: false,
+        };
+        for n in 0..48_000 {
+            let value = source_value(
+                &settings,
+                &mut state,
+                clock,
+                1.0,
+                48_000.0,
+                0.0,
+                &mut noise_state,
+            );
+            if value > 0.8 {
+                return n;
+            }
+        }
+        usize::MAX
+    }
+
+    #[test]
+    fn faster_envelope_attack_tracks_burst_sooner() {
+        let default = samples_to_track_burst(0.35);
+        let fast = samples_to_track_burst(0.1);
+        let slow = samples_to_track_burst(200.0);
+        assert!(fast < default);
+        assert!(default < slow);
+        assert!(slow < usize::MAX);
+    }
+
+    #[test]
+    fn soloed_width_is_the_only_modulated_destination() {
+        let mut matrix = ModMatrix::default();
+        let mut settings = test_settings();
+        settings.route_depths[0] = [1.0; 6];
+        // Width is destination 3.
+        matrix.set_solo(Some(3));
+
+        let mut peaks = [0.0_f32; 6];
+        for n in 0..24_000 {
+            let output = matrix.next(
+                &settings,
+                ClockFrame {
+                    beat_position: n as f64 / 48_000.0,
+                    is_playing: true,
+                    host_timeline: true,
+                },
+                0.5,
+                48_000.0,
+            );
+            for (peak, value) in peaks.iter_mut().zip(output) {
+                *peak = peak.max(value.abs());
+            }
+        }
+
+        assert!(peaks[3] > 0.1);
+        for (index, peak) in peaks.iter().enumerate() {
+            if index != 3 {
+                assert_eq!(*peak, 0.0);
+            }
+        }
+
+        matrix.set_solo(None);
+        let output = matrix.next(
+            &settings,
+            ClockFrame {
+                beat_position: 0.5,
+                is_playing: true,
+                host_timeline: true,
+            },
+            0.5,
+            48_000.0,
+        );
+        assert!(output[0].abs() > 1.0e-3);
+    }
 }
@@ -1,13 +1,16 @@
 //! Parameter definitions and atomic storage for the Tension Field plugin.
 
 use std::ffi::CStr;
-use std::fmt::Write as _;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
-use toybox::clack_extensions::params::{ParamDisplayWriter, ParamInfoFlags, ParamInfoWriter};
+use toybox::clack_extensions::params::{ParamInfoFlags, ParamInfoWriter};
 use toybox::clack_plugin::prelude::ClapId;
 use toybox::clap::params::ParamBuilder;
 
+#[cfg(any(target_os = "windows", test))]
+use crate::diagnostics::PanicSnapshot;
+
 const ROUTE_DEST_COUNT: usize = 6;
 
 /// Pull gesture shape choices.
@@ -197,6 +200,88 @@ impl PullDivision {
             _ => None,
         }
     }
+
+    /// Return the division closest to this one played at `multiplier` speed.
+    ///
+    /// Triplets stay triplets where the range has a matching division and fall
+    /// back to the nearest straight one otherwise; shifts past the ends of the
+    /// range clamp to the fastest or slowest division.
+    pub(crate) fn shifted(self, multiplier: RateMultiplier) -> Self {
+        match multiplier {
+            RateMultiplier::Normal => self,
+            RateMultiplier::Double => match self {
+                Self::Div1_16 | Self::Div1_8T | Self::Div1_8 => Self::Div1_16,
+                Self::Div1_4T => Self::Div1_8T,
+                Self::Div1_4 => Self::Div1_8,
+                Self::Div1_2 => Self::Div1_4,
+                Self::Div1Bar => Self::Div1_2,
+                Self::Div2Bar => Self::Div1Bar,
+            },
+            RateMultiplier::Half => match self {
+                Self::Div1_16 => Self::Div1_8,
+                Self::Div1_8T => Self::Div1_4T,
+                Self::Div1_8 => Self::Div1_4,
+                Self::Div1_4T | Self::Div1_4 => Self::Div1_2,
+                Self::Div1_2 => Self::Div1Bar,
+                Self::Div1Bar | Self::Div2Bar => Self::Div2Bar,
+            },
+        }
+    }
+}
+
+/// Quick half/double-time switch for the pull rate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RateMultiplier {
+    /// Half speed.
+    Half,
+    /// As dialed.
+    Normal,
+    /// Double speed.
+    Double,
+}
+
+impl RateMultiplier {
+    fn from_value(value: f32) -> Self {
+        match value.round() as i32 {
+            0 => Self::Half,
+            2 => Self::Double,
+            _ => Self::Normal,
+        }
+    }
+
+    fn as_value(self) -> f32 {
+        match self {
+            Self::Half => 0.0,
+            Self::Normal => 1.0,
+            Self::Double => 2.0,
+        }
+    }
+
+    /// Return the factor applied to free-running pull rates.
+    pub(crate) fn factor(self) -> f32 {
+        match self {
+            Self::Half => 0.5,
+            Self::Normal => 1.0,
+            Self::Double => 2.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Half => "x0.5",
+            Self::Normal => "x1",
+            Self::Double => "x2",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "0" | "x0.5" | "0.5" | "half" => Some(Self::Half),
+            "1" | "x1" | "normal" => Some(Self::Normal),
+            "2" | "x2" | "double" => Some(Self::Double),
+            _ => None,
+        }
+    }
 }
 
 /// Quantization amount for pull trigger launches.
@@ -307,6 +392,235 @@ impl WarpColor {
     }
 }
 
+/// Decode the high-band warp color, where 0 follows the main warp color.
+fn warp_high_color_from_value(value: f32) -> Option<WarpColor> {
+    match value.round() as i32 {
+        value if value <= 0 => None,
+        value => Some(WarpColor::from_value(value as f32 - 1.0)),
+    }
+}
+
+/// Gesture value shown by the Tension meter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum TensionMeterSource {
+    /// Tension drive sent to the stages, including pull motion.
+    Drive,
+    /// Raw pull envelope, before tension and direction shape it.
+    Envelope,
+    /// Tension after modulation and the floor, before the pull shapes it.
+    Effective,
+}
+
+impl TensionMeterSource {
+    fn from_value(value: f32) -> Self {
+        match value.round() as i32 {
+            1 => Self::Envelope,
+            2 => Self::Effective,
+            _ => Self::Drive,
+        }
+    }
+
+    fn as_value(self) -> f32 {
+        match self {
+            Self::Drive => 0.0,
+            Self::Envelope => 1.0,
+            Self::Effective => 2.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Drive => "Drive",
+            Self::Envelope => "Envelope",
+            Self::Effective => "Effective",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "0" | "drive" => Some(Self::Drive),
+            "1" | "envelope" | "env" => Some(Self::Envelope),
+            "2" | "effective" | "eff" => Some(Self::Effective),
+            _ => None,
+        }
+    }
+}
+
+/// Contour of the pull envelope as it falls back after a release.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ReleaseShape {
+    /// One-pole fall that slows as it nears rest (the original contour).
+    Exponential,
+    /// Constant-rate fall.
+    Linear,
+    /// Eases out of the peak, falls fastest mid-way, then eases into rest.
+    SCurve,
+}
+
+impl ReleaseShape {
+    fn from_value(value: f32) -> Self {
+        match value.round() as i32 {
+            1 => Self::Linear,
+            2 => Self::SCurve,
+            _ => Self::Exponential,
+        }
+    }
+
+    fn as_value(self) -> f32 {
+        match self {
+            Self::Exponential => 0.0,
+            Self::Linear => 1.0,
+            Self::SCurve => 2.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Exponential => "Exponential",
+            Self::Linear => "Linear",
+            Self::SCurve => "S-Curve",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "0" | "exponential" | "exp" => Some(Self::Exponential),
+            "1" | "linear" | "lin" => Some(Self::Linear),
+            "2" | "s-curve" | "scurve" | "s" => Some(Self::SCurve),
+            _ => None,
+        }
+    }
+}
+
+/// Waveshaper applied as the final output safety stage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ClipMode {
+    /// Gentle rational curve (the original shaper).
+    Soft,
+    /// Hyperbolic tangent saturation.
+    Tanh,
+    /// Brick-wall clamp at full scale.
+    Hard,
+    /// No waveshaping; energy ceiling, trim, and the limiter set the level.
+    Off,
+}
+
+impl ClipMode {
+    fn from_value(value: f32) -> Self {
+        match value.round() as i32 {
+            1 => Self::Tanh,
+            2 => Self::Hard,
+            3 => Self::Off,
+            _ => Self::Soft,
+        }
+    }
+
+    fn as_value(self) -> f32 {
+        match self {
+            Self::Soft => 0.0,
+            Self::Tanh => 1.0,
+            Self::Hard => 2.0,
+            Self::Off => 3.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Soft => "Soft",
+            Self::Tanh => "Tanh",
+            Self::Hard => "Hard",
+            Self::Off => "Off",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "0" | "soft" => Some(Self::Soft),
+            "1" | "tanh" => Some(Self::Tanh),
+            "2" | "hard" => Some(Self::Hard),
+            "3" | "off" | "none" => Some(Self::Off),
+            _ => None,
+        }
+    }
+}
+
+/// Scales the elastic pitch offset can lock to.
+///
+/// Offsets snap to the scale's intervals above the unshifted pitch, so material
+/// centred on the key's tonic moves between in-key notes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum KeyLock {
+    /// Free, continuous pitch.
+    Off,
+    /// Major scale degrees.
+    Major,
+    /// Natural minor scale degrees.
+    Minor,
+    /// Any semitone.
+    Chromatic,
+}
+
+impl KeyLock {
+    fn from_value(value: f32) -> Self {
+        match value.round() as i32 {
+            1 => Self::Major,
+            2 => Self::Minor,
+            3 => Self::Chromatic,
+            _ => Self::Off,
+        }
+    }
+
+    fn as_value(self) -> f32 {
+        match self {
+            Self::Off => 0.0,
+            Self::Major => 1.0,
+            Self::Minor => 2.0,
+            Self::Chromatic => 3.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Major => "Major",
+            Self::Minor => "Minor",
+            Self::Chromatic => "Chromatic",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "0" | "off" | "free" => Some(Self::Off),
+            "1" | "major" | "maj" => Some(Self::Major),
+            "2" | "minor" | "min" => Some(Self::Minor),
+            "3" | "chromatic" | "chrom" => Some(Self::Chromatic),
+            _ => None,
+        }
+    }
+
+    /// Snap a pitch offset in semitones to the nearest scale degree in any octave.
+    ///
+    /// Returns the offset unchanged when the lock is off.
+    pub(crate) fn snap_semitones(self, semitones: f32) -> f32 {
+        let degrees: &[f32] = match self {
+            Self::Off => return semitones,
+            Self::Major => &[0.0, 2.0, 4.0, 5.0, 7.0, 9.0, 11.0, 12.0],
+            Self::Minor => &[0.0, 2.0, 3.0, 5.0, 7.0, 8.0, 10.0, 12.0],
+            Self::Chromatic => return semitones.round(),
+        };
+        let octave = (semitones / 12.0).floor() * 12.0;
+        let within = semitones - octave;
+        let nearest = degrees.iter().copied().fold(0.0_f32, |best, degree| {
+            if (degree - within).abs() < (best - within).abs() {
+                degree
+            } else {
+                best
+            }
+        });
+        octave + nearest
+    }
+}
+
 /// Character modes for the elastic and warp processing.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum CharacterMode {
@@ -459,6 +773,10 @@ pub(crate) struct ModSourceSettings {
     pub rate_division: PullDivision,
     /// Output depth applied before route depths.
     pub depth: f32,
+    /// Envelope-follower attack time in milliseconds.
+    pub env_attack_ms: f32,
+    /// Envelope-follower release time in milliseconds.
+    pub env_release_ms: f32,
 }
 
 /// Modulation matrix settings used by the DSP engine.
@@ -472,6 +790,8 @@ pub(crate) struct ModSettings {
     pub source_b: ModSourceSettings,
     /// Route depths for sources x destinations.
     pub route_depths: [[f32; ROUTE_DEST_COUNT]; 2],
+    /// Random phase wander applied to synced source cycles (0 = locked).
+    pub humanize: f32,
 }
 
 /// Snapshot of all parameters used by the DSP engine.
@@ -495,6 +815,8 @@ pub(crate) struct TensionFieldSettings {
     pub pull_trigger: bool,
     /// Latching pull mode.
     pub pull_latch: bool,
+    /// Hold engaged (also folded into `pull_latch`).
+    pub hold: bool,
     /// Quantization amount for pull launches.
     pub pull_quantize: PullQuantize,
     /// Release rebound amount.
@@ -519,8 +841,8 @@ pub(crate) struct TensionFieldSettings {
     pub diffusion: f32,
     /// High-frequency damping amount.
     pub air_damping: f32,
-    /// High-frequency compensation toggle.
-    pub air_compensation: bool,
+    /// Effective high-frequency compensation amount (0 when the toggle is off).
+    pub air_compensation: f32,
     /// Character mode.
     pub character: CharacterMode,
     /// Controlled feedback amount.
@@ -531,6 +853,114 @@ pub(crate) struct TensionFieldSettings {
     pub output_trim_db: f32,
     /// Soft safety amount that attenuates excessive energy build-up.
     pub energy_ceiling: f32,
+    /// Character-dependent output makeup toggle.
+    pub character_makeup: bool,
+    /// Output gain reduction amount driven by pull tension (0..1).
+    pub pull_duck: f32,
+    /// Spring stiffness offset added to elasticity for delay smoothing (-1..1).
+    pub spring: f32,
+    /// Phase offset applied to the pull shape (0..1 of a cycle).
+    pub pull_phase: f32,
+    /// Invert feedback polarity before it is summed with the input.
+    pub feedback_invert: bool,
+    /// Pull envelope floor that keeps the shape animating at rest (0..1).
+    pub idle_motion: f32,
+    /// Collapse the monitored output to mono for compatibility checks.
+    pub mono_monitor: bool,
+    /// Baseline warp drift rate (Hz when free, cycles per pull division when synced).
+    pub warp_rate_hz: f32,
+    /// Free-running or pull-division synced baseline warp drift.
+    pub warp_rate_mode: ModRateMode,
+    /// Emit throttled host-log warnings when the output overshoots.
+    pub verbose_log: bool,
+    /// Feedback bias between channels (-1 = left only, +1 = right only).
+    pub feedback_balance: f32,
+    /// Safety limiter lookahead in milliseconds (adds matching latency).
+    pub limit_lookahead_ms: f32,
+    /// Elastic jitter skew (-1 = shorter delays, +1 = longer delays).
+    pub grain_bias: f32,
+    /// How strongly input attacks snap the elastic read speed back to 1.0.
+    pub transient_preserve: f32,
+    /// Pull-direction auto-return speed after release (0 = off).
+    pub dir_return: f32,
+    /// Crossover below which the warp leaves the signal untouched (0 = full band).
+    pub warp_low_keep_hz: f32,
+    /// Launch only on grid boundaries that are a multiple of this many grids.
+    pub quantize_multiple: u32,
+    /// Whether MIDI sustain (CC64) holds pulls after their trigger is released.
+    pub sustain_pedal: bool,
+    /// Random phase wander applied to synced gesture and mod cycles (0 = locked).
+    pub humanize: f32,
+    /// Whether a second, offset gesture engine drives the right channel.
+    pub dual_gesture: bool,
+    /// Pre-gain into the character saturation in dB, compensated on the way out.
+    pub char_drive_db: f32,
+    /// Half/double-time multiplier on the pull rate or division.
+    pub rate_multiplier: RateMultiplier,
+    /// Minimum effective tension so the effect never goes fully inert.
+    pub tension_floor: f32,
+    /// Scale the coupled elastic pitch offset snaps to, or free pitch when off.
+    pub key_lock: KeyLock,
+    /// Space size scaling the space stage delay lengths (0.2 = original size).
+    pub space_size: f32,
+    /// Separate warp color for the high band, or `None` to follow `warp_color`.
+    pub warp_high_color: Option<WarpColor>,
+    /// While Hold is on, freeze the elastic buffer and scrub it with the map X axis.
+    pub scrub: bool,
+    /// Which gesture value the Tension meter reports.
+    pub tension_meter: TensionMeterSource,
+    /// Tilt on the diffusion input (-1 = dark, 0 = neutral, 1 = bright).
+    pub space_tone: f32,
+    /// Scale free-mode rates by host tempo relative to 120 BPM.
+    pub tempo_follow: bool,
+    /// Delay applied to the right channel after the space stage, in milliseconds.
+    pub haas_ms: f32,
+    /// Fire pulls from the step pattern on each sequencer step.
+    pub sequencer: bool,
+    /// Synced length of one sequencer step.
+    pub seq_division: PullDivision,
+    /// Number of pattern steps the sequencer cycles through.
+    pub seq_steps: usize,
+    /// Air-band corner frequency in Hz at zero damping.
+    pub air_freq_hz: f32,
+    /// Blend from the dry input (0) to the processed signal (1).
+    pub mix: f32,
+    /// One-pole smoothing applied to the pull shape output (0 = hard steps).
+    pub shape_smooth: f32,
+    /// Input gate threshold in dBFS (-96 = off).
+    pub input_gate_db: f32,
+    /// Warp motion response-curve exponent (1 = linear, higher = subtler low end).
+    pub warp_motion_curve: f32,
+    /// Final output clip shaper.
+    pub clip_mode: ClipMode,
+    /// Contour of the pull envelope release.
+    pub release_shape: ReleaseShape,
+    /// Crush character bit depth (2..16 bits).
+    pub crush_depth_bits: f32,
+    /// Rotation of the stereo field in the space stage, in degrees (positive turns toward the right).
+    pub image_rotate: f32,
+    /// Crush character sample-and-hold factor (1 = off, up to 32).
+    pub downsample_factor: f32,
+    /// Quantized launch strength (0 = fire at the press, 1 = fire on the grid).
+    pub quantize_strength: f32,
+    /// Feedback path high-pass cutoff in Hz.
+    pub feedback_hpf_hz: f32,
+    /// Feedback path brightness (1 = full band, lower darkens each repeat).
+    pub feedback_tone: f32,
+    /// Elasticity to width coupling (0 = off, 1 = full).
+    pub elasticity_to_width: f32,
+    /// Usable elastic buffer length in seconds; scales the delay range.
+    pub elastic_size_seconds: f32,
+    /// Freeze the elastic buffer so the read heads loop the stored audio.
+    pub freeze: bool,
+    /// Narrow the applied width as feedback rises, for a coherent image.
+    pub width_safety: bool,
+    /// Run the elastic read heads backward through the buffer.
+    pub elastic_reverse: bool,
+    /// Enables the 2x oversampled true-peak limiter ahead of the final clip (fixed per activation).
+    pub true_peak_limit: bool,
+    /// Holds the output under the safety limiter ceiling ahead of the final clip.
+    pub safety_limit: bool,
     /// Modulation matrix runtime configuration.
     pub modulation: ModSettings,
 }
@@ -565,6 +995,65 @@ pub(crate) struct TensionFieldParams {
     ducking: AtomicF32,
     output_trim_db: AtomicF32,
     energy_ceiling: AtomicF32,
+    character_makeup: AtomicU32,
+    pull_duck: AtomicF32,
+    spring: AtomicF32,
+    air_compensation_amount: AtomicF32,
+    pull_phase: AtomicF32,
+    feedback_invert: AtomicU32,
+    idle_motion: AtomicF32,
+    mono_monitor: AtomicU32,
+    warp_rate_hz: AtomicF32,
+    warp_rate_mode: AtomicF32,
+    verbose_log: AtomicU32,
+    feedback_balance: AtomicF32,
+    limit_lookahead_ms: AtomicF32,
+    grain_bias: AtomicF32,
+    transient_preserve: AtomicF32,
+    dir_return: AtomicF32,
+    mod_a_env_attack: AtomicF32,
+    mod_a_env_release: AtomicF32,
+    mod_b_env_attack: AtomicF32,
+    mod_b_env_release: AtomicF32,
+    warp_low_keep_hz: AtomicF32,
+    quantize_multiple: AtomicF32,
+    sustain_pedal: AtomicU32,
+    humanize: AtomicF32,
+    dual_gesture: AtomicU32,
+    char_drive_db: AtomicF32,
+    rate_mult: AtomicF32,
+    tension_floor: AtomicF32,
+    key_lock: AtomicF32,
+    space_size: AtomicF32,
+    warp_high_color: AtomicF32,
+    scrub: AtomicU32,
+    tension_meter: AtomicF32,
+    space_tone: AtomicF32,
+    tempo_follow: AtomicU32,
+    haas_ms: AtomicF32,
+    seq_enable: AtomicU32,
+    seq_division: AtomicF32,
+    seq_steps: AtomicF32,
+    air_freq_hz: AtomicF32,
+    mix: AtomicF32,
+    shape_smooth: AtomicF32,
+    input_gate_db: AtomicF32,
+    warp_motion_curve: AtomicF32,
+    clip_mode: AtomicF32,
+    release_shape: AtomicF32,
+    crush_depth: AtomicF32,
+    image_rotate: AtomicF32,
+    downsample: AtomicF32,
+    quantize_strength: AtomicF32,
+    feedback_hpf: AtomicF32,
+    feedback_tone: AtomicF32,
+    elasticity_to_width: AtomicF32,
+    elastic_size: AtomicF32,
+    freeze: AtomicU32,
+    width_safety: AtomicU32,
+    elastic_reverse: AtomicU32,
+    true_peak_limit: AtomicU32,
+    safety_limit: AtomicU32,
     mod_run: AtomicU32,
     mod_a_shape: AtomicF32,
     mod_a_rate_mode: AtomicF32,
@@ -612,6 +1101,65 @@ impl TensionFieldParams {
             ducking: AtomicF32::new(0.0),
             output_trim_db: AtomicF32::new(0.0),
             energy_ceiling: AtomicF32::new(0.7),
+            character_makeup: AtomicU32::new(0),
+            pull_duck: AtomicF32::new(0.0),
+            spring: AtomicF32::new(0.0),
+            air_compensation_amount: AtomicF32::new(1.0),
+            pull_phase: AtomicF32::new(0.0),
+            feedback_invert: AtomicU32::new(0),
+            idle_motion: AtomicF32::new(0.0),
+            mono_monitor: AtomicU32::new(0),
+            warp_rate_hz: AtomicF32::new(0.0),
+            warp_rate_mode: AtomicF32::new(ModRateMode::FreeHz.as_value()),
+            verbose_log: AtomicU32::new(0),
+            feedback_balance: AtomicF32::new(0.0),
+            limit_lookahead_ms: AtomicF32::new(0.0),
+            grain_bias: AtomicF32::new(0.0),
+            transient_preserve: AtomicF32::new(0.0),
+            dir_return: AtomicF32::new(0.0),
+            mod_a_env_attack: AtomicF32::new(0.35),
+            mod_a_env_release: AtomicF32::new(0.35),
+            mod_b_env_attack: AtomicF32::new(0.35),
+            mod_b_env_release: AtomicF32::new(0.35),
+            warp_low_keep_hz: AtomicF32::new(0.0),
+            quantize_multiple: AtomicF32::new(1.0),
+            sustain_pedal: AtomicU32::new(1),
+            humanize: AtomicF32::new(0.0),
+            dual_gesture: AtomicU32::new(0),
+            char_drive_db: AtomicF32::new(0.0),
+            rate_mult: AtomicF32::new(RateMultiplier::Normal.as_value()),
+            tension_floor: AtomicF32::new(0.0),
+            key_lock: AtomicF32::new(KeyLock::Off.as_value()),
+            space_size: AtomicF32::new(0.2),
+            warp_high_color: AtomicF32::new(0.0),
+            scrub: AtomicU32::new(0),
+            tension_meter: AtomicF32::new(0.0),
+            space_tone: AtomicF32::new(0.0),
+            tempo_follow: AtomicU32::new(0),
+            haas_ms: AtomicF32::new(0.0),
+            seq_enable: AtomicU32::new(0),
+            seq_division: AtomicF32::new(PullDivision::Div1_16.as_value()),
+            seq_steps: AtomicF32::new(1.0),
+            air_freq_hz: AtomicF32::new(1000.0),
+            mix: AtomicF32::new(1.0),
+            shape_smooth: AtomicF32::new(0.0),
+            input_gate_db: AtomicF32::new(INPUT_GATE_OFF_DB),
+            warp_motion_curve: AtomicF32::new(1.0),
+            clip_mode: AtomicF32::new(ClipMode::Soft.as_value()),
+            release_shape: AtomicF32::new(ReleaseShape::Exponential.as_value()),
+            crush_depth: AtomicF32::new(7.0),
+            image_rotate: AtomicF32::new(0.0),
+            downsample: AtomicF32::new(1.0),
+            quantize_strength: AtomicF32::new(1.0),
+            feedback_hpf: AtomicF32::new(20.0),
+            feedback_tone: AtomicF32::new(1.0),
+            elasticity_to_width: AtomicF32::new(0.0),
+            elastic_size: AtomicF32::new(2.75),
+            freeze: AtomicU32::new(0),
+            width_safety: AtomicU32::new(0),
+            elastic_reverse: AtomicU32::new(0),
+            true_peak_limit: AtomicU32::new(0),
+            safety_limit: AtomicU32::new(0),
             mod_run: AtomicU32::new(1),
             mod_a_shape: AtomicF32::new(ModSourceShape::Sine.as_value()),
             mod_a_rate_mode: AtomicF32::new(ModRateMode::SyncDivision.as_value()),
@@ -681,6 +1229,97 @@ impl TensionFieldParams {
             PARAM_DUCKING_ID => self.ducking.store(clamp(value, 0.0, 1.0)),
             PARAM_OUTPUT_TRIM_DB_ID => self.output_trim_db.store(clamp(value, -12.0, 6.0)),
             PARAM_ENERGY_CEILING_ID => self.energy_ceiling.store(clamp(value, 0.0, 1.0)),
+            PARAM_CHAR_MAKEUP_ID => self
+                .character_makeup
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_PULL_DUCK_ID => self.pull_duck.store(clamp(value, 0.0, 1.0)),
+            PARAM_SPRING_ID => self.spring.store(clamp(value, -1.0, 1.0)),
+            PARAM_AIR_COMP_AMOUNT_ID => self.air_compensation_amount.store(clamp(value, 0.0, 1.0)),
+            PARAM_PULL_PHASE_ID => self.pull_phase.store(clamp(value, 0.0, 1.0)),
+            PARAM_FEEDBACK_POLARITY_ID => self
+                .feedback_invert
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_IDLE_MOTION_ID => self.idle_motion.store(clamp(value, 0.0, 1.0)),
+            PARAM_MONO_MONITOR_ID => self
+                .mono_monitor
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_WARP_RATE_ID => self.warp_rate_hz.store(clamp(value, 0.0, 8.0)),
+            PARAM_WARP_RATE_MODE_ID => self.warp_rate_mode.store(clamp(value, 0.0, 1.0).round()),
+            PARAM_VERBOSE_LOG_ID => self
+                .verbose_log
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_FEEDBACK_BALANCE_ID => self.feedback_balance.store(clamp(value, -1.0, 1.0)),
+            PARAM_LIMIT_LOOKAHEAD_MS_ID => self.limit_lookahead_ms.store(clamp(value, 0.0, 10.0)),
+            PARAM_GRAIN_BIAS_ID => self.grain_bias.store(clamp(value, -1.0, 1.0)),
+            PARAM_TRANSIENT_PRESERVE_ID => self.transient_preserve.store(clamp(value, 0.0, 1.0)),
+            PARAM_DIR_RETURN_ID => self.dir_return.store(clamp(value, 0.0, 1.0)),
+            PARAM_MOD_A_ENV_ATTACK_ID => self.mod_a_env_attack.store(clamp(value, 0.1, 1000.0)),
+            PARAM_MOD_A_ENV_RELEASE_ID => self.mod_a_env_release.store(clamp(value, 0.1, 1000.0)),
+            PARAM_MOD_B_ENV_ATTACK_ID => self.mod_b_env_attack.store(clamp(value, 0.1, 1000.0)),
+            PARAM_MOD_B_ENV_RELEASE_ID => self.mod_b_env_release.store(clamp(value, 0.1, 1000.0)),
+            PARAM_WARP_LOW_KEEP_HZ_ID => self.warp_low_keep_hz.store(clamp(value, 0.0, 1000.0)),
+            PARAM_QUANTIZE_MULTIPLE_ID => self
+                .quantize_multiple
+                .store(clamp(value, 1.0, 16.0).round()),
+            PARAM_SUSTAIN_PEDAL_ID => self
+                .sustain_pedal
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_HUMANIZE_ID => self.humanize.store(clamp(value, 0.0, 1.0)),
+            PARAM_DUAL_GESTURE_ID => self
+                .dual_gesture
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_CHAR_DRIVE_ID => self.char_drive_db.store(clamp(value, 0.0, 24.0)),
+            PARAM_RATE_MULT_ID => self.rate_mult.store(clamp(value, 0.0, 2.0).round()),
+            PARAM_TENSION_FLOOR_ID => self.tension_floor.store(clamp(value, 0.0, 1.0)),
+            PARAM_KEY_LOCK_ID => self.key_lock.store(clamp(value, 0.0, 3.0).round()),
+            PARAM_SPACE_SIZE_ID => self.space_size.store(clamp(value, 0.0, 1.0)),
+            PARAM_WARP_HIGH_COLOR_ID => self.warp_high_color.store(clamp(value, 0.0, 3.0).round()),
+            PARAM_SCRUB_ID => self
+                .scrub
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_TENSION_METER_ID => self.tension_meter.store(clamp(value, 0.0, 2.0).round()),
+            PARAM_SPACE_TONE_ID => self.space_tone.store(clamp(value, -1.0, 1.0)),
+            PARAM_TEMPO_FOLLOW_ID => self
+                .tempo_follow
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_HAAS_MS_ID => self.haas_ms.store(clamp(value, 0.0, 20.0)),
+            PARAM_SEQ_ENABLE_ID => self
+                .seq_enable
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_SEQ_DIVISION_ID => self.seq_division.store(clamp(value, 0.0, 7.0).round()),
+            PARAM_SEQ_STEPS_ID => self.seq_steps.store(clamp(value, 0.0, 1.0).round()),
+            PARAM_AIR_FREQ_HZ_ID => self.air_freq_hz.store(clamp(value, 200.0, 8000.0)),
+            PARAM_MIX_ID => self.mix.store(clamp(value, 0.0, 1.0)),
+            PARAM_SHAPE_SMOOTH_ID => self.shape_smooth.store(clamp(value, 0.0, 1.0)),
+            PARAM_INPUT_GATE_ID => self
+                .input_gate_db
+                .store(clamp(value, INPUT_GATE_OFF_DB, -24.0)),
+            PARAM_WARP_MOTION_CURVE_ID => self.warp_motion_curve.store(clamp(value, 0.5, 4.0)),
+            PARAM_CLIP_MODE_ID => self.clip_mode.store(clamp(value, 0.0, 3.0).round()),
+            PARAM_RELEASE_SHAPE_ID => self.release_shape.store(clamp(value, 0.0, 2.0).round()),
+            PARAM_CRUSH_DEPTH_ID => self.crush_depth.store(clamp(value, 2.0, 16.0).round()),
+            PARAM_IMAGE_ROTATE_ID => self.image_rotate.store(clamp(value, -45.0, 45.0)),
+            PARAM_DOWNSAMPLE_ID => self.downsample.store(clamp(value, 1.0, 32.0).round()),
+            PARAM_QUANTIZE_STRENGTH_ID => self.quantize_strength.store(clamp(value, 0.0, 1.0)),
+            PARAM_FEEDBACK_HPF_ID => self.feedback_hpf.store(clamp(value, 20.0, 800.0)),
+            PARAM_FEEDBACK_TONE_ID => self.feedback_tone.store(clamp(value, 0.0, 1.0)),
+            PARAM_ELASTICITY_TO_WIDTH_ID => self.elasticity_to_width.store(clamp(value, 0.0, 1.0)),
+            PARAM_ELASTIC_SIZE_ID => self.elastic_size.store(clamp(value, 0.25, 6.0)),
+            PARAM_FREEZE_ID => self
+                .freeze
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_WIDTH_SAFETY_ID => self
+                .width_safety
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_ELASTIC_REVERSE_ID => self
+                .elastic_reverse
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_LIMITER_ID => self
+                .true_peak_limit
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
+            PARAM_SAFETY_LIMIT_ID => self
+                .safety_limit
+                .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
             PARAM_MOD_RUN_ID => self
                 .mod_run
                 .store(bool_to_u32(value >= 0.5), Ordering::Relaxed),
@@ -747,6 +1386,89 @@ impl TensionFieldParams {
             PARAM_DUCKING_ID => Some(self.ducking.load()),
             PARAM_OUTPUT_TRIM_DB_ID => Some(self.output_trim_db.load()),
             PARAM_ENERGY_CEILING_ID => Some(self.energy_ceiling.load()),
+            PARAM_CHAR_MAKEUP_ID => {
+                Some(u32_to_bool(self.character_makeup.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_PULL_DUCK_ID => Some(self.pull_duck.load()),
+            PARAM_SPRING_ID => Some(self.spring.load()),
+            PARAM_AIR_COMP_AMOUNT_ID => Some(self.air_compensation_amount.load()),
+            PARAM_PULL_PHASE_ID => Some(self.pull_phase.load()),
+            PARAM_FEEDBACK_POLARITY_ID => {
+                Some(u32_to_bool(self.feedback_invert.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_IDLE_MOTION_ID => Some(self.idle_motion.load()),
+            PARAM_MONO_MONITOR_ID => {
+                Some(u32_to_bool(self.mono_monitor.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_WARP_RATE_ID => Some(self.warp_rate_hz.load()),
+            PARAM_WARP_RATE_MODE_ID => Some(self.warp_rate_mode.load()),
+            PARAM_VERBOSE_LOG_ID => {
+                Some(u32_to_bool(self.verbose_log.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_FEEDBACK_BALANCE_ID => Some(self.feedback_balance.load()),
+            PARAM_LIMIT_LOOKAHEAD_MS_ID => Some(self.limit_lookahead_ms.load()),
+            PARAM_GRAIN_BIAS_ID => Some(self.grain_bias.load()),
+            PARAM_TRANSIENT_PRESERVE_ID => Some(self.transient_preserve.load()),
+            PARAM_DIR_RETURN_ID => Some(self.dir_return.load()),
+            PARAM_MOD_A_ENV_ATTACK_ID => Some(self.mod_a_env_attack.load()),
+            PARAM_MOD_A_ENV_RELEASE_ID => Some(self.mod_a_env_release.load()),
+            PARAM_MOD_B_ENV_ATTACK_ID => Some(self.mod_b_env_attack.load()),
+            PARAM_MOD_B_ENV_RELEASE_ID => Some(self.mod_b_env_release.load()),
+            PARAM_WARP_LOW_KEEP_HZ_ID => Some(self.warp_low_keep_hz.load()),
+            PARAM_QUANTIZE_MULTIPLE_ID => Some(self.quantize_multiple.load()),
+            PARAM_SUSTAIN_PEDAL_ID => {
+                Some(u32_to_bool(self.sustain_pedal.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_HUMANIZE_ID => Some(self.humanize.load()),
+            PARAM_DUAL_GESTURE_ID => {
+                Some(u32_to_bool(self.dual_gesture.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_CHAR_DRIVE_ID => Some(self.char_drive_db.load()),
+            PARAM_RATE_MULT_ID => Some(self.rate_mult.load()),
+            PARAM_TENSION_FLOOR_ID => Some(self.tension_floor.load()),
+            PARAM_KEY_LOCK_ID => Some(self.key_lock.load()),
+            PARAM_SPACE_SIZE_ID => Some(self.space_size.load()),
+            PARAM_WARP_HIGH_COLOR_ID => Some(self.warp_high_color.load()),
+            PARAM_SCRUB_ID => Some(u32_to_bool(self.scrub.load(Ordering::Relaxed)) as u8 as f32),
+            PARAM_TENSION_METER_ID => Some(self.tension_meter.load()),
+            PARAM_SPACE_TONE_ID => Some(self.space_tone.load()),
+            PARAM_TEMPO_FOLLOW_ID => {
+                Some(u32_to_bool(self.tempo_follow.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_HAAS_MS_ID => Some(self.haas_ms.load()),
+            PARAM_SEQ_ENABLE_ID => {
+                Some(u32_to_bool(self.seq_enable.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_SEQ_DIVISION_ID => Some(self.seq_division.load()),
+            PARAM_SEQ_STEPS_ID => Some(self.seq_steps.load()),
+            PARAM_AIR_FREQ_HZ_ID => Some(self.air_freq_hz.load()),
+            PARAM_MIX_ID => Some(self.mix.load()),
+            PARAM_SHAPE_SMOOTH_ID => Some(self.shape_smooth.load()),
+            PARAM_INPUT_GATE_ID => Some(self.input_gate_db.load()),
+            PARAM_WARP_MOTION_CURVE_ID => Some(self.warp_motion_curve.load()),
+            PARAM_CLIP_MODE_ID => Some(self.clip_mode.load()),
+            PARAM_RELEASE_SHAPE_ID => Some(self.release_shape.load()),
+            PARAM_CRUSH_DEPTH_ID => Some(self.crush_depth.load()),
+            PARAM_IMAGE_ROTATE_ID => Some(self.image_rotate.load()),
+            PARAM_DOWNSAMPLE_ID => Some(self.downsample.load()),
+            PARAM_QUANTIZE_STRENGTH_ID => Some(self.quantize_strength.load()),
+            PARAM_FEEDBACK_HPF_ID => Some(self.feedback_hpf.load()),
+            PARAM_FEEDBACK_TONE_ID => Some(self.feedback_tone.load()),
+            PARAM_ELASTICITY_TO_WIDTH_ID => Some(self.elasticity_to_width.load()),
+            PARAM_ELASTIC_SIZE_ID => Some(self.elastic_size.load()),
+            PARAM_FREEZE_ID => Some(u32_to_bool(self.freeze.load(Ordering::Relaxed)) as u8 as f32),
+            PARAM_WIDTH_SAFETY_ID => {
+                Some(u32_to_bool(self.width_safety.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_ELASTIC_REVERSE_ID => {
+                Some(u32_to_bool(self.elastic_reverse.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_LIMITER_ID => {
+                Some(u32_to_bool(self.true_peak_limit.load(Ordering::Relaxed)) as u8 as f32)
+            }
+            PARAM_SAFETY_LIMIT_ID => {
+                Some(u32_to_bool(self.safety_limit.load(Ordering::Relaxed)) as u8 as f32)
+            }
             PARAM_MOD_RUN_ID => {
                 Some(u32_to_bool(self.mod_run.load(Ordering::Relaxed)) as u8 as f32)
             }
@@ -792,6 +1514,7 @@ impl TensionFieldParams {
             pull_trigger: u32_to_bool(self.pull_trigger.load(Ordering::Relaxed)),
             pull_latch: u32_to_bool(self.pull_latch.load(Ordering::Relaxed))
                 || u32_to_bool(self.hold.load(Ordering::Relaxed)),
+            hold: u32_to_bool(self.hold.load(Ordering::Relaxed)),
             pull_quantize: PullQuantize::from_value(self.pull_quantize.load()),
             rebound: self.rebound.load(),
             release_snap: self.release_snap.load(),
@@ -804,12 +1527,70 @@ impl TensionFieldParams {
             width: self.width.load(),
             diffusion: self.diffusion.load(),
             air_damping: self.air_damping.load(),
-            air_compensation: u32_to_bool(self.air_compensation.load(Ordering::Relaxed)),
+            air_compensation: if u32_to_bool(self.air_compensation.load(Ordering::Relaxed)) {
+                self.air_compensation_amount.load()
+            } else {
+                0.0
+            },
             character: CharacterMode::from_value(self.clean_dirty.load()),
             feedback: self.feedback.load(),
             ducking: self.ducking.load(),
             output_trim_db: self.output_trim_db.load(),
             energy_ceiling: self.energy_ceiling.load(),
+            character_makeup: u32_to_bool(self.character_makeup.load(Ordering::Relaxed)),
+            pull_duck: self.pull_duck.load(),
+            spring: self.spring.load(),
+            pull_phase: self.pull_phase.load(),
+            feedback_invert: u32_to_bool(self.feedback_invert.load(Ordering::Relaxed)),
+            idle_motion: self.idle_motion.load(),
+            mono_monitor: u32_to_bool(self.mono_monitor.load(Ordering::Relaxed)),
+            warp_rate_hz: self.warp_rate_hz.load(),
+            warp_rate_mode: ModRateMode::from_value(self.warp_rate_mode.load()),
+            verbose_log: u32_to_bool(self.verbose_log.load(Ordering::Relaxed)),
+            feedback_balance: self.feedback_balance.load(),
+            limit_lookahead_ms: self.limit_lookahead_ms.load(),
+            grain_bias: self.grain_bias.load(),
+            transient_preserve: self.transient_preserve.load(),
+            dir_return: self.dir_return.load(),
+            warp_low_keep_hz: self.warp_low_keep_hz.load(),
+            quantize_multiple: self.quantize_multiple.load() as u32,
+            sustain_pedal: u32_to_bool(self.sustain_pedal.load(Ordering::Relaxed)),
+            humanize: self.humanize.load(),
+            dual_gesture: u32_to_bool(self.dual_gesture.load(Ordering::Relaxed)),
+            char_drive_db: self.char_drive_db.load(),
+            rate_multiplier: RateMultiplier::from_value(self.rate_mult.load()),
+            tension_floor: self.tension_floor.load(),
+            key_lock: KeyLock::from_value(self.key_lock.load()),
+            space_size: self.space_size.load(),
+            warp_high_color: warp_high_color_from_value(self.warp_high_color.load()),
+            scrub: u32_to_bool(self.scrub.load(Ordering::Relaxed)),
+            tension_meter: TensionMeterSource::from_value(self.tension_meter.load()),
+            space_tone: self.space_tone.load(),
+            tempo_follow: u32_to_bool(self.tempo_follow.load(Ordering::Relaxed)),
+            haas_ms: self.haas_ms.load(),
+            sequencer: u32_to_bool(self.seq_enable.load(Ordering::Relaxed)),
+            seq_division: PullDivision::from_value(self.seq_division.load()),
+            seq_steps: seq_step_count(self.seq_steps.load()),
+            air_freq_hz: self.air_freq_hz.load(),
+            mix: self.mix.load(),
+            shape_smooth: self.shape_smooth.load(),
+            input_gate_db: self.input_gate_db.load(),
+            warp_motion_curve: self.warp_motion_curve.load(),
+            clip_mode: ClipMode::from_value(self.clip_mode.load()),
+            release_shape: ReleaseShape::from_value(self.release_shape.load()),
+            crush_depth_bits: self.crush_depth.load(),
+            image_rotate: self.image_rotate.load(),
+            downsample_factor: self.downsample.load(),
+            quantize_strength: self.quantize_strength.load(),
+            feedback_hpf_hz: self.feedback_hpf.load(),
+            feedback_tone: self.feedback_tone.load(),
+            elasticity_to_width: self.elasticity_to_width.load(),
+            elastic_size_seconds: self.elastic_size.load(),
+            freeze: u32_to_bool(self.freeze.load(Ordering::Relaxed)),
+            width_safety: u32_to_bool(self.width_safety.load(Ordering::Relaxed)),
+            elastic_reverse: u32_to_bool(self.elastic_reverse.load(Ordering::Relaxed)),
+            true_peak_limit: u32_to_bool(self.true_peak_limit.load(Ordering::Relaxed)),
+            safety_limit: u32_to_bool(self.safety_limit.load(Ordering::Relaxed)),
             modulation: ModSettings {
                 run: u32_to_bool(self.mod_run.load(Ordering::Relaxed)),
                 source_a: ModSourceSettings {
@@ -818,6 +1599,8 @@ impl TensionFieldParams {
                     rate_hz: self.mod_a_rate_hz.load(),
                     rate_division: PullDivision::from_value(self.mod_a_division.load()),
                     depth: self.mod_a_depth.load(),
+                    env_attack_ms: self.mod_a_env_attack.load(),
+                    env_release_ms: self.mod_a_env_release.load(),
                 },
                 source_b: ModSourceSettings {
                     shape: ModSourceShape::from_value(self.mod_b_shape.load()),
@@ -825,8 +1608,11 @@ impl TensionFieldParams {
                     rate_hz: self.mod_b_rate_hz.load(),
                     rate_division: PullDivision::from_value(self.mod_b_division.load()),
                     depth: self.mod_b_depth.load(),
+                    env_attack_ms: self.mod_b_env_attack.load(),
+                    env_release_ms: self.mod_b_env_release.load(),
                 },
                 route_depths: [route_a, route_b],
+                humanize: self.humanize.load(),
             },
         }
     }
@@ -885,6 +1671,26 @@ pub(crate) fn param_count() -> u32 {
     PARAM_DEFS.len() as u32
 }
 
+/// Find a parameter by display name, ignoring case, spaces, and punctuation.
+pub(crate) fn param_id_by_name(name: &str) -> Option<ClapId> {
+    let key = |text: &[u8]| -> Vec<u8> {
+        text.iter()
+            .filter(|byte| byte.is_ascii_alphanumeric())
+            .map(u8::to_ascii_lowercase)
+            .collect()
+    };
+    let wanted = key(name.as_bytes());
+    PARAM_DEFS
+        .iter()
+        .find(|def| key(def.name) == wanted)
+        .map(|def| def.id)
+}
+
+/// Return whether `param_id` names a host-visible parameter.
+pub(crate) fn is_param_id(param_id: ClapId) -> bool {
+    PARAM_DEFS.iter().any(|def| def.id == param_id)
+}
+
 /// Number of serialized parameter values stored in plugin state.
 pub(crate) const STATE_VALUE_COUNT: usize = PARAM_DEFS.len();
 
@@ -913,73 +1719,555 @@ pub(crate) fn apply_state_values(params: &TensionFieldParams, values: [f32; STAT
     }
 }
 
-/// Write parameter metadata for one parameter index.
-pub(crate) fn write_param_info(param_index: u32, writer: &mut ParamInfoWriter) {
-    let Some(def) = PARAM_DEFS.get(param_index as usize) else {
-        return;
-    };
-    def.to_spec().write(writer);
+/// Per-parameter lock flags that protect values from preset recalls.
+pub(crate) struct ParamLocks {
+    flags: [AtomicU32; STATE_VALUE_COUNT],
 }
 
-/// Format a parameter value for host displays.
-pub(crate) fn value_to_text(
-    param_id: ClapId,
-    value: f64,
-    writer: &mut ParamDisplayWriter,
-) -> std::fmt::Result {
-    match param_id {
-        PARAM_TENSION_ID
-        | PARAM_TENSION_BIAS_ID
-        | PARAM_GRAIN_CONTINUITY_ID
-        | PARAM_PITCH_COUPLING_ID
-        | PARAM_WIDTH_ID
-        | PARAM_DIFFUSION_ID
-        | PARAM_AIR_DAMPING_ID
-        | PARAM_ELASTICITY_ID
-        | PARAM_REBOUND_ID
-        | PARAM_RELEASE_SNAP_ID
-        | PARAM_FEEDBACK_ID
-        | PARAM_SWING_ID
-        | PARAM_WARP_MOTION_ID
-        | PARAM_DUCKING_ID
-        | PARAM_ENERGY_CEILING_ID
-        | PARAM_MOD_A_DEPTH_ID
-        | PARAM_MOD_B_DEPTH_ID => write!(writer, "{:.0}%", value * 100.0),
-        PARAM_PULL_RATE_ID | PARAM_MOD_A_RATE_HZ_ID | PARAM_MOD_B_RATE_HZ_ID => {
-            write!(writer, "{value:.2} Hz")
-        }
-        PARAM_PULL_SHAPE_ID => write!(writer, "{}", PullShape::from_value(value as f32).label()),
-        PARAM_TIME_MODE_ID => write!(writer, "{}", TimeMode::from_value(value as f32).label()),
-        PARAM_PULL_DIVISION_ID | PARAM_MOD_A_DIVISION_ID | PARAM_MOD_B_DIVISION_ID => {
-            write!(writer, "{}", PullDivision::from_value(value as f32).label())
+impl Default for ParamLocks {
+    fn default() -> Self {
+        Self {
+            flags: std::array::from_fn(|_| AtomicU32::new(0)),
         }
-        PARAM_PULL_QUANTIZE_ID => {
-            write!(writer, "{}", PullQuantize::from_value(value as f32).label())
+    }
+}
+
+impl ParamLocks {
+    /// Return whether `param_id` is protected from preset recalls.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn is_locked(&self, param_id: ClapId) -> bool {
+        param_index(param_id)
+            .map(|index| u32_to_bool(self.flags[index].load(Ordering::Relaxed)))
+            .unwrap_or(false)
+    }
+
+    /// Lock or unlock one parameter.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn set_locked(&self, param_id: ClapId, locked: bool) {
+        if let Some(index) = param_index(param_id) {
+            self.flags[index].store(bool_to_u32(locked), Ordering::Relaxed);
         }
-        PARAM_WARP_COLOR_ID => write!(writer, "{}", WarpColor::from_value(value as f32).label()),
-        PARAM_CLEAN_DIRTY_ID => {
-            write!(
-                writer,
-                "{}",
-                CharacterMode::from_value(value as f32).label()
-            )
+    }
+
+    /// Build an ordered lock snapshot in `PARAM_DEFS` order.
+    pub(crate) fn state_values(&self) -> [bool; STATE_VALUE_COUNT] {
+        std::array::from_fn(|index| u32_to_bool(self.flags[index].load(Ordering::Relaxed)))
+    }
+
+    /// Restore lock flags from an ordered snapshot.
+    pub(crate) fn apply_state_values(&self, values: [bool; STATE_VALUE_COUNT]) {
+        for (flag, locked) in self.flags.iter().zip(values) {
+            flag.store(bool_to_u32(locked), Ordering::Relaxed);
         }
-        PARAM_MOD_A_SHAPE_ID | PARAM_MOD_B_SHAPE_ID => {
-            write!(
-                writer,
-                "{}",
-                ModSourceShape::from_value(value as f32).label()
-            )
+    }
+}
+
+/// Number of steps stored in the gesture sequencer pattern.
+pub(crate) const SEQ_STEP_COUNT: usize = 16;
+
+/// Map the Seq Steps parameter value to the number of active pattern steps.
+fn seq_step_count(value: f32) -> usize {
+    if value >= 0.5 { SEQ_STEP_COUNT } else { 8 }
+}
+
+/// Pull intensities for each sequencer step, edited by the GUI and persisted with plugin state.
+pub(crate) struct StepPattern {
+    steps: [AtomicF32; SEQ_STEP_COUNT],
+}
+
+impl Default for StepPattern {
+    /// Full pulls on each beat of a 1/16 grid with lighter offbeat pushes.
+    fn default() -> Self {
+        Self {
+            steps: std::array::from_fn(|index| {
+                AtomicF32::new(match index % 4 {
+                    0 => 1.0,
+                    2 => 0.5,
+                    _ => 0.0,
+                })
+            }),
         }
-        PARAM_MOD_A_RATE_MODE_ID | PARAM_MOD_B_RATE_MODE_ID => {
-            write!(writer, "{}", ModRateMode::from_value(value as f32).label())
+    }
+}
+
+impl StepPattern {
+    /// Return every step intensity (0..1) in order.
+    pub(crate) fn state_values(&self) -> [f32; SEQ_STEP_COUNT] {
+        std::array::from_fn(|index| self.steps[index].load())
+    }
+
+    /// Restore step intensities from an ordered snapshot.
+    pub(crate) fn apply_state_values(&self, values: [f32; SEQ_STEP_COUNT]) {
+        for (step, value) in self.steps.iter().zip(values) {
+            step.store(value.clamp(0.0, 1.0));
         }
-        PARAM_HOLD_ID
-        | PARAM_AIR_COMP_ID
-        | PARAM_PULL_TRIGGER_ID
-        | PARAM_PULL_LATCH_ID
-        | PARAM_MOD_RUN_ID => {
-            if value >= 0.5 {
+    }
+
+    /// Advance one step to the next quarter intensity, wrapping from full back to off.
+    #[cfg(any(target_os = "windows", test))]
+    pub(crate) fn cycle_step(&self, index: usize) -> f32 {
+        let Some(step) = self.steps.get(index) else {
+            return 0.0;
+        };
+        let next = ((step.load() * 4.0).round() + 1.0) % 5.0 / 4.0;
+        step.store(next);
+        next
+    }
+}
+
+/// Largest number of decimal places offered for parameter readouts.
+pub(crate) const MAX_DISPLAY_DECIMALS: u32 = 4;
+
+/// Decimal places used when formatting percentage and Hz readouts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct DisplayPrecision {
+    /// Decimal places for percentage values.
+    pub percent_decimals: u32,
+    /// Decimal places for rate values in Hz.
+    pub hz_decimals: u32,
+}
+
+impl DisplayPrecision {
+    /// Clamp both decimal counts into the supported range.
+    pub(crate) fn clamped(self) -> Self {
+        Self {
+            percent_decimals: self.percent_decimals.min(MAX_DISPLAY_DECIMALS),
+            hz_decimals: self.hz_decimals.min(MAX_DISPLAY_DECIMALS),
+        }
+    }
+}
+
+impl Default for DisplayPrecision {
+    fn default() -> Self {
+        Self {
+            percent_decimals: 0,
+            hz_decimals: 2,
+        }
+    }
+}
+
+/// Readout precision presets shown in the editor.
+#[cfg(target_os = "windows")]
+pub(crate) const DISPLAY_PRECISION_LABELS: [&str; 3] = ["Standard", "Fine", "Finest"];
+
+/// Return the readout precision preset at `index`, matching `DISPLAY_PRECISION_LABELS`.
+#[cfg(target_os = "windows")]
+pub(crate) fn display_precision_preset(index: usize) -> DisplayPrecision {
+    let extra = index.min(DISPLAY_PRECISION_LABELS.len() - 1) as u32;
+    let standard = DisplayPrecision::default();
+    DisplayPrecision {
+        percent_decimals: standard.percent_decimals + extra,
+        hz_decimals: standard.hz_decimals + extra,
+    }
+    .clamped()
+}
+
+/// Default level in dBFS at which the meters switch to the warning color.
+pub(crate) const DEFAULT_METER_WARN_DBFS: f32 = -6.0;
+/// Lowest selectable meter warning level in dBFS.
+const MIN_METER_WARN_DBFS: f32 = -24.0;
+
+/// Meter warning levels offered in the editor, in dBFS.
+#[cfg(target_os = "windows")]
+pub(crate) const METER_WARN_DBFS_OPTIONS: [f32; 5] = [-12.0, -6.0, -3.0, -1.0, 0.0];
+/// Labels for [`METER_WARN_DBFS_OPTIONS`].
+#[cfg(target_os = "windows")]
+pub(crate) const METER_WARN_LABELS: [&str; 5] =
+    ["-12 dBFS", "-6 dBFS", "-3 dBFS", "-1 dBFS", "0 dBFS"];
+
+/// Shared user display and editor preferences, persisted with plugin state.
+pub(crate) struct DisplayPrefs {
+    percent_decimals: AtomicU32,
+    hz_decimals: AtomicU32,
+    pull_toggle: AtomicBool,
+    meter_warn_dbfs: AtomicF32,
+}
+
+impl Default for DisplayPrefs {
+    fn default() -> Self {
+        let precision = DisplayPrecision::default();
+        Self {
+            percent_decimals: AtomicU32::new(precision.percent_decimals),
+            hz_decimals: AtomicU32::new(precision.hz_decimals),
+            pull_toggle: AtomicBool::new(false),
+            meter_warn_dbfs: AtomicF32::new(DEFAULT_METER_WARN_DBFS),
+        }
+    }
+}
+
+impl DisplayPrefs {
+    /// Return the current readout precision.
+    pub(crate) fn precision(&self) -> DisplayPrecision {
+        DisplayPrecision {
+            percent_decimals: self.percent_decimals.load(Ordering::Relaxed),
+            hz_decimals: self.hz_decimals.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Store a new readout precision.
+    pub(crate) fn set_precision(&self, precision: DisplayPrecision) {
+        let precision = precision.clamped();
+        self.percent_decimals
+            .store(precision.percent_decimals, Ordering::Relaxed);
+        self.hz_decimals
+            .store(precision.hz_decimals, Ordering::Relaxed);
+    }
+
+    /// Return whether the editor Pull button latches on click instead of acting momentarily.
+    pub(crate) fn pull_toggle(&self) -> bool {
+        self.pull_toggle.load(Ordering::Relaxed)
+    }
+
+    /// Switch the editor Pull button between momentary and click-to-toggle.
+    pub(crate) fn set_pull_toggle(&self, enabled: bool) {
+        self.pull_toggle.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Return the level in dBFS at which the meters turn to the warning color.
+    pub(crate) fn meter_warn_dbfs(&self) -> f32 {
+        self.meter_warn_dbfs.load()
+    }
+
+    /// Store the meter warning level, clamped to -24..0 dBFS.
+    pub(crate) fn set_meter_warn_dbfs(&self, dbfs: f32) {
+        let dbfs = if dbfs.is_finite() {
+            dbfs.clamp(MIN_METER_WARN_DBFS, 0.0)
+        } else {
+            DEFAULT_METER_WARN_DBFS
+        };
+        self.meter_warn_dbfs.store(dbfs);
+    }
+}
+
+/// Apply preset-style updates, skipping locked parameters.
+///
+/// `on_applied` is called after each parameter that was actually written.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn apply_unlocked_updates(
+    params: &TensionFieldParams,
+    locks: &ParamLocks,
+    updates: &[(ClapId, f32)],
+    mut on_applied: impl FnMut(ClapId, f32),
+) {
+    for &(param_id, value) in updates {
+        if locks.is_locked(param_id) {
+            continue;
+        }
+        params.set_param(param_id, value);
+        on_applied(param_id, value);
+    }
+}
+
+/// Return every parameter to its `ParamDef::default_value`, ignoring preset locks.
+///
+/// `on_applied` is called after each parameter is written.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn reset_all(params: &TensionFieldParams, mut on_applied: impl FnMut(ClapId, f32)) {
+    for def in PARAM_DEFS {
+        let value = def.default_value as f32;
+        params.set_param(def.id, value);
+        on_applied(def.id, value);
+    }
+}
+
+/// Category tags that group bank presets in the editor.
+#[cfg(any(target_os = "windows", test))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum PresetTag {
+    /// Grid-locked pulses and pumping.
+    Rhythmic,
+    /// Wide, diffuse or long-tailed textures.
+    Ambient,
+    /// Driven, crushed or forward-leaning strain.
+    Aggressive,
+}
+
+/// Preset-bank filter labels; index 0 shows every preset.
+#[cfg(target_os = "windows")]
+pub(crate) const PRESET_FILTER_LABELS: [&str; 4] = ["All", "Rhythmic", "Ambient", "Aggressive"];
+
+/// Return the tag selected by the preset filter at `index`, matching `PRESET_FILTER_LABELS`.
+#[cfg(target_os = "windows")]
+pub(crate) fn preset_filter_tag(index: usize) -> Option<PresetTag> {
+    match index {
+        1 => Some(PresetTag::Rhythmic),
+        2 => Some(PresetTag::Ambient),
+        3 => Some(PresetTag::Aggressive),
+        _ => None,
+    }
+}
+
+/// Keep the presets carrying `filter` among their `tags`, in bank order.
+///
+/// `None` keeps every preset.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn filter_presets<T: Copy>(
+    presets: &[T],
+    tags: impl Fn(T) -> &'static [PresetTag],
+    filter: Option<PresetTag>,
+) -> Vec<T> {
+    presets
+        .iter()
+        .copied()
+        .filter(|preset| filter.is_none_or(|tag| tags(*preset).contains(&tag)))
+        .collect()
+}
+
+/// Write the value a momentary (hold-to-engage) editor button maps to and return it.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn apply_momentary(params: &TensionFieldParams, param_id: ClapId, pressed: bool) -> f32 {
+    let value = if pressed { 1.0 } else { 0.0 };
+    params.set_param(param_id, value);
+    value
+}
+
+/// Flip a click-to-toggle editor button's parameter and return the new value.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn apply_click_toggle(params: &TensionFieldParams, param_id: ClapId) -> f32 {
+    let engaged = params.get_param(param_id).unwrap_or(0.0) >= 0.5;
+    let value = if engaged { 0.0 } else { 1.0 };
+    params.set_param(param_id, value);
+    value
+}
+
+/// Render the current parameter values and settings snapshot as JSON for bug reports.
+///
+/// When the audio thread has sanitized non-finite audio, the last snapshot is
+/// appended as `last_panic`; non-finite input samples are written as strings.
+#[cfg(any(target_os = "windows", test))]
+pub(crate) fn debug_dump(
+    params: &TensionFieldParams,
+    sample_rate: f32,
+    panic: Option<&PanicSnapshot>,
+) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "{{");
+    let _ = writeln!(out, "  \"plugin\": \"Tension Field\",");
+    let _ = writeln!(out, "  \"version\": \"{}\",", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(out, "  \"sample_rate\": {sample_rate},");
+    let _ = writeln!(out, "  \"params\": [");
+    for (index, def) in PARAM_DEFS.iter().enumerate() {
+        let value = params.get_param(def.id).unwrap_or(def.default_value as f32);
+        let separator = if index + 1 < PARAM_DEFS.len() {
+            ","
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "    {{\"id\": {}, \"name\": \"{}\", \"module\": \"{}\", \"value\": {value}}}{separator}",
+            def.id.get(),
+            String::from_utf8_lossy(def.name),
+            String::from_utf8_lossy(def.module),
+        );
+    }
+    let _ = writeln!(out, "  ],");
+    let settings = escape_debug(&params.settings());
+    let Some(panic) = panic else {
+        let _ = writeln!(out, "  \"settings\": \"{settings}\"");
+        let _ = write!(out, "}}");
+        return out;
+    };
+    let _ = writeln!(out, "  \"settings\": \"{settings}\",");
+    let _ = writeln!(out, "  \"last_panic\": {{");
+    let _ = writeln!(out, "    \"source\": \"{}\",", panic.source.label());
+    let _ = writeln!(
+        out,
+        "    \"settings\": \"{}\",",
+        escape_debug(&panic.settings)
+    );
+    let samples = panic
+        .recent_input
+        .iter()
+        .map(|(left, right)| format!("[{}, {}]", json_sample(*left), json_sample(*right)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let _ = writeln!(out, "    \"recent_input\": [{samples}]");
+    let _ = writeln!(out, "  }}");
+    let _ = write!(out, "}}");
+    out
+}
+
+#[cfg(any(target_os = "windows", test))]
+fn escape_debug(value: &impl std::fmt::Debug) -> String {
+    format!("{value:?}")
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+}
+
+/// JSON has no NaN or infinity, so those samples are written as strings.
+#[cfg(any(target_os = "windows", test))]
+fn json_sample(sample: f32) -> String {
+    if sample.is_finite() {
+        sample.to_string()
+    } else {
+        format!("\"{sample}\"")
+    }
+}
+
+#[cfg(any(target_os = "windows", test))]
+fn param_index(param_id: ClapId) -> Option<usize> {
+    PARAM_DEFS.iter().position(|def| def.id == param_id)
+}
+
+/// Write parameter metadata for one parameter index.
+pub(crate) fn write_param_info(param_index: u32, writer: &mut ParamInfoWriter) {
+    let Some(def) = PARAM_DEFS.get(param_index as usize) else {
+        return;
+    };
+    def.to_spec().write(writer);
+}
+
+/// Display text for an enum or toggle value outside its parameter's range.
+pub(crate) const INVALID_ENUM_LABEL: &str = "Invalid";
+
+/// Return whether `value` is outside the domain of an enum or toggle parameter.
+///
+/// The `from_value` conversions fall back to their first variant, which would
+/// make a corrupt value read as a plausible setting.
+fn is_invalid_enum_value(param_id: ClapId, value: f64) -> bool {
+    let Some(def) = PARAM_DEFS.iter().find(|def| def.id == param_id) else {
+        return false;
+    };
+    if def.flags & ParamInfoFlags::IS_ENUM.bits() == 0 {
+        return false;
+    }
+    let index = value.round();
+    !index.is_finite() || index < def.min_value || index > def.max_value
+}
+
+/// Format a parameter value for host displays.
+pub(crate) fn value_to_text(
+    param_id: ClapId,
+    value: f64,
+    precision: DisplayPrecision,
+    writer: &mut impl Write,
+) -> std::fmt::Result {
+    if is_invalid_enum_value(param_id, value) {
+        return writer.write_str(INVALID_ENUM_LABEL);
+    }
+    match param_id {
+        PARAM_TENSION_ID
+        | PARAM_TENSION_BIAS_ID
+        | PARAM_GRAIN_CONTINUITY_ID
+        | PARAM_PITCH_COUPLING_ID
+        | PARAM_WIDTH_ID
+        | PARAM_DIFFUSION_ID
+        | PARAM_AIR_DAMPING_ID
+        | PARAM_ELASTICITY_ID
+        | PARAM_REBOUND_ID
+        | PARAM_RELEASE_SNAP_ID
+        | PARAM_FEEDBACK_ID
+        | PARAM_SWING_ID
+        | PARAM_WARP_MOTION_ID
+        | PARAM_DUCKING_ID
+        | PARAM_ENERGY_CEILING_ID
+        | PARAM_MOD_A_DEPTH_ID
+        | PARAM_MOD_B_DEPTH_ID
+        | PARAM_PULL_DUCK_ID
+        | PARAM_SPRING_ID
+        | PARAM_AIR_COMP_AMOUNT_ID
+        | PARAM_PULL_PHASE_ID
+        | PARAM_IDLE_MOTION_ID
+        | PARAM_FEEDBACK_BALANCE_ID
+        | PARAM_GRAIN_BIAS_ID
+        | PARAM_TRANSIENT_PRESERVE_ID
+        | PARAM_DIR_RETURN_ID
+        | PARAM_HUMANIZE_ID
+        | PARAM_TENSION_FLOOR_ID
+        | PARAM_SPACE_SIZE_ID
+        | PARAM_SPACE_TONE_ID
+        | PARAM_MIX_ID
+        | PARAM_SHAPE_SMOOTH_ID
+        | PARAM_QUANTIZE_STRENGTH_ID
+        | PARAM_FEEDBACK_TONE_ID
+        | PARAM_ELASTICITY_TO_WIDTH_ID => write!(
+            writer,
+            "{:.*}%",
+            precision.percent_decimals as usize,
+            value * 100.0
+        ),
+        PARAM_PULL_RATE_ID
+        | PARAM_MOD_A_RATE_HZ_ID
+        | PARAM_MOD_B_RATE_HZ_ID
+        | PARAM_WARP_RATE_ID
+        | PARAM_AIR_FREQ_HZ_ID
+        | PARAM_FEEDBACK_HPF_ID => {
+            write!(writer, "{:.*} Hz", precision.hz_decimals as usize, value)
+        }
+        PARAM_PULL_SHAPE_ID => write!(writer, "{}", PullShape::from_value(value as f32).label()),
+        PARAM_TIME_MODE_ID => write!(writer, "{}", TimeMode::from_value(value as f32).label()),
+        PARAM_PULL_DIVISION_ID
+        | PARAM_MOD_A_DIVISION_ID
+        | PARAM_MOD_B_DIVISION_ID
+        | PARAM_SEQ_DIVISION_ID => {
+            write!(writer, "{}", PullDivision::from_value(value as f32).label())
+        }
+        PARAM_SEQ_STEPS_ID => write!(writer, "{}", seq_step_count(value as f32)),
+        PARAM_PULL_QUANTIZE_ID => {
+            write!(writer, "{}", PullQuantize::from_value(value as f32).label())
+        }
+        PARAM_WARP_COLOR_ID => write!(writer, "{}", WarpColor::from_value(value as f32).label()),
+        PARAM_RATE_MULT_ID => {
+            write!(
+                writer,
+                "{}",
+                RateMultiplier::from_value(value as f32).label()
+            )
+        }
+        PARAM_KEY_LOCK_ID => write!(writer, "{}", KeyLock::from_value(value as f32).label()),
+        PARAM_CLIP_MODE_ID => write!(writer, "{}", ClipMode::from_value(value as f32).label()),
+        PARAM_RELEASE_SHAPE_ID => {
+            write!(writer, "{}", ReleaseShape::from_value(value as f32).label())
+        }
+        PARAM_TENSION_METER_ID => write!(
+            writer,
+            "{}",
+            TensionMeterSource::from_value(value as f32).label()
+        ),
+        PARAM_WARP_HIGH_COLOR_ID => match warp_high_color_from_value(value as f32) {
+            Some(color) => write!(writer, "{}", color.label()),
+            None => write!(writer, "Same"),
+        },
+        PARAM_CLEAN_DIRTY_ID => {
+            write!(
+                writer,
+                "{}",
+                CharacterMode::from_value(value as f32).label()
+            )
+        }
+        PARAM_MOD_A_SHAPE_ID | PARAM_MOD_B_SHAPE_ID => {
+            write!(
+                writer,
+                "{}",
+                ModSourceShape::from_value(value as f32).label()
+            )
+        }
+        PARAM_MOD_A_RATE_MODE_ID | PARAM_MOD_B_RATE_MODE_ID | PARAM_WARP_RATE_MODE_ID => {
+            write!(writer, "{}", ModRateMode::from_value(value as f32).label())
+        }
+        PARAM_HOLD_ID
+        | PARAM_AIR_COMP_ID
+        | PARAM_PULL_TRIGGER_ID
+        | PARAM_PULL_LATCH_ID
+        | PARAM_MOD_RUN_ID
+        | PARAM_CHAR_MAKEUP_ID
+        | PARAM_FEEDBACK_POLARITY_ID
+        | PARAM_MONO_MONITOR_ID
+        | PARAM_VERBOSE_LOG_ID
+        | PARAM_SUSTAIN_PEDAL_ID
+        | PARAM_DUAL_GESTURE_ID
+        | PARAM_SCRUB_ID
+        | PARAM_TEMPO_FOLLOW_ID
+        | PARAM_SEQ_ENABLE_ID
+        | PARAM_FREEZE_ID
+        | PARAM_WIDTH_SAFETY_ID
+        | PARAM_ELASTIC_REVERSE_ID
+        | PARAM_LIMITER_ID
+        | PARAM_SAFETY_LIMIT_ID => {
+            if value >= 0.5 {
                 write!(writer, "On")
             } else {
                 write!(writer, "Off")
@@ -989,7 +2277,30 @@ pub(crate) fn value_to_text(
             let bipolar = value as f32 * 2.0 - 1.0;
             write!(writer, "{bipolar:+.2}")
         }
-        PARAM_OUTPUT_TRIM_DB_ID => write!(writer, "{value:+.1} dB"),
+        PARAM_OUTPUT_TRIM_DB_ID | PARAM_CHAR_DRIVE_ID => write!(writer, "{value:+.1} dB"),
+        PARAM_LIMIT_LOOKAHEAD_MS_ID | PARAM_HAAS_MS_ID => write!(writer, "{value:.1} ms"),
+        PARAM_ELASTIC_SIZE_ID => write!(writer, "{value:.2} s"),
+        PARAM_QUANTIZE_MULTIPLE_ID | PARAM_DOWNSAMPLE_ID => write!(writer, "x{value:.0}"),
+        PARAM_CRUSH_DEPTH_ID => write!(writer, "{value:.0} bit"),
+        PARAM_IMAGE_ROTATE_ID => write!(writer, "{value:+.1}°"),
+        PARAM_INPUT_GATE_ID => {
+            if value <= INPUT_GATE_OFF_DB as f64 {
+                write!(writer, "Off")
+            } else {
+                write!(writer, "{value:.1} dB")
+            }
+        }
+        PARAM_WARP_LOW_KEEP_HZ_ID => {
+            if value <= 0.0 {
+                write!(writer, "Off")
+            } else {
+                write!(writer, "{:.*} Hz", precision.hz_decimals as usize, value)
+            }
+        }
+        PARAM_MOD_A_ENV_ATTACK_ID
+        | PARAM_MOD_A_ENV_RELEASE_ID
+        | PARAM_MOD_B_ENV_ATTACK_ID
+        | PARAM_MOD_B_ENV_RELEASE_ID => write!(writer, "{value:.2} ms"),
         PARAM_MOD_A_TO_TENSION_ID
         | PARAM_MOD_A_TO_DIRECTION_ID
         | PARAM_MOD_A_TO_GRAIN_ID
@@ -1013,27 +2324,91 @@ pub(crate) fn text_to_value(param_id: ClapId, text: &CStr) -> Option<f64> {
     match param_id {
         PARAM_PULL_SHAPE_ID => return PullShape::parse(raw).map(|shape| shape.as_value() as f64),
         PARAM_TIME_MODE_ID => return TimeMode::parse(raw).map(|mode| mode.as_value() as f64),
-        PARAM_PULL_DIVISION_ID | PARAM_MOD_A_DIVISION_ID | PARAM_MOD_B_DIVISION_ID => {
+        PARAM_PULL_DIVISION_ID
+        | PARAM_MOD_A_DIVISION_ID
+        | PARAM_MOD_B_DIVISION_ID
+        | PARAM_SEQ_DIVISION_ID => {
             return PullDivision::parse(raw).map(|division| division.as_value() as f64);
         }
+        PARAM_SEQ_STEPS_ID => {
+            return match raw.trim() {
+                "8" => Some(0.0),
+                "16" => Some(1.0),
+                _ => None,
+            };
+        }
         PARAM_PULL_QUANTIZE_ID => {
             return PullQuantize::parse(raw).map(|quantize| quantize.as_value() as f64);
         }
         PARAM_WARP_COLOR_ID => return WarpColor::parse(raw).map(|color| color.as_value() as f64),
+        PARAM_RATE_MULT_ID => {
+            return RateMultiplier::parse(raw).map(|multiplier| multiplier.as_value() as f64);
+        }
+        PARAM_KEY_LOCK_ID => return KeyLock::parse(raw).map(|lock| lock.as_value() as f64),
+        PARAM_CLIP_MODE_ID => return ClipMode::parse(raw).map(|mode| mode.as_value() as f64),
+        PARAM_RELEASE_SHAPE_ID => {
+            return ReleaseShape::parse(raw).map(|shape| shape.as_value() as f64);
+        }
+        PARAM_TENSION_METER_ID => {
+            return TensionMeterSource::parse(raw).map(|source| source.as_value() as f64);
+        }
+        PARAM_WARP_HIGH_COLOR_ID => {
+            return match raw.trim().to_ascii_lowercase().as_str() {
+                "0" | "same" => Some(0.0),
+                "1" => Some(1.0),
+                "2" => Some(2.0),
+                "3" => Some(3.0),
+                name => WarpColor::parse(name).map(|color| color.as_value() as f64 + 1.0),
+            };
+        }
+        PARAM_WARP_LOW_KEEP_HZ_ID if raw.eq_ignore_ascii_case("off") => return Some(0.0),
+        PARAM_INPUT_GATE_ID if raw.eq_ignore_ascii_case("off") => {
+            return Some(INPUT_GATE_OFF_DB as f64);
+        }
+        PARAM_CRUSH_DEPTH_ID => {
+            return raw
+                .trim_end_matches("bits")
+                .trim_end_matches("bit")
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .map(|bits| bits.round().clamp(2.0, 16.0));
+        }
+        PARAM_QUANTIZE_MULTIPLE_ID | PARAM_DOWNSAMPLE_ID => {
+            return raw
+                .trim_start_matches(['x', 'X'])
+                .trim()
+                .parse::<f64>()
+                .ok();
+        }
         PARAM_CLEAN_DIRTY_ID => {
             return CharacterMode::parse(raw).map(|mode| mode.as_value() as f64);
         }
         PARAM_MOD_A_SHAPE_ID | PARAM_MOD_B_SHAPE_ID => {
             return ModSourceShape::parse(raw).map(|shape| shape.as_value() as f64);
         }
-        PARAM_MOD_A_RATE_MODE_ID | PARAM_MOD_B_RATE_MODE_ID => {
+        PARAM_MOD_A_RATE_MODE_ID | PARAM_MOD_B_RATE_MODE_ID | PARAM_WARP_RATE_MODE_ID => {
             return ModRateMode::parse(raw).map(|mode| mode.as_value() as f64);
         }
         PARAM_HOLD_ID
         | PARAM_AIR_COMP_ID
         | PARAM_PULL_TRIGGER_ID
         | PARAM_PULL_LATCH_ID
-        | PARAM_MOD_RUN_ID => {
+        | PARAM_MOD_RUN_ID
+        | PARAM_CHAR_MAKEUP_ID
+        | PARAM_FEEDBACK_POLARITY_ID
+        | PARAM_MONO_MONITOR_ID
+        | PARAM_VERBOSE_LOG_ID
+        | PARAM_SUSTAIN_PEDAL_ID
+        | PARAM_DUAL_GESTURE_ID
+        | PARAM_SCRUB_ID
+        | PARAM_TEMPO_FOLLOW_ID
+        | PARAM_SEQ_ENABLE_ID
+        | PARAM_FREEZE_ID
+        | PARAM_WIDTH_SAFETY_ID
+        | PARAM_ELASTIC_REVERSE_ID
+        | PARAM_LIMITER_ID
+        | PARAM_SAFETY_LIMIT_ID => {
             return parse_toggle(raw).map(|enabled| enabled as u8 as f64);
         }
         _ => {}
@@ -1041,6 +2416,9 @@ pub(crate) fn text_to_value(param_id: ClapId, text: &CStr) -> Option<f64> {
 
     let numeric = raw
         .trim_end_matches('%')
+        .trim_end_matches('°')
+        .trim_end_matches("ms")
+        .trim_end_matches('s')
         .trim_end_matches("hz")
         .trim_end_matches("Hz")
         .trim_end_matches("db")
@@ -1159,6 +2537,126 @@ pub(crate) const PARAM_TENSION_BIAS_ID: ClapId = ClapId::new(49);
 pub(crate) const PARAM_RELEASE_SNAP_ID: ClapId = ClapId::new(50);
 /// Parameter id for soft energy ceiling amount.
 pub(crate) const PARAM_ENERGY_CEILING_ID: ClapId = ClapId::new(51);
+/// Parameter id for character auto-makeup toggle.
+pub(crate) const PARAM_CHAR_MAKEUP_ID: ClapId = ClapId::new(52);
+/// Parameter id for tension-keyed output ducking.
+pub(crate) const PARAM_PULL_DUCK_ID: ClapId = ClapId::new(53);
+/// Parameter id for spring stiffness offset.
+pub(crate) const PARAM_SPRING_ID: ClapId = ClapId::new(54);
+/// Parameter id for continuous air compensation amount.
+pub(crate) const PARAM_AIR_COMP_AMOUNT_ID: ClapId = ClapId::new(55);
+/// Parameter id for pull-shape phase offset.
+pub(crate) const PARAM_PULL_PHASE_ID: ClapId = ClapId::new(56);
+/// Parameter id for feedback polarity inversion toggle.
+pub(crate) const PARAM_FEEDBACK_POLARITY_ID: ClapId = ClapId::new(57);
+/// Parameter id for idle motion envelope floor.
+pub(crate) const PARAM_IDLE_MOTION_ID: ClapId = ClapId::new(58);
+/// Parameter id for mono-compatibility monitor toggle.
+pub(crate) const PARAM_MONO_MONITOR_ID: ClapId = ClapId::new(59);
+/// Parameter id for baseline warp drift rate (Hz).
+pub(crate) const PARAM_WARP_RATE_ID: ClapId = ClapId::new(60);
+/// Parameter id for warp drift rate mode.
+pub(crate) const PARAM_WARP_RATE_MODE_ID: ClapId = ClapId::new(61);
+/// Parameter id for verbose host-log diagnostics toggle.
+pub(crate) const PARAM_VERBOSE_LOG_ID: ClapId = ClapId::new(62);
+/// Parameter id for left/right feedback balance.
+pub(crate) const PARAM_FEEDBACK_BALANCE_ID: ClapId = ClapId::new(63);
+/// Parameter id for safety limiter lookahead (ms).
+pub(crate) const PARAM_LIMIT_LOOKAHEAD_MS_ID: ClapId = ClapId::new(64);
+/// Parameter id for elastic grain direction bias.
+pub(crate) const PARAM_GRAIN_BIAS_ID: ClapId = ClapId::new(65);
+/// Parameter id for elastic transient preservation amount.
+pub(crate) const PARAM_TRANSIENT_PRESERVE_ID: ClapId = ClapId::new(66);
+/// Parameter id for pull-direction auto-return speed.
+pub(crate) const PARAM_DIR_RETURN_ID: ClapId = ClapId::new(67);
+/// Parameter id for mod source A envelope attack (ms).
+pub(crate) const PARAM_MOD_A_ENV_ATTACK_ID: ClapId = ClapId::new(68);
+/// Parameter id for mod source A envelope release (ms).
+pub(crate) const PARAM_MOD_A_ENV_RELEASE_ID: ClapId = ClapId::new(69);
+/// Parameter id for mod source B envelope attack (ms).
+pub(crate) const PARAM_MOD_B_ENV_ATTACK_ID: ClapId = ClapId::new(70);
+/// Parameter id for mod source B envelope release (ms).
+pub(crate) const PARAM_MOD_B_ENV_RELEASE_ID: ClapId = ClapId::new(71);
+/// Parameter id for the warp low-band keep crossover (Hz).
+pub(crate) const PARAM_WARP_LOW_KEEP_HZ_ID: ClapId = ClapId::new(72);
+/// Parameter id for the quantize launch multiple (grids per launch window).
+pub(crate) const PARAM_QUANTIZE_MULTIPLE_ID: ClapId = ClapId::new(73);
+/// Parameter id for MIDI sustain-pedal pull hold.
+pub(crate) const PARAM_SUSTAIN_PEDAL_ID: ClapId = ClapId::new(74);
+/// Parameter id for synced timing humanization.
+pub(crate) const PARAM_HUMANIZE_ID: ClapId = ClapId::new(75);
+/// Parameter id for dual-mono (independent L/R) gesture processing.
+pub(crate) const PARAM_DUAL_GESTURE_ID: ClapId = ClapId::new(76);
+/// Parameter id for character drive into the Dirty/Crush stages (dB).
+pub(crate) const PARAM_CHAR_DRIVE_ID: ClapId = ClapId::new(77);
+/// Parameter id for the pull rate multiplier (half/normal/double time).
+pub(crate) const PARAM_RATE_MULT_ID: ClapId = ClapId::new(78);
+/// Parameter id for the minimum effective tension.
+pub(crate) const PARAM_TENSION_FLOOR_ID: ClapId = ClapId::new(79);
+/// Parameter id for the elastic pitch key lock scale.
+pub(crate) const PARAM_KEY_LOCK_ID: ClapId = ClapId::new(80);
+/// Parameter id for the space stage size macro.
+pub(crate) const PARAM_SPACE_SIZE_ID: ClapId = ClapId::new(81);
+/// Parameter id for the warp color of the high band (0 = same as Warp Color).
+pub(crate) const PARAM_WARP_HIGH_COLOR_ID: ClapId = ClapId::new(82);
+/// Parameter id for the freeze-and-scrub mode toggle.
+pub(crate) const PARAM_SCRUB_ID: ClapId = ClapId::new(83);
+/// Parameter ID for the Tension meter source.
+pub(crate) const PARAM_TENSION_METER_ID: ClapId = ClapId::new(84);
+/// Parameter ID for the space diffusion tone tilt.
+pub(crate) const PARAM_SPACE_TONE_ID: ClapId = ClapId::new(85);
+/// Parameter ID for scaling free-mode rates by host tempo.
+pub(crate) const PARAM_TEMPO_FOLLOW_ID: ClapId = ClapId::new(86);
+/// Parameter ID for the Haas inter-channel delay in milliseconds.
+pub(crate) const PARAM_HAAS_MS_ID: ClapId = ClapId::new(87);
+/// Parameter ID for the gesture step sequencer toggle.
+pub(crate) const PARAM_SEQ_ENABLE_ID: ClapId = ClapId::new(88);
+/// Parameter ID for the step sequencer grid division.
+pub(crate) const PARAM_SEQ_DIVISION_ID: ClapId = ClapId::new(89);
+/// Parameter ID for the step sequencer pattern length (8 or 16 steps).
+pub(crate) const PARAM_SEQ_STEPS_ID: ClapId = ClapId::new(90);
+/// Air-band corner frequency that damping pulls down from.
+pub(crate) const PARAM_AIR_FREQ_HZ_ID: ClapId = ClapId::new(91);
+/// Parameter id for the dry/wet mix.
+pub(crate) const PARAM_MIX_ID: ClapId = ClapId::new(92);
+/// Parameter id for pull shape smoothing.
+pub(crate) const PARAM_SHAPE_SMOOTH_ID: ClapId = ClapId::new(108);
+/// Input gate threshold in dBFS; the minimum turns the gate off.
+pub(crate) const PARAM_INPUT_GATE_ID: ClapId = ClapId::new(93);
+/// Input gate threshold at which the gate is bypassed.
+pub(crate) const INPUT_GATE_OFF_DB: f32 = -96.0;
+/// Parameter id for the warp motion response-curve exponent.
+pub(crate) const PARAM_WARP_MOTION_CURVE_ID: ClapId = ClapId::new(94);
+/// Parameter id for the output clip shaper selection.
+pub(crate) const PARAM_CLIP_MODE_ID: ClapId = ClapId::new(95);
+/// Parameter id for the pull envelope release contour.
+pub(crate) const PARAM_RELEASE_SHAPE_ID: ClapId = ClapId::new(96);
+/// Parameter id for the Crush character bit depth.
+pub(crate) const PARAM_CRUSH_DEPTH_ID: ClapId = ClapId::new(97);
+/// Parameter ID for the space stage stereo image rotation in degrees.
+pub(crate) const PARAM_IMAGE_ROTATE_ID: ClapId = ClapId::new(109);
+/// Parameter id for the Crush character sample-and-hold decimation factor.
+pub(crate) const PARAM_DOWNSAMPLE_ID: ClapId = ClapId::new(98);
+/// Parameter id for how far quantized launches move toward the grid.
+pub(crate) const PARAM_QUANTIZE_STRENGTH_ID: ClapId = ClapId::new(99);
+/// Parameter id for the feedback path low-cut frequency.
+pub(crate) const PARAM_FEEDBACK_HPF_ID: ClapId = ClapId::new(100);
+/// Parameter id for the feedback path brightness.
+pub(crate) const PARAM_FEEDBACK_TONE_ID: ClapId = ClapId::new(101);
+/// Parameter id for how much elasticity widens or narrows the stereo image.
+pub(crate) const PARAM_ELASTICITY_TO_WIDTH_ID: ClapId = ClapId::new(102);
+/// Parameter id for the usable elastic buffer length in seconds.
+pub(crate) const PARAM_ELASTIC_SIZE_ID: ClapId = ClapId::new(103);
+/// Parameter id for freezing the elastic buffer contents into a loop.
+pub(crate) const PARAM_FREEZE_ID: ClapId = ClapId::new(104);
+/// Parameter id for narrowing the stereo width as feedback rises.
+pub(crate) const PARAM_WIDTH_SAFETY_ID: ClapId = ClapId::new(105);
+/// Parameter id for reading the elastic buffer backward.
+pub(crate) const PARAM_ELASTIC_REVERSE_ID: ClapId = ClapId::new(106);
+/// Parameter id for the optional true-peak output limiter.
+pub(crate) const PARAM_LIMITER_ID: ClapId = ClapId::new(107);
+/// Parameter id for the safety peak limiter toggle.
+pub(crate) const PARAM_SAFETY_LIMIT_ID: ClapId = ClapId::new(110);
 
 /// Pull-shape labels used by the editor dropdown.
 #[cfg(target_os = "windows")]
@@ -1174,6 +2672,28 @@ pub(crate) const PULL_DIVISION_LABELS: [&str; 8] = [
 /// Pull-quantize labels used by the editor dropdown.
 #[cfg(target_os = "windows")]
 pub(crate) const PULL_QUANTIZE_LABELS: [&str; 4] = ["None", "1/16", "1/8", "1/4"];
+/// High-band warp-color labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const WARP_HIGH_COLOR_LABELS: [&str; 4] =
+    ["Same", "Neutral", "Dark Drag", "Bright Shear"];
+/// Release-shape labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const RELEASE_SHAPE_LABELS: [&str; 3] = ["Exponential", "Linear", "S-Curve"];
+/// Output clip-mode labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const CLIP_MODE_LABELS: [&str; 4] = ["Soft", "Tanh", "Hard", "Off"];
+/// Key-lock labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const KEY_LOCK_LABELS: [&str; 4] = ["Off", "Major", "Minor", "Chromatic"];
+/// Tension-meter source labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const TENSION_METER_LABELS: [&str; 3] = ["Drive", "Envelope", "Effective"];
+/// Sequencer length labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const SEQ_STEPS_LABELS: [&str; 2] = ["8", "16"];
+/// Rate-multiplier labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const RATE_MULT_LABELS: [&str; 3] = ["x0.5", "x1", "x2"];
 /// Warp-color labels used by the editor dropdown.
 #[cfg(target_os = "windows")]
 pub(crate) const WARP_COLOR_LABELS: [&str; 3] = ["Neutral", "Dark Drag", "Bright Shear"];
@@ -1220,6 +2740,7 @@ impl ParamDef {
 
 const AUTO: u32 = ParamInfoFlags::IS_AUTOMATABLE.bits();
 const TOGGLE: u32 = AUTO | ParamInfoFlags::IS_STEPPED.bits() | ParamInfoFlags::IS_ENUM.bits();
+const STEPPED: u32 = AUTO | ParamInfoFlags::IS_STEPPED.bits();
 
 const PARAM_DEFS: &[ParamDef] = &[
     ParamDef {
@@ -1681,82 +3202,830 @@ const PARAM_DEFS: &[ParamDef] = &[
         default_value: 0.7,
         flags: AUTO,
     },
-];
-
-fn clamp(value: f32, min: f32, max: f32) -> f32 {
-    value.clamp(min, max)
-}
-
-fn bool_to_u32(value: bool) -> u32 {
-    if value { 1 } else { 0 }
-}
-
-fn u32_to_bool(value: u32) -> bool {
-    value != 0
-}
-
-fn parse_toggle(raw: &str) -> Option<bool> {
-    match raw.trim().to_ascii_lowercase().as_str() {
-        "1" | "on" | "true" | "yes" => Some(true),
-        "0" | "off" | "false" | "no" => Some(false),
-        _ => None,
-    }
-}
-
-/// An atomic `f32` backed by `AtomicU32`.
-#[derive(Default)]
-struct AtomicF32 {
-    value: AtomicU32,
-}
-
-impl AtomicF32 {
-    fn new(value: f32) -> Self {
-        Self {
-            value: AtomicU32::new(u32::from_ne_bytes(value.to_ne_bytes())),
-        }
-    }
-
-    fn store(&self, value: f32) {
-        self.value
-            .store(u32::from_ne_bytes(value.to_ne_bytes()), Ordering::Relaxed);
-    }
-
-    fn load(&self) -> f32 {
-        f32::from_ne_bytes(self.value.load(Ordering::Relaxed).to_ne_bytes())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::{
-        CharacterMode, ModRateMode, ModSourceShape, PullDivision, PullQuantize, PullShape,
-        TimeMode, WarpColor, parse_toggle,
-    };
-
-    #[test]
-    fn pull_shape_parse_handles_names_and_indexes() {
-        assert_eq!(PullShape::parse("linear"), Some(PullShape::Linear));
-        assert_eq!(PullShape::parse("2"), Some(PullShape::Ratchet));
-        assert_eq!(PullShape::parse("wave"), Some(PullShape::Wave));
-        assert_eq!(PullShape::parse("pulse"), Some(PullShape::Pulse));
-        assert_eq!(PullShape::parse("bad"), None);
-    }
-
-    #[test]
-    fn toggle_parser_handles_common_variants() {
-        assert_eq!(parse_toggle("on"), Some(true));
-        assert_eq!(parse_toggle("false"), Some(false));
-        assert_eq!(parse_toggle("unknown"), None);
-    }
-
-    #[test]
-    fn enum_parsers_cover_core_labels() {
-        assert_eq!(TimeMode::parse("sync"), Some(TimeMode::SyncDivision));
-        assert_eq!(PullDivision::parse("1/4"), Some(PullDivision::Div1_4));
-        assert_eq!(PullQuantize::parse("1/8"), Some(PullQuantize::Div1_8));
-        assert_eq!(WarpColor::parse("dark drag"), Some(WarpColor::DarkDrag));
-        assert_eq!(CharacterMode::parse("crush"), Some(CharacterMode::Crush));
-        assert_eq!(ModSourceShape::parse("env"), Some(ModSourceShape::Envelope));
-        assert_eq!(ModRateMode::parse("hz"), Some(ModRateMode::FreeHz));
+    ParamDef {
+        id: PARAM_CHAR_MAKEUP_ID,
+        name: b"Char Makeup",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_PULL_DUCK_ID,
+        name: b"Pull Duck",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_SPRING_ID,
+        name: b"Spring",
+        module: b"Perform",
+        min_value: -1.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_AIR_COMP_AMOUNT_ID,
+        name: b"Air Comp Amount",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 1.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_PULL_PHASE_ID,
+        name: b"Pull Phase",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_FEEDBACK_POLARITY_ID,
+        name: b"Feedback Invert",
+        module: b"Space",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_IDLE_MOTION_ID,
+        name: b"Idle Motion",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_MONO_MONITOR_ID,
+        name: b"Mono Monitor",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_WARP_RATE_ID,
+        name: b"Warp Rate",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 8.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_WARP_RATE_MODE_ID,
+        name: b"Warp Rate Mode",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_VERBOSE_LOG_ID,
+        name: b"Verbose Log",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_FEEDBACK_BALANCE_ID,
+        name: b"Feedback Balance",
+        module: b"Safety",
+        min_value: -1.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_LIMIT_LOOKAHEAD_MS_ID,
+        name: b"Limit Lookahead",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 10.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_GRAIN_BIAS_ID,
+        name: b"Grain Bias",
+        module: b"Tone",
+        min_value: -1.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_TRANSIENT_PRESERVE_ID,
+        name: b"Transient Preserve",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_DIR_RETURN_ID,
+        name: b"Dir Return",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_MOD_A_ENV_ATTACK_ID,
+        name: b"Mod A Env Attack",
+        module: b"Mod",
+        min_value: 0.1,
+        max_value: 1000.0,
+        default_value: 0.35,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_MOD_A_ENV_RELEASE_ID,
+        name: b"Mod A Env Release",
+        module: b"Mod",
+        min_value: 0.1,
+        max_value: 1000.0,
+        default_value: 0.35,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_MOD_B_ENV_ATTACK_ID,
+        name: b"Mod B Env Attack",
+        module: b"Mod",
+        min_value: 0.1,
+        max_value: 1000.0,
+        default_value: 0.35,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_MOD_B_ENV_RELEASE_ID,
+        name: b"Mod B Env Release",
+        module: b"Mod",
+        min_value: 0.1,
+        max_value: 1000.0,
+        default_value: 0.35,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_WARP_LOW_KEEP_HZ_ID,
+        name: b"Warp Low Keep",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 1000.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_QUANTIZE_MULTIPLE_ID,
+        name: b"Quant Multiple",
+        module: b"Perform",
+        min_value: 1.0,
+        max_value: 16.0,
+        default_value: 1.0,
+        flags: STEPPED,
+    },
+    ParamDef {
+        id: PARAM_SUSTAIN_PEDAL_ID,
+        name: b"Pedal Hold",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 1.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_HUMANIZE_ID,
+        name: b"Humanize",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_DUAL_GESTURE_ID,
+        name: b"Dual Gesture",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_CHAR_DRIVE_ID,
+        name: b"Char Drive",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 24.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_RATE_MULT_ID,
+        name: b"Rate Mult",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 2.0,
+        default_value: 1.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_TENSION_FLOOR_ID,
+        name: b"Tension Floor",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_KEY_LOCK_ID,
+        name: b"Key Lock",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 3.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_SPACE_SIZE_ID,
+        name: b"Space Size",
+        module: b"Space",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.2,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_WARP_HIGH_COLOR_ID,
+        name: b"Warp High Color",
+        module: b"Tone",
+        min_value: 0.0,
+        max_value: 3.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_SCRUB_ID,
+        name: b"Scrub",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_TENSION_METER_ID,
+        name: b"Tension Meter",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 2.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_SPACE_TONE_ID,
+        name: b"Space Tone",
+        module: b"Space",
+        min_value: -1.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_TEMPO_FOLLOW_ID,
+        name: b"Tempo Follow",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_HAAS_MS_ID,
+        name: b"Haas Delay",
+        module: b"Space",
+        min_value: 0.0,
+        max_value: 20.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_SEQ_ENABLE_ID,
+        name: b"Sequencer",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_SEQ_DIVISION_ID,
+        name: b"Seq Division",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 7.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_SEQ_STEPS_ID,
+        name: b"Seq Steps",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 1.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_AIR_FREQ_HZ_ID,
+        name: b"Air Freq",
+        module: b"Space",
+        min_value: 200.0,
+        max_value: 8000.0,
+        default_value: 1000.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_MIX_ID,
+        name: b"Mix",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 1.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_SHAPE_SMOOTH_ID,
+        name: b"Shape Smooth",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_INPUT_GATE_ID,
+        name: b"Input Gate",
+        module: b"Safety",
+        min_value: -96.0,
+        max_value: -24.0,
+        default_value: -96.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_WARP_MOTION_CURVE_ID,
+        name: b"Warp Motion Curve",
+        module: b"Tone",
+        min_value: 0.5,
+        max_value: 4.0,
+        default_value: 1.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_CLIP_MODE_ID,
+        name: b"Clip Mode",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 3.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_RELEASE_SHAPE_ID,
+        name: b"Release Shape",
+        module: b"Rhythm",
+        min_value: 0.0,
+        max_value: 2.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_CRUSH_DEPTH_ID,
+        name: b"Crush Depth",
+        module: b"Tone",
+        min_value: 2.0,
+        max_value: 16.0,
+        default_value: 7.0,
+        flags: STEPPED,
+    },
+    ParamDef {
+        id: PARAM_IMAGE_ROTATE_ID,
+        name: b"Image Rotate",
+        module: b"Space",
+        min_value: -45.0,
+        max_value: 45.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_DOWNSAMPLE_ID,
+        name: b"Downsample",
+        module: b"Tone",
+        min_value: 1.0,
+        max_value: 32.0,
+        default_value: 1.0,
+        flags: STEPPED,
+    },
+    ParamDef {
+        id: PARAM_QUANTIZE_STRENGTH_ID,
+        name: b"Quant Strength",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 1.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_FEEDBACK_HPF_ID,
+        name: b"Feedback HPF",
+        module: b"Space",
+        min_value: 20.0,
+        max_value: 800.0,
+        default_value: 20.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_FEEDBACK_TONE_ID,
+        name: b"Feedback Tone",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 1.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_ELASTICITY_TO_WIDTH_ID,
+        name: b"Elastic Width",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_ELASTIC_SIZE_ID,
+        name: b"Elastic Size",
+        module: b"Perform",
+        min_value: 0.25,
+        max_value: 6.0,
+        default_value: 2.75,
+        flags: AUTO,
+    },
+    ParamDef {
+        id: PARAM_FREEZE_ID,
+        name: b"Freeze",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_WIDTH_SAFETY_ID,
+        name: b"Width Safety",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_ELASTIC_REVERSE_ID,
+        name: b"Reverse",
+        module: b"Perform",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_LIMITER_ID,
+        name: b"True Peak Limit",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+    ParamDef {
+        id: PARAM_SAFETY_LIMIT_ID,
+        name: b"Safety Limit",
+        module: b"Safety",
+        min_value: 0.0,
+        max_value: 1.0,
+        default_value: 0.0,
+        flags: TOGGLE,
+    },
+];
+
+fn clamp(value: f32, min: f32, max: f32) -> f32 {
+    value.clamp(min, max)
+}
+
+fn bool_to_u32(value: bool) -> u32 {
+    if value { 1 } else { 0 }
+}
+
+fn u32_to_bool(value: u32) -> bool {
+    value != 0
+}
+
+fn parse_toggle(raw: &str) -> Option<bool> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "1" | "on" | "true" | "yes" => Some(true),
+        "0" | "off" | "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// An atomic `f32` backed by `AtomicU32`.
+#[derive(Default)]
+struct AtomicF32 {
+    value: AtomicU32,
+}
+
+impl AtomicF32 {
+    fn new(value: f32) -> Self {
+        Self {
+            value: AtomicU32::new(u32::from_ne_bytes(value.to_ne_bytes())),
+        }
+    }
+
+    fn store(&self, value: f32) {
+        self.value
+            .store(u32::from_ne_bytes(value.to_ne_bytes()), Ordering::Relaxed);
+    }
+
+    fn load(&self) -> f32 {
+        f32::from_ne_bytes(self.value.load(Ordering::Relaxed).to_ne_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CharacterMode, ClapId, DisplayPrecision, INVALID_ENUM_LABEL, ModRateMode, ModSourceShape,
+        PARAM_DEFS, PARAM_MONO_MONITOR_ID, PARAM_OUTPUT_TRIM_DB_ID, PARAM_PULL_RATE_ID,
+        PARAM_PULL_SHAPE_ID, PARAM_PULL_TRIGGER_ID, PARAM_TENSION_ID, PARAM_WIDTH_ID, ParamLocks,
+        PresetTag, PullDivision, PullQuantize, PullShape, SEQ_STEP_COUNT, StepPattern,
+        TensionFieldParams, TimeMode, WarpColor, apply_click_toggle, apply_momentary,
+        apply_unlocked_updates, debug_dump, filter_presets, parse_toggle, reset_all, value_to_text,
+    };
+
+    #[test]
+    fn pull_shape_parse_handles_names_and_indexes() {
+        assert_eq!(PullShape::parse("linear"), Some(PullShape::Linear));
+        assert_eq!(PullShape::parse("2"), Some(PullShape::Ratchet));
+        assert_eq!(PullShape::parse("wave"), Some(PullShape::Wave));
+        assert_eq!(PullShape::parse("pulse"), Some(PullShape::Pulse));
+        assert_eq!(PullShape::parse("bad"), None);
+    }
+
+    #[test]
+    fn toggle_parser_handles_common_variants() {
+        assert_eq!(parse_toggle("on"), Some(true));
+        assert_eq!(parse_toggle("false"), Some(false));
+        assert_eq!(parse_toggle("unknown"), None);
+    }
+
+    #[test]
+    fn enum_parsers_cover_core_labels() {
+        assert_eq!(TimeMode::parse("sync"), Some(TimeMode::SyncDivision));
+        assert_eq!(PullDivision::parse("1/4"), Some(PullDivision::Div1_4));
+        assert_eq!(PullQuantize::parse("1/8"), Some(PullQuantize::Div1_8));
+        assert_eq!(WarpColor::parse("dark drag"), Some(WarpColor::DarkDrag));
+        assert_eq!(CharacterMode::parse("crush"), Some(CharacterMode::Crush));
+        assert_eq!(ModSourceShape::parse("env"), Some(ModSourceShape::Envelope));
+        assert_eq!(ModRateMode::parse("hz"), Some(ModRateMode::FreeHz));
+    }
+
+    #[test]
+    fn preset_updates_skip_locked_params() {
+        let params = TensionFieldParams::new();
+        let locks = ParamLocks::default();
+        params.set_param(PARAM_WIDTH_ID, 0.3);
+        params.set_param(PARAM_OUTPUT_TRIM_DB_ID, -4.0);
+        locks.set_locked(PARAM_WIDTH_ID, true);
+        locks.set_locked(PARAM_OUTPUT_TRIM_DB_ID, true);
+
+        let mut applied = Vec::new();
+        apply_unlocked_updates(
+            &params,
+            &locks,
+            &[
+                (PARAM_TENSION_ID, 0.8),
+                (PARAM_WIDTH_ID, 0.9),
+                (PARAM_OUTPUT_TRIM_DB_ID, 2.0),
+            ],
+            |param_id, _| applied.push(param_id),
+        );
+
+        assert_eq!(applied, vec![PARAM_TENSION_ID]);
+        assert_eq!(params.get_param(PARAM_TENSION_ID), Some(0.8));
+        assert_eq!(params.get_param(PARAM_WIDTH_ID), Some(0.3));
+        assert_eq!(params.get_param(PARAM_OUTPUT_TRIM_DB_ID), Some(-4.0));
+    }
+
+    #[test]
+    fn preset_filter_keeps_only_matching_tags_in_order() {
+        let tags = |preset: usize| -> &'static [PresetTag] {
+            match preset {
+                0 => &[PresetTag::Rhythmic],
+                1 => &[PresetTag::Ambient, PresetTag::Aggressive],
+                2 => &[],
+                _ => &[PresetTag::Rhythmic, PresetTag::Ambient],
+            }
+        };
+        let bank = [0, 1, 2, 3];
+
+        assert_eq!(filter_presets(&bank, tags, None), vec![0, 1, 2, 3]);
+        assert_eq!(
+            filter_presets(&bank, tags, Some(PresetTag::Rhythmic)),
+            vec![0, 3]
+        );
+        assert_eq!(
+            filter_presets(&bank, tags, Some(PresetTag::Ambient)),
+            vec![1, 3]
+        );
+        assert_eq!(
+            filter_presets(&bank, tags, Some(PresetTag::Aggressive)),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn mono_monitor_button_is_momentary() {
+        let params = TensionFieldParams::new();
+        assert!(!params.settings().mono_monitor);
+
+        assert_eq!(apply_momentary(&params, PARAM_MONO_MONITOR_ID, true), 1.0);
+        assert!(params.settings().mono_monitor);
+
+        assert_eq!(apply_momentary(&params, PARAM_MONO_MONITOR_ID, false), 0.0);
+        assert!(!params.settings().mono_monitor);
+    }
+
+    #[test]
+    fn pull_click_toggle_alternates_on_successive_clicks() {
+        let params = TensionFieldParams::new();
+        assert!(!params.settings().pull_trigger);
+
+        for click in 0..6 {
+            let engaged = click % 2 == 0;
+            let expected = if engaged { 1.0 } else { 0.0 };
+            assert_eq!(apply_click_toggle(&params, PARAM_PULL_TRIGGER_ID), expected);
+            assert_eq!(params.settings().pull_trigger, engaged);
+        }
+    }
+
+    #[test]
+    fn debug_dump_lists_every_param_and_round_trips_values() {
+        let params = TensionFieldParams::new();
+        params.set_param(PARAM_TENSION_ID, 0.83);
+        params.set_param(PARAM_WIDTH_ID, 0.27);
+        params.set_param(PARAM_OUTPUT_TRIM_DB_ID, -3.5);
+
+        let dump = debug_dump(&params, 48_000.0, None);
+        assert!(dump.contains("\"sample_rate\": 48000"));
+        assert!(dump.contains(env!("CARGO_PKG_VERSION")));
+        for def in PARAM_DEFS {
+            let name = format!("\"name\": \"{}\"", String::from_utf8_lossy(def.name));
+            assert!(dump.contains(&name), "missing {name}");
+        }
+
+        let restored = TensionFieldParams::new();
+        for line in dump
+            .lines()
+            .filter(|line| line.trim_start().starts_with("{\"id\""))
+        {
+            let field = |key: &str| {
+                let start = line.find(key).expect("field present") + key.len();
+                let rest = &line[start..];
+                let end = rest.find([',', '}']).expect("field terminated");
+                rest[..end].trim().to_string()
+            };
+            let id: u32 = field("\"id\":").parse().expect("numeric id");
+            let value: f32 = field("\"value\":").parse().expect("numeric value");
+            restored.set_param(ClapId::new(id), value);
+        }
+
+        for def in PARAM_DEFS {
+            assert_eq!(restored.get_param(def.id), params.get_param(def.id));
+        }
+    }
+
+    #[test]
+    fn step_pattern_cycles_quarter_intensities_and_wraps_to_off() {
+        let pattern = StepPattern::default();
+        pattern.apply_state_values([0.0; SEQ_STEP_COUNT]);
+        let cycle: Vec<f32> = (0..5).map(|_| pattern.cycle_step(2)).collect();
+        assert_eq!(cycle, [0.25, 0.5, 0.75, 1.0, 0.0]);
+        assert_eq!(pattern.cycle_step(SEQ_STEP_COUNT), 0.0);
+        assert!(pattern.state_values().iter().all(|value| *value == 0.0));
+    }
+
+    #[test]
+    fn debug_dump_includes_last_panic_snapshot() {
+        use crate::diagnostics::{INPUT_HISTORY_FRAMES, PanicSnapshot, SanitizeSource};
+
+        let params = TensionFieldParams::new();
+        let mut recent_input = [(0.0, 0.0); INPUT_HISTORY_FRAMES];
+        recent_input[INPUT_HISTORY_FRAMES - 1] = (f32::NAN, 0.25);
+        let snapshot = PanicSnapshot {
+            source: SanitizeSource::Input,
+            settings: params.settings(),
+            recent_input,
+        };
+
+        assert!(!debug_dump(&params, 48_000.0, None).contains("last_panic"));
+        let dump = debug_dump(&params, 48_000.0, Some(&snapshot));
+        assert!(dump.contains("\"last_panic\""));
+        assert!(dump.contains("\"source\": \"input\""));
+        assert!(dump.contains("[\"NaN\", 0.25]"));
+    }
+
+    #[test]
+    fn value_to_text_respects_configured_precision() {
+        let mut standard = String::new();
+        value_to_text(
+            PARAM_TENSION_ID,
+            0.4567,
+            DisplayPrecision::default(),
+            &mut standard,
+        )
+        .expect("formatting should succeed");
+        assert_eq!(standard, "46%");
+
+        let fine = DisplayPrecision {
+            percent_decimals: 2,
+            hz_decimals: 1,
+        };
+        let mut percent = String::new();
+        value_to_text(PARAM_TENSION_ID, 0.4567, fine, &mut percent)
+            .expect("formatting should succeed");
+        assert_eq!(percent, "45.67%");
+
+        let mut rate = String::new();
+        value_to_text(PARAM_PULL_RATE_ID, 1.25, fine, &mut rate)
+            .expect("formatting should succeed");
+        assert_eq!(rate, "1.2 Hz");
+    }
+
+    #[test]
+    fn out_of_range_enum_values_format_as_invalid() {
+        let format = |value: f64| {
+            let mut text = String::new();
+            value_to_text(
+                PARAM_PULL_SHAPE_ID,
+                value,
+                DisplayPrecision::default(),
+                &mut text,
+            )
+            .expect("formatting should succeed");
+            text
+        };
+        assert_eq!(format(0.0), "Linear");
+        assert_eq!(format(4.0), "Pulse");
+        assert_eq!(format(7.0), INVALID_ENUM_LABEL);
+        assert_eq!(format(-1.0), INVALID_ENUM_LABEL);
+        assert_eq!(format(f64::NAN), INVALID_ENUM_LABEL);
+    }
+
+    #[test]
+    fn reset_all_restores_every_default() {
+        let params = TensionFieldParams::new();
+        for def in PARAM_DEFS {
+            params.set_param(def.id, def.max_value as f32);
+        }
+
+        let mut applied = 0;
+        reset_all(&params, |_, _| applied += 1);
+
+        assert_eq!(applied, PARAM_DEFS.len());
+        for def in PARAM_DEFS {
+            let value = params.get_param(def.id).expect("param should exist");
+            assert!(
+                (value - def.default_value as f32).abs() < 1.0e-6,
+                "param {:?} did not reset",
+                def.id
+            );
+        }
     }
 }
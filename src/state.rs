@@ -2,12 +2,15 @@
 
 use std::io::{Read, Write};
 
-use crate::params::{STATE_VALUE_COUNT, default_state_values};
+use crate::params::{
+    DEFAULT_METER_WARN_DBFS, DisplayPrecision, SEQ_STEP_COUNT, STATE_VALUE_COUNT, StepPattern,
+    default_state_values,
+};
 
 /// Four-byte magic marker for Tension Field state payloads (`TFST`).
 pub(crate) const STATE_MAGIC: u32 = u32::from_le_bytes(*b"TFST");
 /// Current state payload version.
-pub(crate) const STATE_VERSION: u32 = 3;
+pub(crate) const STATE_VERSION: u32 = 8;
 /// Number of persisted meter values.
 pub(crate) const METER_COUNT: usize = 9;
 
@@ -18,6 +21,16 @@ pub(crate) struct PluginStateSnapshot {
     pub(crate) param_values: [f32; STATE_VALUE_COUNT],
     /// UI meter values used to restore visual continuity.
     pub(crate) meter_values: [f32; METER_COUNT],
+    /// Preset-lock flags in `PARAM_DEFS` order.
+    pub(crate) param_locks: [bool; STATE_VALUE_COUNT],
+    /// User readout precision for percentage and Hz displays.
+    pub(crate) display_precision: DisplayPrecision,
+    /// Whether the editor Pull button is click-to-toggle instead of momentary.
+    pub(crate) pull_toggle: bool,
+    /// Gesture sequencer step intensities.
+    pub(crate) step_pattern: [f32; SEQ_STEP_COUNT],
+    /// Level in dBFS at which the meters switch to the warning color.
+    pub(crate) meter_warn_dbfs: f32,
 }
 
 /// Decode failures for Tension Field plugin state.
@@ -67,6 +80,19 @@ pub(crate) fn write_snapshot<W: Write>(
     for value in snapshot.meter_values {
         writer.write_all(&value.to_le_bytes())?;
     }
+    for locked in snapshot.param_locks {
+        writer.write_all(&[locked as u8])?;
+    }
+    let precision = snapshot.display_precision.clamped();
+    writer.write_all(&[
+        precision.percent_decimals as u8,
+        precision.hz_decimals as u8,
+    ])?;
+    writer.write_all(&[snapshot.pull_toggle as u8])?;
+    for value in snapshot.step_pattern {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    writer.write_all(&snapshot.meter_warn_dbfs.to_le_bytes())?;
     Ok(())
 }
 
@@ -88,18 +114,8 @@ pub(crate) fn read_snapshot<R: Read>(
 
     let mut param_values = default_state_values();
     match version {
-        STATE_VERSION => {
-            if param_count != STATE_VALUE_COUNT as u32 {
-                return Err(StateDecodeError::InvalidPayload);
-            }
-            for value in &mut param_values {
-                *value = read_f32(reader)?;
-                if !value.is_finite() {
-                    return Err(StateDecodeError::NonFiniteValue);
-                }
-            }
-        }
-        2 => {
+        2..=STATE_VERSION => {
+            // Older payloads may carry fewer parameters; missing values keep defaults.
             if param_count > STATE_VALUE_COUNT as u32 {
                 return Err(StateDecodeError::InvalidPayload);
             }
@@ -123,9 +139,59 @@ pub(crate) fn read_snapshot<R: Read>(
         }
     }
 
+    let mut param_locks = [false; STATE_VALUE_COUNT];
+    if version >= 4 {
+        for locked in param_locks.iter_mut().take(param_count as usize) {
+            let mut byte = [0u8; 1];
+            reader.read_exact(&mut byte)?;
+            *locked = byte[0] != 0;
+        }
+    }
+
+    let mut display_precision = DisplayPrecision::default();
+    if version >= 5 {
+        let mut bytes = [0u8; 2];
+        reader.read_exact(&mut bytes)?;
+        display_precision = DisplayPrecision {
+            percent_decimals: u32::from(bytes[0]),
+            hz_decimals: u32::from(bytes[1]),
+        }
+        .clamped();
+    }
+
+    let mut pull_toggle = false;
+    if version >= 6 {
+        let mut byte = [0u8; 1];
+        reader.read_exact(&mut byte)?;
+        pull_toggle = byte[0] != 0;
+    }
+
+    let mut step_pattern = StepPattern::default().state_values();
+    if version >= 7 {
+        for value in &mut step_pattern {
+            *value = read_f32(reader)?;
+            if !value.is_finite() {
+                return Err(StateDecodeError::NonFiniteValue);
+            }
+        }
+    }
+
+    let mut meter_warn_dbfs = DEFAULT_METER_WARN_DBFS;
+    if version >= 8 {
+        meter_warn_dbfs = read_f32(reader)?;
+        if !meter_warn_dbfs.is_finite() {
+            return Err(StateDecodeError::NonFiniteValue);
+        }
+    }
+
     Ok(PluginStateSnapshot {
         param_values,
         meter_values,
+        param_locks,
+        display_precision,
+        pull_toggle,
+        step_pattern,
+        meter_warn_dbfs,
     })
 }
 
@@ -144,9 +210,11 @@ fn read_f32<R: Read>(reader: &mut R) -> Result<f32, StateDecodeError> {
 #[cfg(test)]
 mod tests {
     use super::{
-        METER_COUNT, PluginStateSnapshot, STATE_MAGIC, STATE_VALUE_COUNT, STATE_VERSION,
-        StateDecodeError, read_snapshot, write_snapshot,
+        DEFAULT_METER_WARN_DBFS, DisplayPrecision, METER_COUNT, PluginStateSnapshot, STATE_MAGIC,
+        STATE_VALUE_COUNT, STATE_VERSION, StateDecodeError, StepPattern, default_state_values,
+        read_snapshot, write_snapshot,
     };
+    use crate::params::{PARAM_TENSION_ID, TensionFieldParams, apply_state_values, state_values};
 
     #[test]
     fn roundtrip_preserves_snapshot() {
@@ -159,9 +227,21 @@ mod tests {
             *value = index as f32 * 0.05;
         }
 
+        let mut locks = [false; STATE_VALUE_COUNT];
+        locks[1] = true;
+        locks[STATE_VALUE_COUNT - 1] = true;
+
         let expected = PluginStateSnapshot {
             param_values: params,
             meter_values: meters,
+            param_locks: locks,
+            display_precision: DisplayPrecision {
+                percent_decimals: 1,
+                hz_decimals: 3,
+            },
+            pull_toggle: true,
+            step_pattern: std::array::from_fn(|index| index as f32 / 16.0),
+            meter_warn_dbfs: -3.0,
         };
 
         let mut data = Vec::new();
@@ -173,6 +253,53 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn param_values_survive_save_and_reload() {
+        let saved = TensionFieldParams::new();
+        saved.set_param(PARAM_TENSION_ID, 0.8);
+        let snapshot = PluginStateSnapshot {
+            param_values: state_values(&saved),
+            meter_values: [0.0; METER_COUNT],
+            param_locks: [false; STATE_VALUE_COUNT],
+            display_precision: DisplayPrecision::default(),
+            pull_toggle: false,
+            step_pattern: StepPattern::default().state_values(),
+            meter_warn_dbfs: DEFAULT_METER_WARN_DBFS,
+        };
+        let mut data = Vec::new();
+        write_snapshot(&mut data, &snapshot).expect("state should serialize");
+
+        let loaded = TensionFieldParams::new();
+        let restored = read_snapshot(&mut data.as_slice()).expect("state should deserialize");
+        apply_state_values(&loaded, restored.param_values);
+        assert_eq!(loaded.get_param(PARAM_TENSION_ID), Some(0.8));
+    }
+
+    #[test]
+    fn malformed_payloads_fail_without_panicking() {
+        let snapshot = PluginStateSnapshot {
+            param_values: default_state_values(),
+            meter_values: [0.0; METER_COUNT],
+            param_locks: [false; STATE_VALUE_COUNT],
+            display_precision: DisplayPrecision::default(),
+            pull_toggle: false,
+            step_pattern: StepPattern::default().state_values(),
+            meter_warn_dbfs: DEFAULT_METER_WARN_DBFS,
+        };
+        let mut data = Vec::new();
+        write_snapshot(&mut data, &snapshot).expect("state should serialize");
+
+        for length in [0, 3, 16, data.len() / 2, data.len() - 1] {
+            let error = read_snapshot(&mut &data[..length]).expect_err("truncated state must fail");
+            assert_eq!(error, StateDecodeError::Io);
+        }
+
+        let mut corrupt = data.clone();
+        corrupt[16..20].copy_from_slice(&f32::NAN.to_le_bytes());
+        let error = read_snapshot(&mut corrupt.as_slice()).expect_err("NaN state must fail");
+        assert_eq!(error, StateDecodeError::NonFiniteValue);
+    }
+
     #[test]
     fn invalid_magic_is_rejected() {
         let mut data = Vec::new();
@@ -203,7 +330,7 @@ mod tests {
 
     #[test]
     fn v2_snapshot_migrates_missing_param_values() {
-        let legacy_param_count = STATE_VALUE_COUNT as u32 - 3;
+        let legacy_param_count = 48_u32;
         let mut data = Vec::new();
         data.extend_from_slice(&STATE_MAGIC.to_le_bytes());
         data.extend_from_slice(&2u32.to_le_bytes());
@@ -219,6 +346,10 @@ mod tests {
 
         let mut cursor = data.as_slice();
         let snapshot = read_snapshot(&mut cursor).expect("v2 state should migrate");
+        assert!(snapshot.param_locks.iter().all(|locked| !locked));
+        assert_eq!(snapshot.display_precision, DisplayPrecision::default());
+        assert!(!snapshot.pull_toggle);
+        assert_eq!(snapshot.step_pattern, StepPattern::default().state_values());
 
         assert!((snapshot.param_values[0] - 0.0).abs() < 1.0e-6);
         assert!((snapshot.param_values[legacy_param_count as usize - 1] - 0.47).abs() < 1.0e-6);
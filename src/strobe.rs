@@ -0,0 +1,62 @@
+//! Gesture-phase strobe overlay brightness for the tension map.
+
+use std::f32::consts::TAU;
+
+/// Brightness lost per second once the strobe has passed its peak.
+#[cfg(target_os = "windows")]
+const STROBE_DECAY_PER_SECOND: f32 = 3.5;
+
+/// Strobe brightness (0..1) for a gesture `phase`, peaking once per cycle at phase 0.
+pub(crate) fn strobe_brightness(phase: f32) -> f32 {
+    let pulse = (phase.rem_euclid(1.0) * TAU).cos() * 0.5 + 0.5;
+    pulse * pulse * pulse * pulse
+}
+
+/// Displayed strobe level that flashes instantly and fades with editor frame time.
+#[cfg(target_os = "windows")]
+#[derive(Default)]
+pub(crate) struct StrobeFlash {
+    level: f32,
+}
+
+#[cfg(target_os = "windows")]
+impl StrobeFlash {
+    /// Advance by one editor frame and return the brightness to draw.
+    pub(crate) fn advance(&mut self, phase: f32, frame_dt: f32) -> f32 {
+        let target = strobe_brightness(phase);
+        self.level = if target >= self.level {
+            target
+        } else {
+            (self.level - frame_dt * STROBE_DECAY_PER_SECOND).max(target)
+        };
+        self.level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strobe_brightness;
+
+    #[test]
+    fn brightness_peaks_once_per_cycle() {
+        let steps_per_cycle = 64;
+        let cycles = 3;
+        let samples: Vec<f32> = (0..steps_per_cycle * cycles)
+            .map(|step| strobe_brightness(step as f32 / steps_per_cycle as f32))
+            .collect();
+
+        let mut peaks = 0;
+        for index in 0..samples.len() {
+            let previous = samples[(index + samples.len() - 1) % samples.len()];
+            let next = samples[(index + 1) % samples.len()];
+            if samples[index] > previous && samples[index] >= next {
+                peaks += 1;
+            }
+        }
+
+        assert_eq!(peaks, cycles);
+        assert!((strobe_brightness(0.0) - 1.0).abs() < 1.0e-6);
+        assert!(strobe_brightness(0.5) < 1.0e-6);
+        assert!(samples.iter().all(|value| (0.0..=1.0).contains(value)));
+    }
+}
@@ -0,0 +1,225 @@
+//! Decimation and smoothing helpers for tension-map pointer traces.
+
+use std::collections::VecDeque;
+
+/// Storage interval and low-pass amount applied to recorded map traces.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct TraceSmoothing {
+    /// Keep one point out of every `interval` samples.
+    pub interval: usize,
+    /// One-pole smoothing amount (0 = raw, towards 1 = heavier filtering).
+    pub smoothing: f32,
+}
+
+impl TraceSmoothing {
+    /// Unfiltered trace, every pointer sample kept.
+    pub(crate) const RAW: Self = Self {
+        interval: 1,
+        smoothing: 0.0,
+    };
+    /// Light decimation with gentle filtering.
+    pub(crate) const SMOOTH: Self = Self {
+        interval: 2,
+        smoothing: 0.45,
+    };
+    /// Sparse storage with heavy filtering for slow automation moves.
+    pub(crate) const SMOOTHER: Self = Self {
+        interval: 4,
+        smoothing: 0.7,
+    };
+
+    /// Return the preset at `index`, matching `TRACE_SMOOTHING_LABELS`.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn from_index(index: usize) -> Self {
+        match index {
+            0 => Self::RAW,
+            2 => Self::SMOOTHER,
+            _ => Self::SMOOTH,
+        }
+    }
+
+    /// Return the dropdown index for this configuration.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn index(self) -> usize {
+        if self == Self::RAW {
+            0
+        } else if self == Self::SMOOTHER {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl Default for TraceSmoothing {
+    fn default() -> Self {
+        Self::SMOOTH
+    }
+}
+
+/// Trace-detail labels used by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const TRACE_SMOOTHING_LABELS: [&str; 3] = ["Raw", "Smooth", "Smoother"];
+
+/// Trail lengths in seconds offered by the editor dropdown.
+#[cfg(target_os = "windows")]
+pub(crate) const TRACE_HISTORY_SECONDS: [f32; 4] = [0.25, 0.6, 1.0, 2.0];
+/// Trail-length labels matching `TRACE_HISTORY_SECONDS`.
+#[cfg(target_os = "windows")]
+pub(crate) const TRACE_HISTORY_LABELS: [&str; 4] = ["0.25 s", "0.6 s", "1 s", "2 s"];
+/// Default trail length, about 36 frames at 60 fps.
+#[cfg(target_os = "windows")]
+pub(crate) const DEFAULT_TRACE_HISTORY_SECONDS: f32 = 0.6;
+
+/// Pointer trail holding a fixed span of real time regardless of frame rate.
+pub(crate) struct TimedTrace {
+    points: VecDeque<((f32, f32), f64)>,
+    now: f64,
+    window_seconds: f32,
+}
+
+impl TimedTrace {
+    /// Create an empty trail keeping `window_seconds` of history.
+    pub(crate) fn new(window_seconds: f32) -> Self {
+        Self {
+            points: VecDeque::with_capacity(128),
+            now: 0.0,
+            window_seconds: window_seconds.max(0.0),
+        }
+    }
+
+    /// Return the history length in seconds.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn window_seconds(&self) -> f32 {
+        self.window_seconds
+    }
+
+    /// Change the history length; older points are dropped on the next push.
+    #[cfg(target_os = "windows")]
+    pub(crate) fn set_window_seconds(&mut self, seconds: f32) {
+        self.window_seconds = seconds.max(0.0);
+    }
+
+    /// Advance the trail clock by `frame_dt`, add `point`, and evict expired points.
+    pub(crate) fn push(&mut self, point: (f32, f32), frame_dt: f32) {
+        self.now += f64::from(frame_dt.max(0.0));
+        self.points.push_back((point, self.now));
+        let oldest = self.now - f64::from(self.window_seconds);
+        while self
+            .points
+            .front()
+            .is_some_and(|(_, stamp)| *stamp < oldest)
+        {
+            self.points.pop_front();
+        }
+    }
+
+    /// Iterate retained points from oldest to newest.
+    pub(crate) fn points(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.points.iter().map(|(point, _)| *point)
+    }
+}
+
+/// Decimate a pointer trace and low-pass it before playback.
+///
+/// Endpoints are always kept so the overall path is preserved. Filtering runs
+/// forward and backward so the smoothed trace does not lag behind the pointer.
+pub(crate) fn decimate_and_smooth(
+    points: &[(f32, f32)],
+    config: TraceSmoothing,
+) -> Vec<(f32, f32)> {
+    let interval = config.interval.max(1);
+    let mut decimated: Vec<(f32, f32)> = points.iter().copied().step_by(interval).collect();
+    if let Some(&last) = points.last()
+        && !(points.len() - 1).is_multiple_of(interval)
+    {
+        decimated.push(last);
+    }
+
+    let smoothing = config.smoothing.clamp(0.0, 0.99);
+    if smoothing <= 0.0 || decimated.len() < 3 {
+        return decimated;
+    }
+
+    let coeff = 1.0 - smoothing;
+    let mut state = decimated[0];
+    for point in decimated.iter_mut() {
+        state.0 += (point.0 - state.0) * coeff;
+        state.1 += (point.1 - state.1) * coeff;
+        *point = state;
+    }
+    let mut state = *decimated.last().unwrap_or(&(0.0, 0.0));
+    for point in decimated.iter_mut().rev() {
+        state.0 += (point.0 - state.0) * coeff;
+        state.1 += (point.1 - state.1) * coeff;
+        *point = state;
+    }
+    decimated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TimedTrace, TraceSmoothing, decimate_and_smooth};
+
+    fn jitter(points: &[(f32, f32)]) -> f32 {
+        points
+            .windows(3)
+            .map(|w| {
+                let dx = w[0].0 - 2.0 * w[1].0 + w[2].0;
+                let dy = w[0].1 - 2.0 * w[1].1 + w[2].1;
+                dx.abs() + dy.abs()
+            })
+            .sum::<f32>()
+            / points.len().max(1) as f32
+    }
+
+    #[test]
+    fn smoothing_reduces_points_and_jitter_but_keeps_path() {
+        let raw: Vec<(f32, f32)> = (0..200)
+            .map(|i| {
+                let t = i as f32 / 199.0;
+                let noise = if i % 2 == 0 { 0.04 } else { -0.04 };
+                (t + noise, t - noise)
+            })
+            .collect();
+
+        let smoothed = decimate_and_smooth(&raw, TraceSmoothing::SMOOTHER);
+
+        assert!(smoothed.len() < raw.len());
+        assert!(jitter(&smoothed) < jitter(&raw) * 0.5);
+
+        let first = smoothed[0];
+        let last = smoothed[smoothed.len() - 1];
+        assert!(first.0.abs() < 0.1 && first.1.abs() < 0.1);
+        assert!((last.0 - 1.0).abs() < 0.1 && (last.1 - 1.0).abs() < 0.1);
+        for (index, point) in smoothed.iter().enumerate().skip(1) {
+            assert!(point.0 >= smoothed[index - 1].0 - 0.02);
+        }
+    }
+
+    #[test]
+    fn raw_config_is_passthrough() {
+        let raw = [(0.0, 0.0), (0.5, 0.2), (1.0, 1.0)];
+        assert_eq!(decimate_and_smooth(&raw, TraceSmoothing::RAW), raw.to_vec());
+    }
+
+    #[test]
+    fn timed_trace_keeps_the_same_span_at_any_frame_rate() {
+        for fps in [30.0_f32, 60.0, 144.0] {
+            let dt = 1.0 / fps;
+            let mut trace = TimedTrace::new(0.5);
+            let mut stamps = Vec::new();
+            for frame in 0..(fps as usize * 3) {
+                let t = frame as f32 * dt;
+                trace.push((t, 0.0), dt);
+                stamps.push(t);
+            }
+            let newest = *stamps.last().unwrap_or(&0.0);
+            let kept: Vec<f32> = trace.points().map(|point| point.0).collect();
+            let span = newest - kept[0];
+            assert!(span <= 0.5 + 1.0e-4, "{fps} fps kept {span} s");
+            assert!(span > 0.5 - dt - 1.0e-4, "{fps} fps kept only {span} s");
+            assert_eq!(kept.last(), stamps.last());
+        }
+    }
+}